@@ -0,0 +1,11 @@
+use std::{env, path::PathBuf};
+
+/// Directory the settings of the application are kept in, e.g. `~/.config/amrust` or
+/// `%APPDATA%\amrust`. `None` when the environment names no such place.
+pub fn config_directory() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CONFIG_HOME")
+        .or_else(|| env::var_os("APPDATA"))
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(base.join("amrust"))
+}
@@ -0,0 +1,3 @@
+pub mod directory;
+pub mod printer_profiles;
+pub mod recent_files;
@@ -0,0 +1,274 @@
+use anyhow::{anyhow, Result};
+use glam::{DVec2, DVec3};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use super::directory::config_directory;
+
+const PRINTER_PROFILES_NAME: &str = "printer_profiles.xml";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BedShape {
+    Rectangular,
+    /// A round bed as on delta printers, `bed_width` is its diameter.
+    Circular,
+}
+
+/// Where the origin of the printer coordinates lies on the bed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BedOrigin {
+    /// The front left corner, as on most cartesian printers.
+    FrontLeft,
+    /// The centre of the bed, as on delta printers.
+    Center,
+}
+
+impl fmt::Display for BedShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BedShape::Rectangular => "Rectangular",
+            BedShape::Circular => "Circular",
+        })
+    }
+}
+
+impl fmt::Display for BedOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BedOrigin::FrontLeft => "Front left",
+            BedOrigin::Center => "Center",
+        })
+    }
+}
+
+/// Build volume of a printer in millimetres.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PrinterProfile {
+    #[serde(rename = "@name")]
+    pub name: String,
+    #[serde(rename = "@shape")]
+    pub shape: BedShape,
+    /// Size along X, the diameter of a circular bed.
+    #[serde(rename = "@width")]
+    pub bed_width: f64,
+    /// Size along Y, not used by a circular bed.
+    #[serde(rename = "@depth")]
+    pub bed_depth: f64,
+    #[serde(rename = "@height")]
+    pub max_height: f64,
+    #[serde(rename = "@origin")]
+    pub origin: BedOrigin,
+}
+
+impl Default for PrinterProfile {
+    fn default() -> Self {
+        Self {
+            name: "Generic 220 x 220".to_string(),
+            shape: BedShape::Rectangular,
+            bed_width: 220.0,
+            bed_depth: 220.0,
+            max_height: 250.0,
+            origin: BedOrigin::FrontLeft,
+        }
+    }
+}
+
+impl PrinterProfile {
+    /// Size of the bed along X and Y.
+    pub fn bed_size(&self) -> DVec2 {
+        match self.shape {
+            BedShape::Rectangular => DVec2::new(self.bed_width, self.bed_depth),
+            BedShape::Circular => DVec2::splat(self.bed_width),
+        }
+    }
+
+    /// Centre of the bed in printer coordinates.
+    pub fn bed_center(&self) -> DVec2 {
+        match self.origin {
+            BedOrigin::FrontLeft => self.bed_size() / 2.0,
+            BedOrigin::Center => DVec2::ZERO,
+        }
+    }
+
+    /// Corners of the box around the build volume as `(min, max)`.
+    pub fn volume_bounds(&self) -> (DVec3, DVec3) {
+        let half = self.bed_size() / 2.0;
+        let center = self.bed_center();
+        (
+            (center - half).extend(0.0),
+            (center + half).extend(self.max_height),
+        )
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        for (name, value) in [
+            ("Bed width", self.bed_width),
+            ("Bed depth", self.bed_depth),
+            ("Maximum height", self.max_height),
+        ] {
+            if value.is_nan() || value <= 0.0 {
+                return Err(anyhow!(
+                    "{} of printer {} must be positive, not {}",
+                    name,
+                    self.name,
+                    value
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Every known printer and the one models are checked against.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename = "printers")]
+pub struct PrinterProfiles {
+    #[serde(rename = "@selected", default)]
+    pub selected: usize,
+    #[serde(rename = "printer", default)]
+    pub profiles: Vec<PrinterProfile>,
+}
+
+impl Default for PrinterProfiles {
+    fn default() -> Self {
+        Self {
+            selected: 0,
+            profiles: vec![
+                PrinterProfile::default(),
+                PrinterProfile {
+                    name: "Generic 300 x 300".to_string(),
+                    bed_width: 300.0,
+                    bed_depth: 300.0,
+                    max_height: 400.0,
+                    ..Default::default()
+                },
+                PrinterProfile {
+                    name: "Generic delta".to_string(),
+                    shape: BedShape::Circular,
+                    bed_width: 200.0,
+                    bed_depth: 200.0,
+                    max_height: 300.0,
+                    origin: BedOrigin::Center,
+                },
+            ],
+        }
+    }
+}
+
+impl PrinterProfiles {
+    /// Reads the profiles from the configuration directory. Missing or unreadable profiles
+    /// are logged and replaced by the built in ones.
+    pub fn load() -> Self {
+        let Some(path) = Self::default_path() else {
+            return Self::default();
+        };
+        if !path.exists() {
+            return Self::default();
+        }
+        match Self::read(&path) {
+            Ok(profiles) => profiles,
+            Err(e) => {
+                log::warn!(
+                    "Failed to read printer profiles from {}: {}",
+                    path.display(),
+                    e
+                );
+                Self::default()
+            }
+        }
+    }
+
+    /// Writes the profiles to the configuration directory.
+    pub fn save(&self) -> Result<()> {
+        let path =
+            Self::default_path().ok_or_else(|| anyhow!("No configuration directory is known"))?;
+        self.write(&path)?;
+        log::info!("Saved printer profiles to {}", path.display());
+        Ok(())
+    }
+
+    pub fn read(path: &Path) -> Result<Self> {
+        let mut profiles: Self = quick_xml::de::from_str(&fs::read_to_string(path)?)?;
+        if profiles.profiles.is_empty() {
+            return Err(anyhow!("No printer is defined"));
+        }
+        for profile in &profiles.profiles {
+            profile.validate()?;
+        }
+        profiles.selected = profiles.selected.min(profiles.profiles.len() - 1);
+        Ok(profiles)
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+        fs::write(path, quick_xml::se::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn selected(&self) -> &PrinterProfile {
+        &self.profiles[self.selected]
+    }
+
+    pub fn selected_mut(&mut self) -> &mut PrinterProfile {
+        &mut self.profiles[self.selected]
+    }
+
+    fn default_path() -> Option<PathBuf> {
+        config_directory().map(|directory| directory.join(PRINTER_PROFILES_NAME))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_write_and_read() {
+        let path = env::temp_dir().join("amrust_test_printer_profiles.xml");
+        let mut profiles = PrinterProfiles {
+            selected: 2,
+            ..Default::default()
+        };
+        profiles.profiles[0].name = "Mine & yours".to_string();
+
+        profiles.write(&path).unwrap();
+        let read = PrinterProfiles::read(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(
+            read.as_ref().is_ok_and(|read| *read == profiles),
+            "Profiles changed after writing: {:?}",
+            read
+        );
+    }
+
+    #[test]
+    fn test_origin_moves_the_volume() {
+        let mut profile = PrinterProfile::default();
+        assert!(
+            profile.volume_bounds() == (DVec3::ZERO, DVec3::new(220.0, 220.0, 250.0)),
+            "Volume of a front left origin is wrong"
+        );
+
+        profile.origin = BedOrigin::Center;
+        profile.shape = BedShape::Circular;
+        profile.bed_width = 200.0;
+        assert!(
+            profile.volume_bounds()
+                == (
+                    DVec3::new(-100.0, -100.0, 0.0),
+                    DVec3::new(100.0, 100.0, 250.0)
+                ),
+            "Volume of a centred round bed is wrong: {:?}",
+            profile.volume_bounds()
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use anyhow::Result;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use super::directory::config_directory;
+
+/// Number of files remembered.
+pub const MAX_RECENT_FILES: usize = 12;
+const RECENT_FILES_NAME: &str = "recent_files.txt";
+
+/// Files opened before, most recent first, kept as one path per line.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RecentFiles {
+    pub paths: Vec<PathBuf>,
+}
+
+impl RecentFiles {
+    /// Reads the list from the configuration directory. A missing or unreadable list is
+    /// logged and starts empty.
+    pub fn load() -> Self {
+        let Some(path) = Self::default_path() else {
+            return Self::default();
+        };
+        match Self::read(&path) {
+            Ok(recent_files) => recent_files,
+            Err(e) => {
+                if path.exists() {
+                    log::warn!("Failed to read recent files from {}: {}", path.display(), e);
+                }
+                Self::default()
+            }
+        }
+    }
+
+    /// Writes the list to the configuration directory.
+    pub fn save(&self) -> Result<()> {
+        match Self::default_path() {
+            Some(path) => self.write(&path),
+            None => Ok(()),
+        }
+    }
+
+    pub fn read(path: &Path) -> Result<Self> {
+        let paths = fs::read_to_string(path)?
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(PathBuf::from)
+            .take(MAX_RECENT_FILES)
+            .collect();
+        Ok(Self { paths })
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+        let lines: Vec<String> = self
+            .paths
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+        fs::write(path, lines.join("\n"))?;
+        Ok(())
+    }
+
+    /// Moves `path` to the front, dropping the oldest file when the list is full.
+    pub fn add(&mut self, path: &Path) {
+        self.paths.retain(|recent| recent != path);
+        self.paths.insert(0, path.to_path_buf());
+        self.paths.truncate(MAX_RECENT_FILES);
+    }
+
+    fn default_path() -> Option<PathBuf> {
+        config_directory().map(|directory| directory.join(RECENT_FILES_NAME))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_most_recent_file_is_first_and_unique() {
+        let mut recent_files = RecentFiles::default();
+        for index in 0..MAX_RECENT_FILES + 2 {
+            recent_files.add(Path::new(&format!("{}.3mf", index)));
+        }
+        recent_files.add(Path::new("5.3mf"));
+
+        assert!(
+            recent_files.paths.len() == MAX_RECENT_FILES,
+            "List is not limited to {} files",
+            MAX_RECENT_FILES
+        );
+        assert!(
+            recent_files.paths[0] == Path::new("5.3mf")
+                && recent_files
+                    .paths
+                    .iter()
+                    .filter(|p| p.ends_with("5.3mf"))
+                    .count()
+                    == 1,
+            "Reopened file is not moved to the front"
+        );
+    }
+
+    #[test]
+    fn test_write_and_read() {
+        let path = env::temp_dir().join("amrust_test_recent_files.txt");
+        let mut recent_files = RecentFiles::default();
+        recent_files.add(Path::new("/models/a b.3mf"));
+        recent_files.add(Path::new("/models/c.stl"));
+
+        recent_files.write(&path).unwrap();
+        let read = RecentFiles::read(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(
+            read == recent_files,
+            "List changed after writing: {:?}",
+            read
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use std::fmt;
+
+/// What a move prints, as named by the `;TYPE:` comments slicers write before the moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Feature {
+    OuterWall,
+    InnerWall,
+    Skin,
+    Fill,
+    Support,
+    Skirt,
+    /// Moves without a type comment or with a type that is not known.
+    Other,
+}
+
+impl Feature {
+    pub const ALL: [Feature; 7] = [
+        Feature::OuterWall,
+        Feature::InnerWall,
+        Feature::Skin,
+        Feature::Fill,
+        Feature::Support,
+        Feature::Skirt,
+        Feature::Other,
+    ];
+
+    /// Name written in `;TYPE:` comments, the one Cura uses.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Feature::OuterWall => "WALL-OUTER",
+            Feature::InnerWall => "WALL-INNER",
+            Feature::Skin => "SKIN",
+            Feature::Fill => "FILL",
+            Feature::Support => "SUPPORT",
+            Feature::Skirt => "SKIRT",
+            Feature::Other => "CUSTOM",
+        }
+    }
+
+    /// Reads the type of a `;TYPE:` comment as written by Cura, PrusaSlicer or OrcaSlicer.
+    pub fn from_type_name(name: &str) -> Feature {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "wall-outer" | "external perimeter" | "outer wall" => Feature::OuterWall,
+            "wall-inner" | "perimeter" | "inner wall" | "overhang perimeter" | "overhang wall" => {
+                Feature::InnerWall
+            }
+            "skin"
+            | "solid infill"
+            | "top solid infill"
+            | "bridge infill"
+            | "internal solid infill"
+            | "top surface"
+            | "bottom surface" => Feature::Skin,
+            "fill" | "internal infill" | "sparse infill" => Feature::Fill,
+            "support"
+            | "support-interface"
+            | "support material"
+            | "support material interface"
+            | "support interface" => Feature::Support,
+            "skirt" | "skirt/brim" | "brim" => Feature::Skirt,
+            _ => Feature::Other,
+        }
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Feature::OuterWall => "Outer wall",
+            Feature::InnerWall => "Inner wall",
+            Feature::Skin => "Skin",
+            Feature::Fill => "Infill",
+            Feature::Support => "Support",
+            Feature::Skirt => "Skirt",
+            Feature::Other => "Other",
+        };
+        f.write_str(text)
+    }
+}
@@ -0,0 +1,511 @@
+use anyhow::{anyhow, Result};
+use glam::{DVec2, DVec3};
+use std::{f64::consts::TAU, ops::Range};
+
+use super::feature::Feature;
+
+/// Arcs are split into lines no longer than this, in millimetres.
+const ARC_SEGMENT_LENGTH: f64 = 0.5;
+/// Extrusions at heights closer than this, in millimetres, belong to the same layer.
+const LAYER_EPSILON: f64 = 1e-4;
+const MILLIMETERS_PER_INCH: f64 = 25.4;
+/// M commands followed by free text instead of parameters.
+const TEXT_COMMANDS: [f64; 7] = [23.0, 28.0, 30.0, 32.0, 117.0, 118.0, 928.0];
+
+/// A straight move of the nozzle. Arcs are read as several moves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GcodeMove {
+    pub from: DVec3,
+    pub to: DVec3,
+    /// Filament pushed in millimetres, negative for retractions.
+    pub extrusion: f64,
+    /// Requested speed in millimetres per minute.
+    pub feedrate: f64,
+    pub feature: Feature,
+    pub layer: usize,
+}
+
+impl GcodeMove {
+    pub fn length(&self) -> f64 {
+        self.from.distance(self.to)
+    }
+
+    /// Whether the move prints, rather than travels or only moves the filament.
+    pub fn is_extrusion(&self) -> bool {
+        self.extrusion > 0.0 && self.from.truncate() != self.to.truncate()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GcodeLayer {
+    /// Height of the extrusions of the layer.
+    pub z: f64,
+    /// Indices of the moves of the layer in [`GcodeProgram::moves`], from the travel to
+    /// its first extrusion to the travel to the next layer.
+    pub moves: Range<usize>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GcodeProgram {
+    pub moves: Vec<GcodeMove>,
+    pub layers: Vec<GcodeLayer>,
+    pub line_count: usize,
+}
+
+/// State of the machine while reading. Positions are in millimetres in machine
+/// coordinates, `G92` only moves the origin of the coordinates the program uses.
+struct Machine {
+    position: DVec3,
+    origin: DVec3,
+    /// Filament position in the coordinates of the program.
+    extruder: f64,
+    feedrate: f64,
+    relative_positions: bool,
+    relative_extrusion: bool,
+    units: f64,
+    feature: Feature,
+}
+
+/// Reads the moves of a G-code program as written for Marlin.
+///
+/// Understands G0/G1 lines, G2/G3 arcs given by their centre or radius, G28 homing,
+/// G90/G91 positioning, G92 and M82/M83 extrusion modes, G20/G21 units and the
+/// `;TYPE:` comments of common slicers. Other commands are skipped.
+pub fn parse_gcode(text: &str) -> Result<GcodeProgram> {
+    let mut machine = Machine {
+        position: DVec3::ZERO,
+        origin: DVec3::ZERO,
+        extruder: 0.0,
+        feedrate: 0.0,
+        relative_positions: false,
+        relative_extrusion: false,
+        units: 1.0,
+        feature: Feature::Other,
+    };
+    let mut program = GcodeProgram::default();
+    let mut layer_z: Option<f64> = None;
+
+    for (index, line) in text.lines().enumerate() {
+        program.line_count += 1;
+        let (code, comment) = match line.split_once(';') {
+            Some((code, comment)) => (code, Some(comment)),
+            None => (line, None),
+        };
+        if let Some(name) = comment.and_then(|comment| comment.trim().strip_prefix("TYPE:")) {
+            machine.feature = Feature::from_type_name(name);
+        }
+
+        let words =
+            parse_words(code).map_err(|e| anyhow!("{} on line {}: {}", e, index + 1, line))?;
+        let Some(&(letter, number)) = words.first() else {
+            continue;
+        };
+        let value = |wanted: char| {
+            words
+                .iter()
+                .find(|(letter, _)| *letter == wanted)
+                .map(|(_, value)| *value)
+        };
+
+        let first_move = program.moves.len();
+        match (letter, number) {
+            ('G', 0.0 | 1.0) => {
+                let to = machine.target(value('X'), value('Y'), value('Z'));
+                let extrusion = machine.extrude(value('E'));
+                machine.set_feedrate(value('F'));
+                if to != machine.position || extrusion != 0.0 {
+                    program.moves.push(machine.move_to(to, extrusion));
+                }
+            }
+            ('G', n @ (2.0 | 3.0)) => {
+                let to = machine.target(value('X'), value('Y'), value('Z'));
+                let extrusion = machine.extrude(value('E'));
+                machine.set_feedrate(value('F'));
+                let center = match (value('I'), value('J'), value('R')) {
+                    (None, None, Some(radius)) => arc_center_from_radius(
+                        machine.position,
+                        to,
+                        radius * machine.units,
+                        n == 2.0,
+                    ),
+                    (i, j, None) if i.is_some() || j.is_some() => {
+                        // offsets of the centre are relative to the start in any positioning mode
+                        machine.position.truncate()
+                            + DVec2::new(i.unwrap_or(0.0), j.unwrap_or(0.0)) * machine.units
+                    }
+                    _ => {
+                        return Err(anyhow!(
+                            "Arc needs either I and J or R on line {}: {}",
+                            index + 1,
+                            line
+                        ))
+                    }
+                };
+                // the points are evenly spaced, so is the filament
+                let points = arc_points(machine.position, to, center, n == 2.0);
+                let share = extrusion / points.len() as f64;
+                for point in points {
+                    program.moves.push(machine.move_to(point, share));
+                }
+            }
+            ('G', 20.0) => machine.units = MILLIMETERS_PER_INCH,
+            ('G', 21.0) => machine.units = 1.0,
+            ('G', 28.0) => {
+                // without axes every axis is homed
+                let all = !['X', 'Y', 'Z'].iter().any(|&axis| value(axis).is_some());
+                let mut to = machine.position;
+                for (axis, component) in ['X', 'Y', 'Z'].into_iter().enumerate() {
+                    if all || value(component).is_some() {
+                        to[axis] = 0.0;
+                        machine.origin[axis] = 0.0;
+                    }
+                }
+                if to != machine.position {
+                    program.moves.push(machine.move_to(to, 0.0));
+                }
+            }
+            ('G', 90.0) => {
+                machine.relative_positions = false;
+                machine.relative_extrusion = false;
+            }
+            ('G', 91.0) => {
+                machine.relative_positions = true;
+                machine.relative_extrusion = true;
+            }
+            ('G', 92.0) => {
+                for (axis, component) in ['X', 'Y', 'Z'].into_iter().enumerate() {
+                    if let Some(value) = value(component) {
+                        machine.origin[axis] = machine.position[axis] - value * machine.units;
+                    }
+                }
+                if let Some(value) = value('E') {
+                    machine.extruder = value * machine.units;
+                }
+            }
+            ('M', 82.0) => machine.relative_extrusion = false,
+            ('M', 83.0) => machine.relative_extrusion = true,
+            _ => {}
+        }
+
+        for move_index in first_move..program.moves.len() {
+            let gcode_move = &program.moves[move_index];
+            let starts_layer = gcode_move.is_extrusion()
+                && layer_z.is_none_or(|z| (gcode_move.to.z - z).abs() > LAYER_EPSILON);
+            if starts_layer {
+                // the travel to the first extrusion belongs to the new layer
+                let start = program.layers.last().map_or(0, |layer| {
+                    program.moves[layer.moves.start..move_index]
+                        .iter()
+                        .rposition(GcodeMove::is_extrusion)
+                        .map_or(move_index, |last| layer.moves.start + last + 1)
+                });
+                if let Some(layer) = program.layers.last_mut() {
+                    layer.moves.end = start;
+                }
+                program.layers.push(GcodeLayer {
+                    z: gcode_move.to.z,
+                    moves: start..move_index + 1,
+                });
+                layer_z = Some(gcode_move.to.z);
+            }
+        }
+    }
+
+    let move_count = program.moves.len();
+    match program.layers.last_mut() {
+        Some(layer) => layer.moves.end = move_count,
+        None if move_count > 0 => program.layers.push(GcodeLayer {
+            z: machine.position.z,
+            moves: 0..move_count,
+        }),
+        None => {}
+    }
+    for layer_index in 0..program.layers.len() {
+        let range = program.layers[layer_index].moves.clone();
+        for gcode_move in &mut program.moves[range] {
+            gcode_move.layer = layer_index;
+        }
+    }
+
+    Ok(program)
+}
+
+impl Machine {
+    /// Position after a move to the given coordinates of the program.
+    fn target(&self, x: Option<f64>, y: Option<f64>, z: Option<f64>) -> DVec3 {
+        let mut to = self.position;
+        for (axis, value) in [x, y, z].into_iter().enumerate() {
+            if let Some(value) = value {
+                let value = value * self.units;
+                to[axis] = if self.relative_positions {
+                    to[axis] + value
+                } else {
+                    self.origin[axis] + value
+                };
+            }
+        }
+        to
+    }
+
+    /// Filament pushed by a move with the given E value.
+    fn extrude(&mut self, e: Option<f64>) -> f64 {
+        let Some(e) = e else {
+            return 0.0;
+        };
+        let e = e * self.units;
+        if self.relative_extrusion {
+            self.extruder += e;
+            e
+        } else {
+            let extrusion = e - self.extruder;
+            self.extruder = e;
+            extrusion
+        }
+    }
+
+    fn set_feedrate(&mut self, f: Option<f64>) {
+        if let Some(f) = f {
+            self.feedrate = f * self.units;
+        }
+    }
+
+    fn move_to(&mut self, to: DVec3, extrusion: f64) -> GcodeMove {
+        let gcode_move = GcodeMove {
+            from: self.position,
+            to,
+            extrusion,
+            feedrate: self.feedrate,
+            feature: self.feature,
+            layer: 0,
+        };
+        self.position = to;
+        gcode_move
+    }
+}
+
+/// Letters with their numbers, e.g. `G1 X10 E.5` gives `[('G', 1), ('X', 10), ('E', 0.5)]`.
+/// Line numbers and checksums are left out.
+fn parse_words(code: &str) -> Result<Vec<(char, f64)>> {
+    let code = code.split_once('*').map_or(code, |(code, _)| code);
+    let mut words = Vec::new();
+    if code.trim() == "%" {
+        // start and end of tape
+        return Ok(words);
+    }
+    let mut chars = code.char_indices().peekable();
+    while let Some((start, letter)) = chars.next() {
+        if letter.is_whitespace() {
+            continue;
+        }
+        if letter == '(' {
+            // comments in parentheses
+            for (_, c) in chars.by_ref() {
+                if c == ')' {
+                    break;
+                }
+            }
+            continue;
+        }
+        if !letter.is_ascii_alphabetic() {
+            return Err(anyhow!("Unexpected character {:?}", letter));
+        }
+
+        let number_start = start + letter.len_utf8();
+        let mut number_end = number_start;
+        while let Some(&(index, c)) = chars.peek() {
+            if !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+' || c == ' ') {
+                break;
+            }
+            number_end = index + c.len_utf8();
+            chars.next();
+        }
+        let number = code[number_start..number_end].trim();
+        let letter = letter.to_ascii_uppercase();
+        if letter == 'N' {
+            continue;
+        }
+        // flags like `G28 X` have no number
+        let value = if number.is_empty() {
+            0.0
+        } else {
+            number
+                .replace(' ', "")
+                .parse()
+                .map_err(|_| anyhow!("Invalid number {:?} after {}", number, letter))?
+        };
+        words.push((letter, value));
+        if words.len() == 1 && letter == 'M' && TEXT_COMMANDS.contains(&value) {
+            // messages and file names
+            break;
+        }
+    }
+
+    Ok(words)
+}
+
+fn arc_center_from_radius(from: DVec3, to: DVec3, radius: f64, clockwise: bool) -> DVec2 {
+    let (from, to) = (from.truncate(), to.truncate());
+    let chord = to - from;
+    let half = chord.length() / 2.0;
+    let height = (radius * radius - half * half).max(0.0).sqrt();
+    // a negative radius asks for the longer of the two arcs
+    let side = if clockwise == (radius > 0.0) {
+        -1.0
+    } else {
+        1.0
+    };
+    from + chord / 2.0 + chord.normalize_or_zero().perp() * height * side
+}
+
+/// Angle swept from `from` to `to` around `center`, negative when clockwise. Arcs that
+/// end where they start are full circles.
+fn arc_sweep(from: DVec3, to: DVec3, center: DVec2, clockwise: bool) -> f64 {
+    let start = (from.truncate() - center).to_angle();
+    let end = (to.truncate() - center).to_angle();
+    let mut sweep = end - start;
+    if clockwise {
+        if sweep >= 0.0 {
+            sweep -= TAU;
+        }
+    } else if sweep <= 0.0 {
+        sweep += TAU;
+    }
+    sweep
+}
+
+/// Points along an arc, ending at `to`. Z changes evenly for helical arcs.
+fn arc_points(from: DVec3, to: DVec3, center: DVec2, clockwise: bool) -> Vec<DVec3> {
+    let radius = (from.truncate() - center).length();
+    let start = (from.truncate() - center).to_angle();
+    let sweep = arc_sweep(from, to, center, clockwise);
+    let count = ((sweep.abs() * radius / ARC_SEGMENT_LENGTH).ceil() as usize).max(1);
+
+    (1..=count)
+        .map(|step| {
+            if step == count {
+                return to;
+            }
+            let t = step as f64 / count as f64;
+            let planar = center + DVec2::from_angle(start + sweep * t) * radius;
+            planar.extend(from.z + (to.z - from.z) * t)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::get_file_as_string_from_test_resource;
+
+    #[test]
+    fn test_generated_box_gcode_is_read_back() {
+        let program = parse_gcode(&get_file_as_string_from_test_resource("box.gcode")).unwrap();
+        assert!(
+            program.layers.len() == 100,
+            "{} layers instead of 100",
+            program.layers.len()
+        );
+        assert!(
+            program
+                .layers
+                .iter()
+                .enumerate()
+                .all(|(index, layer)| (layer.z - (index + 1) as f64 * 0.3).abs() < 1e-9),
+            "Layer heights are wrong"
+        );
+        assert!(
+            program
+                .moves
+                .iter()
+                .filter(|gcode_move| gcode_move.is_extrusion())
+                .all(|gcode_move| gcode_move.feature != Feature::Other
+                    && gcode_move.to.x >= 0.0
+                    && gcode_move.to.x <= 10.0
+                    && gcode_move.to.y >= 0.0
+                    && gcode_move.to.y <= 20.0),
+            "Extrusions are outside the box or without a feature"
+        );
+        let outer_wall = program.moves[program.layers[3].moves.clone()]
+            .iter()
+            .filter(|gcode_move| {
+                gcode_move.is_extrusion() && gcode_move.feature == Feature::OuterWall
+            })
+            .map(GcodeMove::length)
+            .sum::<f64>();
+        assert!(
+            (outer_wall - 2.0 * (9.55 + 19.55)).abs() < 1e-6,
+            "Outer wall of a layer is {} long",
+            outer_wall
+        );
+    }
+
+    #[test]
+    fn test_positioning_and_extrusion_modes() {
+        let program = parse_gcode(
+            "G21\nG90\nM82\nG1 X10 Y10 Z0.2 F600\nG1 X20 E5\nG92 E0\nG1 X30 E2\n\
+             M83\nG1 X40 E1\nG91\nG1 X5 Y-5 E1\nG92 X0 Y0\nG90\nM83\nG1 X1 Y1 E1\n",
+        )
+        .unwrap();
+
+        let extrusions: Vec<f64> = program.moves.iter().map(|m| m.extrusion).collect();
+        assert!(
+            extrusions == vec![0.0, 5.0, 2.0, 1.0, 1.0, 1.0],
+            "Extrusions are wrong: {:?}",
+            extrusions
+        );
+        let ends: Vec<DVec3> = program.moves.iter().map(|m| m.to).collect();
+        assert!(
+            ends[4] == DVec3::new(45.0, 5.0, 0.2) && ends[5] == DVec3::new(46.0, 6.0, 0.2),
+            "Relative moves or the G92 origin are wrong: {:?}",
+            ends
+        );
+        assert!(
+            program.moves.iter().all(|m| m.feedrate == 600.0),
+            "Feedrate is not kept between moves"
+        );
+    }
+
+    #[test]
+    fn test_arcs_are_split_into_lines() {
+        let program =
+            parse_gcode("G1 X10 Y0 Z0.2\nG3 X-10 Y0 I-10 J0 E2.5\nG2 X10 Y0 R10\n").unwrap();
+        let arc: Vec<&GcodeMove> = program.moves[1..].iter().collect();
+
+        assert!(
+            arc.iter()
+                .all(|m| (m.to.truncate().length() - 10.0).abs() < 1e-9),
+            "Arc points are not on the circle"
+        );
+        let counter_clockwise = arc.iter().take_while(|m| m.extrusion > 0.0);
+        assert!(
+            counter_clockwise.clone().all(|m| m.to.y >= -1e-9),
+            "Counter clockwise arc does not pass through positive Y"
+        );
+        let extrusion: f64 = counter_clockwise.map(|m| m.extrusion).sum();
+        assert!(
+            (extrusion - 2.5).abs() < 1e-9,
+            "Extrusion is not spread over the arc: {}",
+            extrusion
+        );
+        assert!(
+            arc.iter()
+                .filter(|m| m.extrusion == 0.0)
+                .all(|m| m.to.y >= -1e-9),
+            "Clockwise arc from the left does not pass through positive Y"
+        );
+    }
+
+    #[test]
+    fn test_invalid_number_is_reported_with_its_line() {
+        assert!(
+            parse_gcode("M117 Printing 50%\nN10 G1 X1 (move) *71\n").is_ok(),
+            "Message, line number or checksum was not accepted"
+        );
+        let error = parse_gcode("G1 X1\nG1 X1..2\n").unwrap_err();
+        assert!(
+            error.to_string().contains("line 2"),
+            "Line is missing from the error: {}",
+            error
+        );
+    }
+}
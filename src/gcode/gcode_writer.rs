@@ -0,0 +1,606 @@
+use anyhow::{anyhow, Result};
+use glam::DVec2;
+use std::f64::consts::PI;
+
+use super::feature::Feature;
+use crate::slicer::planar_slicer::{Contour, Layer};
+
+/// Part of the line width infill overlaps the innermost perimeter so the two bond.
+const INFILL_OVERLAP: f64 = 0.25;
+/// Infill lines shorter than this fraction of the line width are not printed.
+const MIN_INFILL_FRACTION: f64 = 0.5;
+/// A layer is exposed when a neighbouring layer has less area than this fraction of it.
+const EXPOSED_AREA_TOLERANCE: f64 = 1e-6;
+
+pub const DEFAULT_START_GCODE: &str = "M140 S{bed_temperature}
+M104 S{nozzle_temperature}
+M190 S{bed_temperature}
+M109 S{nozzle_temperature}
+G28
+G92 E0";
+
+pub const DEFAULT_END_GCODE: &str = "M104 S0
+M140 S0
+G91
+G1 Z5 F600
+G90
+G28 X0 Y0
+M84";
+
+/// Settings of the printer and the toolpaths. Lengths are in millimetres, speeds in
+/// millimetres per second.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GcodeSettings {
+    pub line_width: f64,
+    pub filament_diameter: f64,
+    /// Closed loops printed along every contour before the infill.
+    pub perimeters: usize,
+    /// Fraction of the area inside the perimeters filled on layers that are not solid.
+    pub infill_density: f64,
+    /// Direction of the infill lines in degrees, turned by 90 degrees on every other layer.
+    pub infill_angle: f64,
+    pub top_layers: usize,
+    pub bottom_layers: usize,
+    pub print_speed: f64,
+    pub first_layer_speed: f64,
+    pub travel_speed: f64,
+    pub retraction_length: f64,
+    pub retraction_speed: f64,
+    /// Travels shorter than this are made without retracting.
+    pub retraction_min_travel: f64,
+    pub nozzle_temperature: u32,
+    pub bed_temperature: u32,
+    /// Fan speed from the second layer on, 0 to 255.
+    pub fan_speed: u8,
+    /// Scripts around the print. `{nozzle_temperature}` and `{bed_temperature}` are
+    /// replaced by the temperatures above.
+    pub start_gcode: String,
+    pub end_gcode: String,
+}
+
+impl Default for GcodeSettings {
+    fn default() -> Self {
+        Self {
+            line_width: 0.45,
+            filament_diameter: 1.75,
+            perimeters: 2,
+            infill_density: 0.2,
+            infill_angle: 45.0,
+            top_layers: 4,
+            bottom_layers: 4,
+            print_speed: 50.0,
+            first_layer_speed: 20.0,
+            travel_speed: 150.0,
+            retraction_length: 2.0,
+            retraction_speed: 40.0,
+            retraction_min_travel: 1.0,
+            nozzle_temperature: 210,
+            bed_temperature: 60,
+            fan_speed: 255,
+            start_gcode: DEFAULT_START_GCODE.to_string(),
+            end_gcode: DEFAULT_END_GCODE.to_string(),
+        }
+    }
+}
+
+impl GcodeSettings {
+    fn validate(&self) -> Result<()> {
+        let positive = [
+            ("Line width", self.line_width),
+            ("Filament diameter", self.filament_diameter),
+            ("Print speed", self.print_speed),
+            ("First layer speed", self.first_layer_speed),
+            ("Travel speed", self.travel_speed),
+            ("Retraction speed", self.retraction_speed),
+        ];
+        for (name, value) in positive {
+            if value.is_nan() || value <= 0.0 {
+                return Err(anyhow!("{} must be positive, not {}", name, value));
+            }
+        }
+        if !(0.0..=1.0).contains(&self.infill_density) {
+            return Err(anyhow!(
+                "Infill density must be between 0 and 1, not {}",
+                self.infill_density
+            ));
+        }
+        if self.retraction_length.is_nan() || self.retraction_length < 0.0 {
+            return Err(anyhow!(
+                "Retraction length must not be negative, not {}",
+                self.retraction_length
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Writes Marlin G-code printing the layers from the bed up, in absolute coordinates
+/// and relative extrusion.
+///
+/// The contours have to be in millimetres. Every layer is printed `layer_height` above
+/// the previous one, whatever the height of the model above the bed. The output only
+/// depends on its inputs, so the same model always gives the same text.
+pub fn generate_gcode(
+    layers: &[Layer],
+    layer_height: f64,
+    settings: &GcodeSettings,
+) -> Result<String> {
+    if layer_height.is_nan() || layer_height <= 0.0 {
+        return Err(anyhow!(
+            "Layer height must be positive, not {}",
+            layer_height
+        ));
+    }
+    settings.validate()?;
+
+    let filament_area = PI * settings.filament_diameter * settings.filament_diameter / 4.0;
+    let mut writer = Writer {
+        output: String::new(),
+        settings,
+        extrusion_per_mm: settings.line_width * layer_height / filament_area,
+        position: None,
+        feedrate: None,
+        feature: None,
+        retracted: false,
+    };
+
+    writer.line("; generated by AMRUST");
+    writer.line(&format!("; layer_height = {}", number(layer_height, 3)));
+    writer.line(&format!(
+        "; line_width = {}",
+        number(settings.line_width, 3)
+    ));
+    writer.line(&format!("; perimeters = {}", settings.perimeters));
+    writer.line(&format!(
+        "; infill_density = {}",
+        number(settings.infill_density, 3)
+    ));
+    writer.line(&format!("; layer_count = {}", layers.len()));
+    writer.script(&settings.start_gcode);
+    writer.line("G21");
+    writer.line("G90");
+    writer.line("M83");
+    writer.line("M107");
+
+    let solid = solid_layers(layers, settings);
+    for (index, layer) in layers.iter().enumerate() {
+        writer.line(&format!(";LAYER:{}", index));
+        if index == 1 && settings.fan_speed > 0 {
+            writer.line(&format!("M106 S{}", settings.fan_speed));
+        }
+        let z = (index + 1) as f64 * layer_height;
+        writer.retract();
+        writer.line(&format!(
+            "G0 Z{} F{}",
+            number(z, 3),
+            number(settings.travel_speed * 60.0, 0)
+        ));
+        writer.feedrate = None;
+
+        let speed = if index == 0 {
+            settings.first_layer_speed
+        } else {
+            settings.print_speed
+        };
+        writer.layer(layer, index, solid[index], speed);
+    }
+
+    writer.retract();
+    writer.line("M107");
+    writer.script(&settings.end_gcode);
+    Ok(writer.output)
+}
+
+/// Layers printed fully solid: the bottom and top layers of the print, and those under
+/// or over a layer with less area, where part of them is a surface.
+///
+/// The contours of neighbouring layers are not clipped against each other, so a whole
+/// layer is made solid when only part of it is exposed.
+fn solid_layers(layers: &[Layer], settings: &GcodeSettings) -> Vec<bool> {
+    let areas: Vec<f64> = layers.iter().map(Layer::area).collect();
+    (0..layers.len())
+        .map(|index| {
+            let exposed =
+                |other: usize| areas[other] < areas[index] * (1.0 - EXPOSED_AREA_TOLERANCE);
+            index < settings.bottom_layers
+                || index + settings.top_layers >= layers.len()
+                || (index.saturating_sub(settings.bottom_layers)..index).any(exposed)
+                || (index + 1..=index + settings.top_layers).any(exposed)
+        })
+        .collect()
+}
+
+struct Writer<'a> {
+    output: String,
+    settings: &'a GcodeSettings,
+    /// Millimetres of filament for a millimetre of extruded line.
+    extrusion_per_mm: f64,
+    position: Option<DVec2>,
+    feedrate: Option<f64>,
+    feature: Option<Feature>,
+    retracted: bool,
+}
+
+impl Writer<'_> {
+    fn line(&mut self, line: &str) {
+        self.output.push_str(line);
+        self.output.push('\n');
+    }
+
+    fn script(&mut self, script: &str) {
+        let script = script
+            .replace(
+                "{nozzle_temperature}",
+                &self.settings.nozzle_temperature.to_string(),
+            )
+            .replace(
+                "{bed_temperature}",
+                &self.settings.bed_temperature.to_string(),
+            );
+        for line in script.lines() {
+            self.line(line.trim_end());
+        }
+    }
+
+    /// Walls from the inside out, then the infill.
+    fn layer(&mut self, layer: &Layer, index: usize, solid: bool, speed: f64) {
+        let width = self.settings.line_width;
+        for perimeter in (0..self.settings.perimeters).rev() {
+            let feature = if perimeter == 0 {
+                Feature::OuterWall
+            } else {
+                Feature::InnerWall
+            };
+            let distance = (perimeter as f64 + 0.5) * width;
+            for contour in layer.contours.iter().filter_map(|c| c.offset(distance)) {
+                self.set_feature(feature);
+                self.closed_loop(&contour.points, speed);
+            }
+        }
+
+        let (feature, density) = if solid {
+            (Feature::Skin, 1.0)
+        } else {
+            (Feature::Fill, self.settings.infill_density)
+        };
+        if density <= 0.0 {
+            return;
+        }
+        let distance = if self.settings.perimeters == 0 {
+            width / 2.0
+        } else {
+            (self.settings.perimeters as f64 + 0.5 - INFILL_OVERLAP) * width
+        };
+        let boundary: Vec<Contour> = layer
+            .contours
+            .iter()
+            .filter_map(|contour| contour.offset(distance))
+            .collect();
+        let angle = self.settings.infill_angle + if index % 2 == 1 { 90.0 } else { 0.0 };
+        let lines = infill_lines(
+            &boundary,
+            width / density,
+            angle,
+            width * MIN_INFILL_FRACTION,
+        );
+        for [from, to] in lines {
+            self.set_feature(feature);
+            self.travel(from);
+            self.extrude(to, speed);
+        }
+    }
+
+    fn set_feature(&mut self, feature: Feature) {
+        if self.feature != Some(feature) {
+            self.feature = Some(feature);
+            self.line(&format!(";TYPE:{}", feature.type_name()));
+        }
+    }
+
+    /// Prints a loop starting at the point closest to the nozzle.
+    fn closed_loop(&mut self, points: &[DVec2], speed: f64) {
+        let start = match self.position {
+            Some(position) => (0..points.len())
+                .min_by(|&a, &b| {
+                    position
+                        .distance_squared(points[a])
+                        .total_cmp(&position.distance_squared(points[b]))
+                })
+                .unwrap_or(0),
+            None => 0,
+        };
+        self.travel(points[start]);
+        for offset in 1..=points.len() {
+            self.extrude(points[(start + offset) % points.len()], speed);
+        }
+    }
+
+    fn travel(&mut self, to: DVec2) {
+        if let Some(position) = self.position {
+            if position.distance(to) < 1e-9 {
+                return;
+            }
+            if position.distance(to) >= self.settings.retraction_min_travel {
+                self.retract();
+            }
+        }
+        let feedrate = self.feedrate_parameter(self.settings.travel_speed);
+        self.line(&format!(
+            "G0 X{} Y{}{}",
+            number(to.x, 3),
+            number(to.y, 3),
+            feedrate
+        ));
+        self.position = Some(to);
+    }
+
+    fn extrude(&mut self, to: DVec2, speed: f64) {
+        let from = self.position.unwrap_or(to);
+        self.unretract();
+        let feedrate = self.feedrate_parameter(speed);
+        self.line(&format!(
+            "G1 X{} Y{} E{}{}",
+            number(to.x, 3),
+            number(to.y, 3),
+            number(from.distance(to) * self.extrusion_per_mm, 5),
+            feedrate
+        ));
+        self.position = Some(to);
+    }
+
+    fn retract(&mut self) {
+        if self.retracted || self.settings.retraction_length <= 0.0 {
+            return;
+        }
+        self.line(&format!(
+            "G1 E-{} F{}",
+            number(self.settings.retraction_length, 5),
+            number(self.settings.retraction_speed * 60.0, 0)
+        ));
+        self.feedrate = None;
+        self.retracted = true;
+    }
+
+    fn unretract(&mut self) {
+        if !self.retracted {
+            return;
+        }
+        self.line(&format!(
+            "G1 E{} F{}",
+            number(self.settings.retraction_length, 5),
+            number(self.settings.retraction_speed * 60.0, 0)
+        ));
+        self.feedrate = None;
+        self.retracted = false;
+    }
+
+    /// ` F...` in millimetres per minute when the speed changes, empty otherwise.
+    fn feedrate_parameter(&mut self, speed: f64) -> String {
+        let feedrate = speed * 60.0;
+        if self.feedrate == Some(feedrate) {
+            return String::new();
+        }
+        self.feedrate = Some(feedrate);
+        format!(" F{}", number(feedrate, 0))
+    }
+}
+
+/// Parallel lines filling the area inside the contours, `spacing` apart at `angle`
+/// degrees from the X axis.
+///
+/// Lines lie on a grid fixed to the origin so infill lines up between layers. They are
+/// ordered in a zigzag, every other line running backwards.
+fn infill_lines(
+    contours: &[Contour],
+    spacing: f64,
+    angle: f64,
+    min_length: f64,
+) -> Vec<[DVec2; 2]> {
+    // turn the contours so the lines are horizontal
+    let rotation = DVec2::from_angle(-angle.to_radians());
+    let polygons: Vec<Vec<DVec2>> = contours
+        .iter()
+        .map(|contour| contour.points.iter().map(|p| rotation.rotate(*p)).collect())
+        .collect();
+    let Some((min, max)) = polygons.iter().flatten().fold(None, |bounds, &point| {
+        Some(match bounds {
+            None => (point.y, point.y),
+            Some((min, max)) => (point.y.min(min), point.y.max(max)),
+        })
+    }) else {
+        return Vec::new();
+    };
+
+    let back = DVec2::from_angle(angle.to_radians());
+    let mut lines = Vec::new();
+    let first = (min / spacing - 0.5).ceil() as i64;
+    let last = (max / spacing - 0.5).floor() as i64;
+    for (row, step) in (first..=last).enumerate() {
+        let y = (step as f64 + 0.5) * spacing;
+
+        // crossings with the direction of the edge, outer contours are counter
+        // clockwise so material is where more edges went down than up
+        let mut crossings: Vec<(f64, i32)> = Vec::new();
+        for polygon in &polygons {
+            for (index, &a) in polygon.iter().enumerate() {
+                let b = polygon[(index + 1) % polygon.len()];
+                if (a.y <= y) == (b.y <= y) {
+                    continue;
+                }
+                let x = a.x + (y - a.y) / (b.y - a.y) * (b.x - a.x);
+                crossings.push((x, if b.y < a.y { 1 } else { -1 }));
+            }
+        }
+        crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut row_lines = Vec::new();
+        let mut winding = 0;
+        let mut start = 0.0;
+        for (x, direction) in crossings {
+            let was_inside = winding > 0;
+            winding += direction;
+            if !was_inside && winding > 0 {
+                start = x;
+            } else if was_inside && winding <= 0 && x - start >= min_length {
+                row_lines.push([DVec2::new(start, y), DVec2::new(x, y)]);
+            }
+        }
+
+        if row % 2 == 1 {
+            row_lines.reverse();
+            for line in &mut row_lines {
+                line.swap(0, 1);
+            }
+        }
+        lines.extend(
+            row_lines
+                .into_iter()
+                .map(|line| line.map(|point| back.rotate(point))),
+        );
+    }
+
+    lines
+}
+
+/// Formats with at most `decimals` decimals and no trailing zeros.
+fn number(value: f64, decimals: usize) -> String {
+    let text = format!("{:.*}", decimals, value);
+    let text = if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &text
+    };
+    if text == "-0" {
+        "0".to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        slicer::planar_slicer::{self, SliceSettings},
+        test_support::{open_model_parts_from_test_resource, test_resource_path},
+    };
+    use std::{env, fs};
+
+    fn square(min: f64, max: f64) -> Contour {
+        Contour {
+            points: vec![
+                DVec2::new(min, min),
+                DVec2::new(max, min),
+                DVec2::new(max, max),
+                DVec2::new(min, max),
+            ],
+            is_hole: false,
+        }
+    }
+
+    /// Compares the G-code of the box with `box.gcode`. Run with `UPDATE_GOLDEN=1` to
+    /// write the file again after an intended change of the output.
+    #[test]
+    fn test_box_gcode_matches_golden_file() {
+        let model_parts = open_model_parts_from_test_resource("box.3mf");
+        let slice_settings = SliceSettings { layer_height: 0.3 };
+        let layers = planar_slicer::slice_model(&model_parts, &slice_settings).unwrap();
+
+        let gcode = generate_gcode(
+            &layers,
+            slice_settings.layer_height,
+            &GcodeSettings::default(),
+        )
+        .unwrap();
+        assert!(
+            gcode == generate_gcode(&layers, 0.3, &GcodeSettings::default()).unwrap(),
+            "Output is not deterministic"
+        );
+
+        let golden_path = test_resource_path("box.gcode");
+        if env::var("UPDATE_GOLDEN").is_ok() {
+            fs::write(&golden_path, &gcode).unwrap();
+        }
+        let golden = fs::read_to_string(&golden_path)
+            .unwrap()
+            .replace("\r\n", "\n");
+        if let Some((line, (expected, actual))) = golden
+            .lines()
+            .zip(gcode.lines())
+            .enumerate()
+            .find(|(_, (expected, actual))| expected != actual)
+        {
+            panic!(
+                "Line {} differs from the golden file: expected {:?}, got {:?}",
+                line + 1,
+                expected,
+                actual
+            );
+        }
+        assert!(
+            golden.lines().count() == gcode.lines().count(),
+            "Number of lines differs from the golden file"
+        );
+    }
+
+    #[test]
+    fn test_infill_lines_are_clipped_and_spaced() {
+        let mut hole = square(4.0, 6.0);
+        hole.points.reverse();
+        hole.is_hole = true;
+
+        let lines = infill_lines(&[square(0.0, 10.0), hole], 1.0, 0.0, 0.1);
+        assert!(lines.len() == 12, "{} lines instead of 12", lines.len());
+        assert!(
+            lines[0] == [DVec2::new(0.0, 0.5), DVec2::new(10.0, 0.5)]
+                && lines[1] == [DVec2::new(10.0, 1.5), DVec2::new(0.0, 1.5)],
+            "Lines are not in a zigzag: {:?}",
+            &lines[..2]
+        );
+        let length: f64 = lines.iter().map(|[a, b]| a.distance(*b)).sum();
+        assert!(
+            (length - 96.0).abs() < 1e-9,
+            "Length of the lines is {} instead of the area 96",
+            length
+        );
+    }
+
+    #[test]
+    fn test_top_and_bottom_layers_are_solid() {
+        let layers: Vec<Layer> = (0..10)
+            .map(|index| Layer {
+                index,
+                z: (index + 1) as f64 * 0.2,
+                contours: vec![square(0.0, if index < 5 { 10.0 } else { 5.0 })],
+            })
+            .collect();
+        let settings = GcodeSettings {
+            top_layers: 2,
+            bottom_layers: 2,
+            ..GcodeSettings::default()
+        };
+
+        let solid = solid_layers(&layers, &settings);
+        assert!(
+            solid == vec![true, true, false, true, true, false, false, false, true, true],
+            "Solid layers are wrong: {:?}",
+            solid
+        );
+    }
+
+    #[test]
+    fn test_invalid_settings_are_rejected() {
+        let settings = GcodeSettings {
+            infill_density: 1.5,
+            ..GcodeSettings::default()
+        };
+        assert!(
+            generate_gcode(&[], 0.2, &settings).is_err(),
+            "Infill density above 1 was accepted"
+        );
+        assert!(
+            generate_gcode(&[], 0.0, &GcodeSettings::default()).is_err(),
+            "Layer height of 0 was accepted"
+        );
+    }
+}
@@ -0,0 +1,4 @@
+pub mod feature;
+pub mod gcode_reader;
+pub mod gcode_writer;
+pub mod print_estimate;
@@ -0,0 +1,286 @@
+use anyhow::{anyhow, Result};
+use glam::DVec3;
+use std::f64::consts::PI;
+
+use super::gcode_reader::GcodeProgram;
+
+/// Motion limits of the printer, in millimetres and seconds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MachineLimits {
+    pub max_acceleration: f64,
+    /// Largest instant change of velocity at a corner.
+    pub max_jerk: f64,
+    pub max_feedrate: f64,
+}
+
+impl Default for MachineLimits {
+    fn default() -> Self {
+        Self {
+            max_acceleration: 1000.0,
+            max_jerk: 10.0,
+            max_feedrate: 200.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilamentSettings {
+    /// Diameter in millimetres.
+    pub diameter: f64,
+    /// Density in grams per cubic centimetre.
+    pub density: f64,
+    /// Price of a kilogram in any currency.
+    pub price_per_kg: f64,
+}
+
+impl Default for FilamentSettings {
+    fn default() -> Self {
+        // PLA
+        Self {
+            diameter: 1.75,
+            density: 1.24,
+            price_per_kg: 20.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrintEstimate {
+    /// Seconds of every layer of the program, in the order of its layers.
+    pub layer_times: Vec<f64>,
+    /// Filament used in millimetres, retractions that are pushed back do not count.
+    pub filament_length: f64,
+    /// Cubic millimetres.
+    pub filament_volume: f64,
+    /// Grams.
+    pub filament_weight: f64,
+    pub filament_cost: f64,
+}
+
+impl PrintEstimate {
+    /// Seconds of the whole print.
+    pub fn total_time(&self) -> f64 {
+        self.layer_times.iter().sum()
+    }
+}
+
+/// Estimates how long the program takes and how much filament it uses.
+///
+/// Every move accelerates and decelerates with the maximum acceleration, a trapezoid
+/// of speed over time, and passes corners as fast as the jerk limit allows. Moves of
+/// only the filament are taken at constant speed and stop the nozzle, as retractions
+/// mostly do. Heating and other waiting is not counted.
+pub fn estimate_print(
+    program: &GcodeProgram,
+    limits: &MachineLimits,
+    filament: &FilamentSettings,
+) -> Result<PrintEstimate> {
+    for (name, value) in [
+        ("Maximum acceleration", limits.max_acceleration),
+        ("Maximum jerk", limits.max_jerk),
+        ("Maximum feedrate", limits.max_feedrate),
+        ("Filament diameter", filament.diameter),
+    ] {
+        if value.is_nan() || value <= 0.0 {
+            return Err(anyhow!("{} must be positive, not {}", name, value));
+        }
+    }
+
+    let mut layer_times = vec![0.0; program.layers.len().max(1)];
+    let mut motion: Vec<Motion> = Vec::new();
+    for gcode_move in &program.moves {
+        let speed = match gcode_move.feedrate / 60.0 {
+            speed if speed > 0.0 => speed.min(limits.max_feedrate),
+            _ => limits.max_feedrate,
+        };
+        let layer = gcode_move.layer.min(layer_times.len() - 1);
+
+        let length = gcode_move.length();
+        if length > 0.0 {
+            motion.push(Motion {
+                direction: (gcode_move.to - gcode_move.from) / length,
+                length,
+                speed,
+                layer,
+            });
+            continue;
+        }
+
+        // the nozzle stops while only the filament moves
+        add_motion_times(&motion, limits, &mut layer_times);
+        motion.clear();
+        layer_times[layer] += gcode_move.extrusion.abs() / speed;
+    }
+    add_motion_times(&motion, limits, &mut layer_times);
+
+    let filament_length = program
+        .moves
+        .iter()
+        .map(|gcode_move| gcode_move.extrusion)
+        .sum::<f64>()
+        .max(0.0);
+    let filament_volume = filament_length * PI * filament.diameter * filament.diameter / 4.0;
+    // a cubic centimetre is a thousand cubic millimetres
+    let filament_weight = filament_volume / 1000.0 * filament.density;
+
+    if program.layers.is_empty() {
+        layer_times.clear();
+    }
+    Ok(PrintEstimate {
+        layer_times,
+        filament_length,
+        filament_volume,
+        filament_weight,
+        filament_cost: filament_weight / 1000.0 * filament.price_per_kg,
+    })
+}
+
+/// A move of the nozzle in an uninterrupted run of moves.
+struct Motion {
+    direction: DVec3,
+    length: f64,
+    /// Requested speed in millimetres per second.
+    speed: f64,
+    layer: usize,
+}
+
+/// Plans the speeds of a run of moves that starts and ends at rest and adds the time of
+/// every move to its layer.
+fn add_motion_times(motion: &[Motion], limits: &MachineLimits, layer_times: &mut [f64]) {
+    if motion.is_empty() {
+        return;
+    }
+    let acceleration = limits.max_acceleration;
+
+    // speeds at the start of every move and at the end of the last one
+    let mut junctions = vec![0.0; motion.len() + 1];
+    for index in 1..motion.len() {
+        let (before, after) = (&motion[index - 1], &motion[index]);
+        // the change of velocity at the corner grows with the speed through it
+        let turn = (before.direction - after.direction).length();
+        let mut speed = before.speed.min(after.speed);
+        if turn > 0.0 {
+            speed = speed.min(limits.max_jerk / turn);
+        }
+        junctions[index] = speed;
+    }
+
+    // reachable speeds, braking towards the end and accelerating from the start
+    for index in (0..motion.len()).rev() {
+        let reachable =
+            (junctions[index + 1].powi(2) + 2.0 * acceleration * motion[index].length).sqrt();
+        junctions[index] = junctions[index].min(reachable);
+    }
+    for index in 0..motion.len() {
+        let reachable =
+            (junctions[index].powi(2) + 2.0 * acceleration * motion[index].length).sqrt();
+        junctions[index + 1] = junctions[index + 1].min(reachable);
+    }
+
+    for (index, motion) in motion.iter().enumerate() {
+        layer_times[motion.layer] += trapezoid_time(
+            motion.length,
+            junctions[index],
+            motion.speed,
+            junctions[index + 1],
+            acceleration,
+        );
+    }
+}
+
+/// Time of a move of `length` entered at `entry` speed, cruising at `cruise` and left at
+/// `exit` speed. Moves too short to reach the cruise speed accelerate to a lower peak.
+fn trapezoid_time(length: f64, entry: f64, cruise: f64, exit: f64, acceleration: f64) -> f64 {
+    let accelerating = (cruise * cruise - entry * entry) / (2.0 * acceleration);
+    let decelerating = (cruise * cruise - exit * exit) / (2.0 * acceleration);
+    if accelerating + decelerating <= length {
+        return (cruise - entry) / acceleration
+            + (cruise - exit) / acceleration
+            + (length - accelerating - decelerating) / cruise;
+    }
+
+    let peak = ((2.0 * acceleration * length + entry * entry + exit * exit) / 2.0).sqrt();
+    (peak - entry).max(0.0) / acceleration + (peak - exit).max(0.0) / acceleration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gcode::gcode_reader::parse_gcode;
+
+    fn estimate(gcode: &str) -> PrintEstimate {
+        estimate_print(
+            &parse_gcode(gcode).unwrap(),
+            &MachineLimits::default(),
+            &FilamentSettings::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_straight_move_is_a_trapezoid() {
+        // 0.05 s to reach 50 mm/s over 1.25 mm, the same to stop
+        let time = estimate("G1 X100 F3000\n").total_time();
+        assert!(
+            (time - 2.05).abs() < 1e-9,
+            "Move takes {} s instead of 2.05 s",
+            time
+        );
+
+        // 1 mm is too short to reach 50 mm/s, the peak is sqrt(1000)
+        let time = estimate("G1 X1 F3000\n").total_time();
+        assert!(
+            (time - 2.0 * 1000.0_f64.sqrt() / 1000.0).abs() < 1e-9,
+            "Short move takes {} s",
+            time
+        );
+    }
+
+    #[test]
+    fn test_corners_slow_down_and_straight_runs_do_not() {
+        let split = estimate("G1 X50 F3000\nG1 X100\n").total_time();
+        assert!(
+            (split - 2.05).abs() < 1e-9,
+            "Straight run split in two takes {} s instead of 2.05 s",
+            split
+        );
+
+        let corner = estimate("G1 X50 F3000\nG1 X50 Y50\n").total_time();
+        assert!(
+            corner > split,
+            "Corner takes {} s, not longer than {} s",
+            corner,
+            split
+        );
+    }
+
+    #[test]
+    fn test_filament_use() {
+        let estimate = estimate(
+            "M83\nG1 X10 Z0.2 E100 F1200\nG1 E-2 F2400\nG1 X20 F9000\nG1 E2 F2400\n\
+             G1 X30 Z0.4 E900 F1200\n",
+        );
+
+        assert!(
+            (estimate.filament_length - 1000.0).abs() < 1e-9,
+            "Retractions are counted in the length of {} mm",
+            estimate.filament_length
+        );
+        let volume = 1000.0 * PI * 1.75 * 1.75 / 4.0;
+        assert!(
+            (estimate.filament_weight - volume / 1000.0 * 1.24).abs() < 1e-9,
+            "Weight is {} g",
+            estimate.filament_weight
+        );
+        assert!(
+            (estimate.filament_cost - estimate.filament_weight * 0.02).abs() < 1e-9,
+            "Cost is {}",
+            estimate.filament_cost
+        );
+        assert!(
+            estimate.layer_times.len() == 2 && estimate.layer_times.iter().all(|&t| t > 0.0),
+            "Layer times are wrong: {:?}",
+            estimate.layer_times
+        );
+    }
+}
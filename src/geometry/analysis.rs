@@ -0,0 +1,359 @@
+use anyhow::Result;
+use glam::{DMat3, DVec3};
+use threemf::model::Unit;
+
+use super::build::{bounds_of_build, flatten_build, flatten_object, BuildMesh};
+use crate::threemf::threemf_reader::ModelParts;
+
+/// Below this volume a mesh is treated as open or flat and its centroid is taken
+/// from the surface instead.
+const VOLUME_EPSILON: f64 = 1e-12;
+const JACOBI_ITERATIONS: usize = 32;
+
+/// A box with arbitrary orientation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrientedBox {
+    pub center: DVec3,
+    /// Unit axes of the box, ordered from the longest extent to the shortest.
+    pub axes: [DVec3; 3],
+    pub half_extents: DVec3,
+}
+
+impl OrientedBox {
+    pub fn size(&self) -> DVec3 {
+        self.half_extents * 2.0
+    }
+}
+
+/// Measurements of a mesh, in the unit of the model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeshReport {
+    pub vertex_count: usize,
+    pub triangle_count: usize,
+    /// Enclosed volume from signed tetrahedra, only meaningful for closed meshes.
+    pub volume: f64,
+    pub surface_area: f64,
+    /// Axis aligned bounds as `(min, max)`.
+    pub bounding_box: Option<(DVec3, DVec3)>,
+    pub oriented_box: Option<OrientedBox>,
+    pub centroid: Option<DVec3>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ObjectReport {
+    pub part: String,
+    pub object_id: usize,
+    pub name: Option<String>,
+    pub report: MeshReport,
+}
+
+#[derive(Debug, Clone)]
+pub struct ItemReport {
+    pub index: usize,
+    pub object_id: usize,
+    pub report: MeshReport,
+}
+
+/// Measurements of every object and build item of a model.
+#[derive(Debug, Clone)]
+pub struct ModelReport {
+    /// Name of the `unit` of the root model, e.g. `mm`.
+    pub unit: &'static str,
+    pub millimeters_per_unit: f64,
+    /// Objects in their own coordinate system, components included.
+    pub objects: Vec<ObjectReport>,
+    /// Build items in build coordinates, transforms applied.
+    pub items: Vec<ItemReport>,
+}
+
+/// Measures every object of every model part and every build item of the root model.
+pub fn analyse_model(model_parts: &ModelParts) -> Result<ModelReport> {
+    let (unit, millimeters_per_unit) = unit_info(&model_parts.root_part().model.unit);
+
+    let mut objects = Vec::new();
+    for part in model_parts.parts.values() {
+        for object in &part.model.resources.object {
+            let mesh = flatten_object(model_parts, &part.name, object.id)?;
+            objects.push(ObjectReport {
+                part: part.name.clone(),
+                object_id: object.id,
+                name: object.name.clone(),
+                report: analyse_mesh(&mesh),
+            });
+        }
+    }
+
+    let items = flatten_build(model_parts)?
+        .iter()
+        .enumerate()
+        .map(|(index, mesh)| ItemReport {
+            index,
+            object_id: mesh.object_id,
+            report: analyse_mesh(mesh),
+        })
+        .collect();
+
+    Ok(ModelReport {
+        unit,
+        millimeters_per_unit,
+        objects,
+        items,
+    })
+}
+
+pub fn analyse_mesh(mesh: &BuildMesh) -> MeshReport {
+    let mut volume = 0.0;
+    let mut surface_area = 0.0;
+    let mut volume_moment = DVec3::ZERO;
+    let mut area_moment = DVec3::ZERO;
+
+    for triangle in &mesh.triangles {
+        let [a, b, c] = triangle.map(|index| mesh.positions[index as usize]);
+
+        // tetrahedron spanned by the triangle and the origin
+        let tetrahedron_volume = a.dot(b.cross(c)) / 6.0;
+        volume += tetrahedron_volume;
+        volume_moment += tetrahedron_volume * (a + b + c) / 4.0;
+
+        let area = (b - a).cross(c - a).length() / 2.0;
+        surface_area += area;
+        area_moment += area * (a + b + c) / 3.0;
+    }
+
+    let centroid = if volume.abs() > VOLUME_EPSILON {
+        Some(volume_moment / volume)
+    } else if surface_area > 0.0 {
+        Some(area_moment / surface_area)
+    } else {
+        mean_of_points(&mesh.positions)
+    };
+
+    MeshReport {
+        vertex_count: mesh.positions.len(),
+        triangle_count: mesh.triangles.len(),
+        volume,
+        surface_area,
+        bounding_box: bounds_of_build(std::slice::from_ref(mesh)),
+        oriented_box: oriented_box_of_points(&mesh.positions),
+        centroid,
+    }
+}
+
+/// Name and size in millimeters of a 3MF `unit`.
+pub fn unit_info(unit: &Unit) -> (&'static str, f64) {
+    match unit {
+        Unit::Micron => ("µm", 0.001),
+        Unit::Millimeter => ("mm", 1.0),
+        Unit::Centimeter => ("cm", 10.0),
+        Unit::Inch => ("in", 25.4),
+        Unit::Foot => ("ft", 304.8),
+        Unit::Meter => ("m", 1000.0),
+    }
+}
+
+/// Fits a box along the principal axes of the points. The axis aligned box is returned
+/// instead when it is smaller, which principal axes do not guarantee.
+fn oriented_box_of_points(points: &[DVec3]) -> Option<OrientedBox> {
+    let mean = mean_of_points(points)?;
+
+    let mut covariance = DMat3::ZERO;
+    for point in points {
+        let d = *point - mean;
+        covariance += DMat3::from_cols(d * d.x, d * d.y, d * d.z);
+    }
+    let principal = box_along_axes(points, principal_axes(covariance / points.len() as f64));
+    let axis_aligned = box_along_axes(points, [DVec3::X, DVec3::Y, DVec3::Z]);
+
+    let box_volume = |oriented: &OrientedBox| oriented.half_extents.element_product();
+    if box_volume(&principal) < box_volume(&axis_aligned) - VOLUME_EPSILON {
+        Some(principal)
+    } else {
+        Some(axis_aligned)
+    }
+}
+
+fn box_along_axes(points: &[DVec3], axes: [DVec3; 3]) -> OrientedBox {
+    let mut min = DVec3::splat(f64::INFINITY);
+    let mut max = DVec3::splat(f64::NEG_INFINITY);
+    for point in points {
+        let projected = DVec3::new(point.dot(axes[0]), point.dot(axes[1]), point.dot(axes[2]));
+        min = min.min(projected);
+        max = max.max(projected);
+    }
+    let middle = (min + max) / 2.0;
+
+    let mut oriented = OrientedBox {
+        center: axes[0] * middle.x + axes[1] * middle.y + axes[2] * middle.z,
+        axes,
+        half_extents: (max - min) / 2.0,
+    };
+    // longest extent first so boxes are easy to compare
+    let mut order = [0, 1, 2];
+    order.sort_by(|&a, &b| {
+        oriented.half_extents[b]
+            .partial_cmp(&oriented.half_extents[a])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let half_extents = oriented.half_extents;
+    oriented.axes = order.map(|index| axes[index]);
+    oriented.half_extents = DVec3::from_array(order.map(|index| half_extents[index]));
+
+    oriented
+}
+
+/// Eigenvectors of a symmetric matrix with the cyclic Jacobi method.
+fn principal_axes(matrix: DMat3) -> [DVec3; 3] {
+    let mut a = matrix.to_cols_array_2d();
+    let mut vectors = DMat3::IDENTITY.to_cols_array_2d();
+
+    for _ in 0..JACOBI_ITERATIONS {
+        let off_diagonal = a[0][1].powi(2) + a[0][2].powi(2) + a[1][2].powi(2);
+        if off_diagonal < 1e-24 {
+            break;
+        }
+
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if a[p][q].abs() < 1e-30 {
+                continue;
+            }
+            let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+            let c = 1.0 / (t * t + 1.0).sqrt();
+            let s = t * c;
+
+            // a = Jᵀ a J, rotating columns and then rows p and q
+            for row in a.iter_mut() {
+                let (akp, akq) = (row[p], row[q]);
+                row[p] = c * akp - s * akq;
+                row[q] = s * akp + c * akq;
+            }
+            let (row_p, row_q) = (a[p], a[q]);
+            a[p] = std::array::from_fn(|k| c * row_p[k] - s * row_q[k]);
+            a[q] = std::array::from_fn(|k| s * row_p[k] + c * row_q[k]);
+            for vector in vectors.iter_mut() {
+                let (vp, vq) = (vector[p], vector[q]);
+                vector[p] = c * vp - s * vq;
+                vector[q] = s * vp + c * vq;
+            }
+        }
+    }
+
+    // the columns of the accumulated rotation are the eigenvectors
+    let vectors = DMat3::from_cols_array_2d(&vectors).transpose();
+    [vectors.x_axis, vectors.y_axis, vectors.z_axis].map(DVec3::normalize)
+}
+
+fn mean_of_points(points: &[DVec3]) -> Option<DVec3> {
+    if points.is_empty() {
+        return None;
+    }
+    Some(points.iter().sum::<DVec3>() / points.len() as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::open_model_parts_from_test_resource;
+    use glam::DMat4;
+
+    fn approx_eq(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-6
+    }
+
+    #[test]
+    fn test_box_measurements() {
+        let report = analyse_model(&open_model_parts_from_test_resource("box.3mf")).unwrap();
+
+        assert!(report.unit == "mm", "Unit of the model is wrong");
+        assert!(report.objects.len() == 1, "Number of objects is wrong");
+        let box_report = &report.objects[0].report;
+        assert!(
+            box_report.vertex_count == 8 && box_report.triangle_count == 12,
+            "Vertex and triangle counts are wrong"
+        );
+        assert!(
+            approx_eq(box_report.volume, 6000.0),
+            "Volume of the box is {} instead of 6000",
+            box_report.volume
+        );
+        assert!(
+            approx_eq(box_report.surface_area, 2200.0),
+            "Surface area of the box is {} instead of 2200",
+            box_report.surface_area
+        );
+        assert!(
+            box_report.bounding_box == Some((DVec3::ZERO, DVec3::new(10.0, 20.0, 30.0))),
+            "Bounding box is wrong"
+        );
+        assert!(
+            box_report
+                .centroid
+                .unwrap()
+                .abs_diff_eq(DVec3::new(5.0, 10.0, 15.0), 1e-9),
+            "Centroid is not the center of the box"
+        );
+    }
+
+    #[test]
+    fn test_box_oriented_bounding_box() {
+        let report = analyse_model(&open_model_parts_from_test_resource("box.3mf")).unwrap();
+        let oriented = report.objects[0].report.oriented_box.clone().unwrap();
+
+        assert!(
+            oriented
+                .size()
+                .abs_diff_eq(DVec3::new(30.0, 20.0, 10.0), 1e-9),
+            "Oriented box size is {} instead of 30x20x10",
+            oriented.size()
+        );
+        assert!(
+            oriented
+                .center
+                .abs_diff_eq(DVec3::new(5.0, 10.0, 15.0), 1e-9),
+            "Oriented box is not centered on the box"
+        );
+    }
+
+    #[test]
+    fn test_oriented_box_follows_rotation() {
+        let parts = open_model_parts_from_test_resource("box.3mf");
+        let mut mesh = flatten_object(&parts, &parts.root, 1).unwrap();
+        let rotation = DMat4::from_rotation_z(0.5);
+        for position in mesh.positions.iter_mut() {
+            *position = rotation.transform_point3(*position);
+        }
+
+        let report = analyse_mesh(&mesh);
+        let oriented = report.oriented_box.unwrap();
+        assert!(
+            approx_eq(report.volume, 6000.0),
+            "Rotation changed the volume"
+        );
+        assert!(
+            oriented
+                .size()
+                .abs_diff_eq(DVec3::new(30.0, 20.0, 10.0), 1e-6),
+            "Oriented box of a rotated box is {}",
+            oriented.size()
+        );
+    }
+
+    #[test]
+    fn test_build_items_are_measured_in_build_coordinates() {
+        let report = analyse_model(&open_model_parts_from_test_resource("production.3mf")).unwrap();
+
+        assert!(report.items.len() == 2, "Number of build items is wrong");
+        let (min, _) = report.items[1].report.bounding_box.unwrap();
+        assert!(
+            min == DVec3::new(20.0, 0.0, 0.0),
+            "Component transform is not applied to the build item"
+        );
+        assert!(
+            report
+                .items
+                .iter()
+                .all(|item| approx_eq(item.report.volume, 6000.0)),
+            "Build item volume is wrong"
+        );
+    }
+}
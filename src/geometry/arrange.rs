@@ -0,0 +1,276 @@
+use anyhow::Result;
+use glam::{DMat4, DVec2};
+use std::f64::consts::{FRAC_PI_2, SQRT_2};
+
+use super::{
+    analysis::unit_info, build::flatten_build, transform::mat4_from_optional_threemf_transform,
+};
+use crate::{
+    config::printer_profiles::{BedShape, PrinterProfile},
+    threemf::threemf_reader::ModelParts,
+};
+
+/// Overflows smaller than this, in millimetres, are rounding.
+const TOLERANCE: f64 = 1e-6;
+
+/// How [`arrange_build`] packs the build items.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArrangeSettings {
+    /// Gap between footprints in millimetres.
+    pub spacing: f64,
+    /// Whether items may be turned a quarter about Z to pack tighter.
+    pub allow_rotation: bool,
+}
+
+impl Default for ArrangeSettings {
+    fn default() -> Self {
+        Self {
+            spacing: 5.0,
+            allow_rotation: true,
+        }
+    }
+}
+
+/// Where a footprint goes on the bed, in millimetres.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FootprintPlacement {
+    pub center: DVec2,
+    /// Turned a quarter counterclockwise, which swaps its width and depth.
+    pub rotated: bool,
+}
+
+/// New transforms of the build items of the root model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Arrangement {
+    pub transforms: Vec<DMat4>,
+    /// Whether all footprints fit on the bed.
+    pub fits: bool,
+}
+
+/// Packs the footprints of all build items on the bed of the printer, keeping their
+/// height. The same model and settings always give the same arrangement.
+pub fn arrange_build(
+    model_parts: &ModelParts,
+    profile: &PrinterProfile,
+    settings: &ArrangeSettings,
+) -> Result<Arrangement> {
+    let root = model_parts.root_part();
+    let (_, millimeters_per_unit) = unit_info(&root.model.unit);
+
+    // footprints of the items as they are now, in millimetres
+    let footprints: Vec<Option<(DVec2, DVec2)>> = flatten_build(model_parts)?
+        .iter()
+        .map(|mesh| {
+            let mut points = mesh
+                .positions
+                .iter()
+                .map(|position| position.truncate() * millimeters_per_unit);
+            let first = points.next()?;
+            Some(points.fold((first, first), |(min, max), point| {
+                (point.min(min), point.max(max))
+            }))
+        })
+        .collect();
+    let sizes: Vec<Option<DVec2>> = footprints
+        .iter()
+        .map(|footprint| footprint.map(|(min, max)| max - min))
+        .collect();
+    let (placements, fits) = pack_footprints(&sizes, profile, settings);
+
+    let transforms = root
+        .model
+        .build
+        .item
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let current = mat4_from_optional_threemf_transform(item.transform.as_ref());
+            let (Some(Some((min, max))), Some(Some(placement))) =
+                (footprints.get(index), placements.get(index))
+            else {
+                return current;
+            };
+            let center = ((*min + *max) / 2.0 / millimeters_per_unit).extend(0.0);
+            let target = (placement.center / millimeters_per_unit).extend(0.0);
+            let turn = if placement.rotated {
+                DMat4::from_rotation_z(FRAC_PI_2)
+            } else {
+                DMat4::IDENTITY
+            };
+            DMat4::from_translation(target) * turn * DMat4::from_translation(-center) * current
+        })
+        .collect();
+
+    Ok(Arrangement { transforms, fits })
+}
+
+/// Packs footprints of `sizes` in rows from the front of the bed to the back, deepest
+/// first, and centres the rows on the bed. Items without a footprint are not placed.
+/// Returns where every footprint goes and whether they all fit.
+pub fn pack_footprints(
+    sizes: &[Option<DVec2>],
+    profile: &PrinterProfile,
+    settings: &ArrangeSettings,
+) -> (Vec<Option<FootprintPlacement>>, bool) {
+    // the square inside a round bed
+    let area = match profile.shape {
+        BedShape::Rectangular => profile.bed_size(),
+        BedShape::Circular => DVec2::splat(profile.bed_width / SQRT_2),
+    };
+
+    // items are turned to lie along X so rows stay shallow, or when only turned they fit
+    let mut order: Vec<(usize, DVec2, bool)> = sizes
+        .iter()
+        .enumerate()
+        .filter_map(|(index, size)| {
+            let size = (*size)?;
+            let turned = DVec2::new(size.y, size.x);
+            let rotated = settings.allow_rotation
+                && turned.x <= area.x
+                && (size.y > size.x || size.x > area.x);
+            Some((index, if rotated { turned } else { size }, rotated))
+        })
+        .collect();
+    order.sort_by(|a, b| {
+        b.1.y
+            .total_cmp(&a.1.y)
+            .then(b.1.x.total_cmp(&a.1.x))
+            .then(a.0.cmp(&b.0))
+    });
+
+    let mut corners = vec![DVec2::ZERO; sizes.len()];
+    let mut cursor = DVec2::ZERO;
+    let mut row_depth: f64 = 0.0;
+    let mut row_empty = true;
+    let mut block_width: f64 = 0.0;
+    for (index, size, _) in &order {
+        if !row_empty && cursor.x + size.x > area.x {
+            cursor = DVec2::new(0.0, cursor.y + row_depth + settings.spacing);
+            row_depth = 0.0;
+        }
+        corners[*index] = cursor;
+        block_width = block_width.max(cursor.x + size.x);
+        row_depth = row_depth.max(size.y);
+        cursor.x += size.x + settings.spacing;
+        row_empty = false;
+    }
+
+    let block = DVec2::new(block_width, cursor.y + row_depth);
+    let origin = profile.bed_center() - block / 2.0;
+    let mut placements = vec![None; sizes.len()];
+    for (index, size, rotated) in order {
+        placements[index] = Some(FootprintPlacement {
+            center: origin + corners[index] + size / 2.0,
+            rotated,
+        });
+    }
+
+    let fits = block.x <= area.x + TOLERANCE && block.y <= area.y + TOLERANCE;
+    (placements, fits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::build_volume::check_build_volume,
+        test_support::open_model_parts_from_test_resource,
+    };
+
+    #[test]
+    fn test_pack_rows_with_spacing() {
+        let profile = PrinterProfile::default();
+        let settings = ArrangeSettings::default();
+        let sizes = [
+            Some(DVec2::new(100.0, 50.0)),
+            None,
+            Some(DVec2::new(40.0, 120.0)),
+            Some(DVec2::new(100.0, 50.0)),
+        ];
+
+        let (placements, fits) = pack_footprints(&sizes, &profile, &settings);
+        assert!(fits, "Footprints do not fit a 220 mm bed");
+        assert!(placements[1].is_none(), "Item without footprint is placed");
+        let deep = placements[2].unwrap();
+        assert!(deep.rotated, "Deep footprint is not turned");
+        // the deeper footprints share the first row, the turned one is 120 x 40 behind them
+        let (first, second) = (placements[0].unwrap(), placements[3].unwrap());
+        assert!(
+            first.center.y == second.center.y
+                && (second.center.x - first.center.x - 105.0).abs() < 1e-9,
+            "Equal footprints are not next to each other with spacing: {:?} {:?}",
+            first,
+            second
+        );
+        assert!(
+            (deep.center.y - first.center.y - 50.0).abs() < 1e-9,
+            "Rows are not spaced: {:?} {:?}",
+            first,
+            deep
+        );
+
+        let without_rotation = ArrangeSettings {
+            allow_rotation: false,
+            ..settings
+        };
+        let (placements, _) = pack_footprints(&sizes, &profile, &without_rotation);
+        assert!(
+            placements
+                .iter()
+                .flatten()
+                .all(|placement| !placement.rotated),
+            "Footprints are turned without rotation"
+        );
+    }
+
+    #[test]
+    fn test_arranged_items_do_not_overlap() {
+        let mut model_parts = open_model_parts_from_test_resource("box.3mf");
+        let root = model_parts.root.clone();
+        let items = &mut model_parts.parts.get_mut(&root).unwrap().model.build.item;
+        let item = items[0].clone();
+        items.extend([item.clone(), item.clone(), item]);
+
+        let profile = PrinterProfile::default();
+        let settings = ArrangeSettings::default();
+        let arrangement = arrange_build(&model_parts, &profile, &settings).unwrap();
+        assert!(
+            arrangement.fits && arrangement.transforms.len() == 4,
+            "Four boxes are not arranged"
+        );
+
+        let items = &mut model_parts.parts.get_mut(&root).unwrap().model.build.item;
+        for (item, transform) in items.iter_mut().zip(&arrangement.transforms) {
+            item.transform = Some(crate::geometry::transform::threemf_transform_from_mat4(
+                transform,
+            ));
+        }
+        let placements = check_build_volume(&model_parts, &profile).unwrap();
+        assert!(
+            placements.iter().all(|placement| placement.fits()),
+            "Arranged boxes leave the bed: {:?}",
+            placements
+        );
+        for (a, b) in placements
+            .iter()
+            .flat_map(|a| placements[a.index + 1..].iter().map(move |b| (a, b)))
+        {
+            let ((a_min, a_max), (b_min, b_max)) = (a.bounds.unwrap(), b.bounds.unwrap());
+            let apart = a_max.x + settings.spacing <= b_min.x + 1e-9
+                || b_max.x + settings.spacing <= a_min.x + 1e-9
+                || a_max.y + settings.spacing <= b_min.y + 1e-9
+                || b_max.y + settings.spacing <= a_min.y + 1e-9;
+            assert!(apart, "Items {} and {} are too close", a.index, b.index);
+        }
+
+        assert!(
+            arrange_build(&model_parts, &profile, &settings)
+                .unwrap()
+                .transforms
+                .iter()
+                .zip(&arrangement.transforms)
+                .all(|(again, first)| again.abs_diff_eq(*first, 1e-9)),
+            "Arranging again moves the items"
+        );
+    }
+}
@@ -0,0 +1,408 @@
+use anyhow::{anyhow, Result};
+use glam::{DMat4, DVec3};
+use std::collections::{HashMap, HashSet};
+use threemf::model::{Mesh, ObjectData};
+
+use super::transform::mat4_from_optional_threemf_transform;
+use crate::threemf::threemf_reader::ModelParts;
+
+/// Colour of objects without a display colour of their own.
+pub const DEFAULT_COLOR: [f32; 3] = [0.85, 0.55, 0.2];
+
+/// A single build item with all of its meshes and components resolved into
+/// world space triangles.
+#[derive(Debug, Clone)]
+pub struct BuildMesh {
+    pub object_id: usize,
+    pub positions: Vec<DVec3>,
+    /// Display colour of every position.
+    pub colors: Vec<[f32; 3]>,
+    pub triangles: Vec<[u32; 3]>,
+    /// Colours of the corners of triangles with material properties of their own, in
+    /// step with `triangles`. Other triangles have the colours of their positions.
+    pub triangle_colors: Vec<Option<[[f32; 3]; 3]>>,
+    /// Texture, as an index into `textures`, and the texture coordinates of the corners of
+    /// textured triangles, in step with `triangles`.
+    pub triangle_textures: Vec<Option<(u32, [[f64; 2]; 3])>>,
+    /// Part name and id of every texture of the triangles.
+    pub textures: Vec<(String, usize)>,
+    /// Origin of every triangle as an index into `objects` and the index of the triangle
+    /// in the mesh of that object.
+    pub triangle_sources: Vec<(u32, u32)>,
+    /// Part name and id of every mesh object appended to this build mesh.
+    pub objects: Vec<(String, usize)>,
+}
+
+/// Triangles of mesh objects by part name and object id, e.g. to highlight them.
+pub type TriangleSet = HashMap<(String, usize), HashSet<usize>>;
+
+impl BuildMesh {
+    fn new(object_id: usize) -> Self {
+        Self {
+            object_id,
+            positions: Vec::new(),
+            colors: Vec::new(),
+            triangles: Vec::new(),
+            triangle_colors: Vec::new(),
+            triangle_textures: Vec::new(),
+            textures: Vec::new(),
+            triangle_sources: Vec::new(),
+            objects: Vec::new(),
+        }
+    }
+
+    /// Index of the texture in `textures`, which is added the first time.
+    fn texture_index(&mut self, part: &str, texture_id: usize) -> u32 {
+        let index = self
+            .textures
+            .iter()
+            .position(|(texture_part, id)| texture_part == part && *id == texture_id)
+            .unwrap_or_else(|| {
+                self.textures.push((part.to_string(), texture_id));
+                self.textures.len() - 1
+            });
+        index as u32
+    }
+
+    /// Whether triangle `index` of this build mesh is part of `triangles`.
+    pub fn contains_triangle(&self, triangles: &TriangleSet, index: usize) -> bool {
+        let (object, triangle) = self.triangle_sources[index];
+        triangles
+            .get(&self.objects[object as usize])
+            .is_some_and(|set| set.contains(&(triangle as usize)))
+    }
+}
+
+/// Axis aligned bounds of every mesh in the build as `(min, max)`.
+pub fn bounds_of_build(meshes: &[BuildMesh]) -> Option<(DVec3, DVec3)> {
+    bounds_of_points(
+        meshes
+            .iter()
+            .flat_map(|mesh| mesh.positions.iter().copied()),
+    )
+}
+
+/// Flattens every build item of the root model into a [`BuildMesh`], applying the item
+/// transform and the transforms of any nested components. Objects living in other model
+/// parts are resolved through their `p:path`.
+///
+/// Triangles pointing at vertices that do not exist are skipped so a broken mesh can
+/// still be displayed.
+pub fn flatten_build(model_parts: &ModelParts) -> Result<Vec<BuildMesh>> {
+    let root = model_parts.root_part();
+
+    let mut meshes = Vec::with_capacity(root.model.build.item.len());
+    for (index, item) in root.model.build.item.iter().enumerate() {
+        let mut mesh = BuildMesh::new(item.objectid);
+        let transform = mat4_from_optional_threemf_transform(item.transform.as_ref());
+        append_object(
+            model_parts,
+            root.item_part(index),
+            item.objectid,
+            transform,
+            &mut mesh,
+            &mut Vec::new(),
+        )?;
+        meshes.push(mesh);
+    }
+
+    Ok(meshes)
+}
+
+/// Resolves a single object and its components into a [`BuildMesh`] in the object's own
+/// coordinate system.
+pub fn flatten_object(model_parts: &ModelParts, part: &str, object_id: usize) -> Result<BuildMesh> {
+    let mut mesh = BuildMesh::new(object_id);
+    append_object(
+        model_parts,
+        part,
+        object_id,
+        DMat4::IDENTITY,
+        &mut mesh,
+        &mut Vec::new(),
+    )?;
+
+    Ok(mesh)
+}
+
+fn append_object(
+    model_parts: &ModelParts,
+    part: &str,
+    object_id: usize,
+    transform: DMat4,
+    mesh: &mut BuildMesh,
+    visiting: &mut Vec<(String, usize)>,
+) -> Result<()> {
+    if visiting
+        .iter()
+        .any(|(visited_part, visited_id)| visited_part == part && *visited_id == object_id)
+    {
+        return Err(anyhow!(
+            "Object {} references itself through its components",
+            object_id
+        ));
+    }
+
+    let object = model_parts
+        .object(part, object_id)
+        .ok_or_else(|| anyhow!("Reference to unknown object {} in {}", object_id, part))?;
+
+    match &object.object {
+        ObjectData::Mesh(source) => {
+            let source_part = &model_parts.parts[part];
+            let color = source_part
+                .materials
+                .object_color(object_id)
+                .unwrap_or(DEFAULT_COLOR);
+            mesh.objects.push((part.to_string(), object_id));
+            append_mesh(source, transform, color, mesh);
+            mesh.triangle_colors.extend(
+                mesh.triangle_sources[mesh.triangle_colors.len()..]
+                    .iter()
+                    .map(|&(_, index)| {
+                        source_part
+                            .materials
+                            .triangle_colors(object_id, index as usize)
+                    }),
+            );
+            for triangle in mesh.triangle_textures.len()..mesh.triangle_sources.len() {
+                let (_, index) = mesh.triangle_sources[triangle];
+                let texture = source_part
+                    .materials
+                    .triangle_texture(object_id, index as usize)
+                    .map(|(texture_id, coordinates)| {
+                        (mesh.texture_index(part, texture_id), coordinates)
+                    });
+                mesh.triangle_textures.push(texture);
+            }
+        }
+        ObjectData::Components { component } => {
+            let source_part = &model_parts.parts[part];
+            visiting.push((part.to_string(), object_id));
+            for (index, component) in component.iter().enumerate() {
+                let component_transform =
+                    transform * mat4_from_optional_threemf_transform(component.transform.as_ref());
+                append_object(
+                    model_parts,
+                    source_part.component_part(object_id, index),
+                    component.objectid,
+                    component_transform,
+                    mesh,
+                    visiting,
+                )?;
+            }
+            visiting.pop();
+        }
+    }
+
+    Ok(())
+}
+
+fn append_mesh(source: &Mesh, transform: DMat4, color: [f32; 3], mesh: &mut BuildMesh) {
+    let offset = mesh.positions.len() as u32;
+    let vertex_count = source.vertices.vertex.len();
+
+    mesh.positions.extend(
+        source
+            .vertices
+            .vertex
+            .iter()
+            .map(|v| transform.transform_point3(DVec3::new(v.x, v.y, v.z))),
+    );
+    mesh.colors.resize(mesh.positions.len(), color);
+
+    let object = (mesh.objects.len() - 1) as u32;
+    let mut skipped = 0;
+    for (index, triangle) in source.triangles.triangle.iter().enumerate() {
+        if triangle.v1 >= vertex_count || triangle.v2 >= vertex_count || triangle.v3 >= vertex_count
+        {
+            skipped += 1;
+            continue;
+        }
+        mesh.triangles.push([
+            offset + triangle.v1 as u32,
+            offset + triangle.v2 as u32,
+            offset + triangle.v3 as u32,
+        ]);
+        mesh.triangle_sources.push((object, index as u32));
+    }
+
+    if skipped > 0 {
+        log::warn!(
+            "Skipped {} triangles with out of range vertex indices in object {}",
+            skipped,
+            mesh.object_id
+        );
+    }
+}
+
+fn bounds_of_points(points: impl Iterator<Item = DVec3>) -> Option<(DVec3, DVec3)> {
+    points.fold(None, |bounds, point| match bounds {
+        Some((min, max)) => Some((point.min(min), point.max(max))),
+        None => Some((point, point)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_support::open_model_parts_from_test_resource,
+        threemf::{
+            materials::{get_materials_from_3mf_model_file_string, TextureImage},
+            threemf_reader::{get_model_from_3mf_model_file_string, ModelPart},
+        },
+    };
+    use std::collections::BTreeMap;
+
+    const COMPONENT_MODEL: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<model unit="millimeter" xmlns="http://schemas.microsoft.com/3dmanufacturing/core/2015/02">
+  <resources>
+    <object id="1" type="model">
+      <mesh>
+        <vertices>
+          <vertex x="0" y="0" z="0" />
+          <vertex x="1" y="0" z="0" />
+          <vertex x="0" y="1" z="0" />
+        </vertices>
+        <triangles>
+          <triangle v1="0" v2="1" v3="2" />
+          <triangle v1="0" v2="1" v3="7" />
+        </triangles>
+      </mesh>
+    </object>
+    <object id="2" type="model">
+      <components>
+        <component objectid="1" transform="1 0 0 0 1 0 0 0 1 0 0 5" />
+        <component objectid="1" transform="2 0 0 0 2 0 0 0 2 0 0 0" />
+      </components>
+    </object>
+  </resources>
+  <build>
+    <item objectid="2" transform="1 0 0 0 1 0 0 0 1 10 0 0" />
+  </build>
+</model>"#;
+
+    const TEXTURED_MODEL: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<model unit="millimeter" xmlns="http://schemas.microsoft.com/3dmanufacturing/core/2015/02"
+  xmlns:m="http://schemas.microsoft.com/3dmanufacturing/material/2015/02">
+  <resources>
+    <m:texture2d id="1" path="/3D/Texture/checker.png" contenttype="image/png" />
+    <m:texture2dgroup id="2" texid="1">
+      <m:tex2coord u="0" v="0" />
+      <m:tex2coord u="1" v="0" />
+      <m:tex2coord u="0" v="1" />
+    </m:texture2dgroup>
+    <object id="3" type="model">
+      <mesh>
+        <vertices>
+          <vertex x="0" y="0" z="0" />
+          <vertex x="1" y="0" z="0" />
+          <vertex x="0" y="1" z="0" />
+        </vertices>
+        <triangles>
+          <triangle v1="0" v2="1" v3="2" pid="2" p1="0" p2="1" p3="2" />
+          <triangle v1="0" v2="2" v3="1" />
+        </triangles>
+      </mesh>
+    </object>
+  </resources>
+  <build>
+    <item objectid="3" />
+    <item objectid="3" transform="1 0 0 0 1 0 0 0 1 5 0 0" />
+  </build>
+</model>"#;
+
+    #[test]
+    fn test_flatten_build_applies_item_and_component_transforms() {
+        let xml = COMPONENT_MODEL.to_string();
+        let part = ModelPart {
+            name: "/3D/3dmodel.model".to_string(),
+            model: get_model_from_3mf_model_file_string(&xml).unwrap(),
+            production: Default::default(),
+            materials: Default::default(),
+            xml: xml.into(),
+        };
+        let model_parts = ModelParts {
+            root: part.name.clone(),
+            parts: BTreeMap::from([(part.name.clone(), part)]),
+        };
+        let meshes = flatten_build(&model_parts).unwrap();
+
+        assert!(meshes.len() == 1, "Number of build meshes is wrong");
+        let mesh = &meshes[0];
+        assert!(
+            mesh.positions.len() == 6,
+            "Component vertices are not merged"
+        );
+        assert!(
+            mesh.triangles.len() == 2,
+            "Out of range triangles were not skipped"
+        );
+        assert!(
+            mesh.positions[0] == DVec3::new(10.0, 0.0, 5.0),
+            "Item and component translations are not combined"
+        );
+        assert!(
+            mesh.positions[5] == DVec3::new(10.0, 2.0, 0.0),
+            "Component scale is not applied before the item translation"
+        );
+        assert!(
+            mesh.triangles[1] == [3, 4, 5],
+            "Triangle indices are not offset"
+        );
+    }
+
+    #[test]
+    fn test_textured_triangles_keep_their_texture_coordinates() {
+        let name = "/3D/3dmodel.model";
+        let xml = TEXTURED_MODEL.to_string();
+        let mut materials = get_materials_from_3mf_model_file_string(name, &xml).unwrap();
+        materials.textures.get_mut(&1).unwrap().image = Some(TextureImage {
+            width: 1,
+            height: 1,
+            pixels: vec![[255; 4]],
+        });
+        let part = ModelPart {
+            name: name.to_string(),
+            model: get_model_from_3mf_model_file_string(&xml).unwrap(),
+            production: Default::default(),
+            materials,
+            xml: xml.into(),
+        };
+        let model_parts = ModelParts {
+            root: part.name.clone(),
+            parts: BTreeMap::from([(part.name.clone(), part)]),
+        };
+        let meshes = flatten_build(&model_parts).unwrap();
+
+        for mesh in &meshes {
+            assert!(
+                mesh.textures == [(name.to_string(), 1)],
+                "Textures of the build mesh are wrong: {:?}",
+                mesh.textures
+            );
+            assert!(
+                mesh.triangle_textures == [Some((0, [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])), None],
+                "Texture coordinates of the triangles are wrong: {:?}",
+                mesh.triangle_textures
+            );
+        }
+    }
+
+    #[test]
+    fn test_flatten_build_resolves_objects_in_other_parts() {
+        let model_parts = open_model_parts_from_test_resource("production.3mf");
+        let meshes = flatten_build(&model_parts).unwrap();
+
+        assert!(meshes.len() == 2, "Number of build meshes is wrong");
+        assert!(
+            meshes.iter().all(|mesh| mesh.triangles.len() == 12),
+            "Box from the object part is not flattened"
+        );
+        assert!(
+            meshes[1].positions[0] == DVec3::new(20.0, 0.0, 0.0),
+            "Component transform is not applied to the referenced object"
+        );
+    }
+}
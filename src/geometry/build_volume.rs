@@ -0,0 +1,158 @@
+use anyhow::Result;
+use glam::DVec3;
+
+use super::{analysis::unit_info, build::flatten_build};
+use crate::{
+    config::printer_profiles::{BedShape, PrinterProfile},
+    threemf::threemf_reader::ModelParts,
+};
+
+/// Overhangs smaller than this, in millimetres, are rounding and not reported.
+const TOLERANCE: f64 = 1e-6;
+
+/// Where a build item lies in the build volume of a printer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemPlacement {
+    pub index: usize,
+    pub object_id: usize,
+    /// Bounds of the item in millimetres as `(min, max)`, transforms applied.
+    pub bounds: Option<(DVec3, DVec3)>,
+    /// How the item leaves the build volume, e.g. `2.50 mm past the right edge`.
+    pub problems: Vec<String>,
+}
+
+impl ItemPlacement {
+    pub fn fits(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+/// Checks every build item of the root model, with its transform and the unit of the
+/// model applied, against the build volume of the printer.
+pub fn check_build_volume(
+    model_parts: &ModelParts,
+    profile: &PrinterProfile,
+) -> Result<Vec<ItemPlacement>> {
+    let (_, millimeters_per_unit) = unit_info(&model_parts.root_part().model.unit);
+
+    Ok(flatten_build(model_parts)?
+        .iter()
+        .enumerate()
+        .map(|(index, mesh)| {
+            let points: Vec<DVec3> = mesh
+                .positions
+                .iter()
+                .map(|position| *position * millimeters_per_unit)
+                .collect();
+            ItemPlacement {
+                index,
+                object_id: mesh.object_id,
+                bounds: bounds_of_points(&points),
+                problems: placement_problems(&points, profile),
+            }
+        })
+        .collect())
+}
+
+/// Describes every side of the build volume the points are outside of.
+pub fn placement_problems(points: &[DVec3], profile: &PrinterProfile) -> Vec<String> {
+    let Some((min, max)) = bounds_of_points(points) else {
+        return Vec::new();
+    };
+    let (volume_min, volume_max) = profile.volume_bounds();
+    let mut problems = Vec::new();
+    let mut report = |distance: f64, side: &str| {
+        if distance > TOLERANCE {
+            problems.push(format!("{:.2} mm {}", distance, side));
+        }
+    };
+
+    match profile.shape {
+        BedShape::Rectangular => {
+            report(volume_min.x - min.x, "past the left edge");
+            report(max.x - volume_max.x, "past the right edge");
+            report(volume_min.y - min.y, "past the front edge");
+            report(max.y - volume_max.y, "past the back edge");
+        }
+        BedShape::Circular => {
+            let center = profile.bed_center();
+            let farthest = points
+                .iter()
+                .map(|point| point.truncate().distance(center))
+                .fold(0.0, f64::max);
+            report(farthest - profile.bed_width / 2.0, "past the edge");
+        }
+    }
+    report(volume_min.z - min.z, "below the bed");
+    report(max.z - volume_max.z, "above the build volume");
+
+    problems
+}
+
+fn bounds_of_points(points: &[DVec3]) -> Option<(DVec3, DVec3)> {
+    let first = *points.first()?;
+    Some(points.iter().fold((first, first), |(min, max), point| {
+        (point.min(min), point.max(max))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::printer_profiles::BedOrigin, test_support::open_model_parts_from_test_resource,
+    };
+
+    #[test]
+    fn test_item_transform_is_applied() {
+        let mut model_parts = open_model_parts_from_test_resource("box.3mf");
+        let profile = PrinterProfile::default();
+
+        let placements = check_build_volume(&model_parts, &profile).unwrap();
+        assert!(
+            placements.len() == 1 && placements[0].fits(),
+            "Box does not fit a 220 mm bed: {:?}",
+            placements
+        );
+
+        let root = model_parts.root.clone();
+        model_parts.parts.get_mut(&root).unwrap().model.build.item[0].transform = Some([
+            1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 215.0, 0.0, -1.0,
+        ]);
+        let placements = check_build_volume(&model_parts, &profile).unwrap();
+        assert!(
+            placements[0].problems == ["5.00 mm past the right edge", "1.00 mm below the bed"],
+            "Moved box is not flagged: {:?}",
+            placements[0].problems
+        );
+        assert!(
+            placements[0].bounds
+                == Some((DVec3::new(215.0, 0.0, -1.0), DVec3::new(225.0, 20.0, 29.0))),
+            "Bounds are not measured after the transform"
+        );
+    }
+
+    #[test]
+    fn test_round_bed() {
+        let profile = PrinterProfile {
+            shape: BedShape::Circular,
+            bed_width: 100.0,
+            origin: BedOrigin::Center,
+            ..Default::default()
+        };
+        // the corners of a 80 mm square are 56.6 mm from the centre
+        let square = [DVec3::new(-40.0, -40.0, 0.0), DVec3::new(40.0, 40.0, 10.0)];
+        let problems = placement_problems(&square, &profile);
+        assert!(
+            problems.len() == 1 && problems[0].ends_with("past the edge"),
+            "Square corners are not outside the round bed: {:?}",
+            problems
+        );
+
+        let inside = [DVec3::new(-35.0, 0.0, 0.0), DVec3::new(0.0, 35.0, 10.0)];
+        assert!(
+            placement_problems(&inside, &profile).is_empty(),
+            "Points inside the round bed are flagged"
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub mod analysis;
+pub mod arrange;
+pub mod build;
+pub mod build_volume;
+pub mod repair;
+pub mod transform;
+pub mod validation;
@@ -0,0 +1,749 @@
+use anyhow::{anyhow, Result};
+use glam::DVec3;
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt,
+};
+use threemf::model::{Mesh, ObjectData, Triangle, Vertex};
+
+use crate::threemf::{
+    threemf_package::Package, threemf_reader::ModelParts, threemf_writer, xml_edit,
+};
+
+/// Vertices closer than this along every axis, in model units, are merged. Points are
+/// snapped to a grid of this size, so two close points on either side of a grid line
+/// are left apart.
+const MERGE_TOLERANCE: f64 = 1e-6;
+/// Same as the degenerate check of the mesh validation.
+const DEGENERATE_TOLERANCE: f64 = 1e-10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepairOperation {
+    MergeCoincidentVertices,
+    RemoveInvalidTriangles,
+    RemoveDuplicateTriangles,
+    UnifyWinding,
+    FillHoles,
+    RemoveUnreferencedVertices,
+}
+
+impl RepairOperation {
+    /// Every operation in the order a full repair runs them.
+    pub const ALL: [RepairOperation; 6] = [
+        RepairOperation::MergeCoincidentVertices,
+        RepairOperation::RemoveInvalidTriangles,
+        RepairOperation::RemoveDuplicateTriangles,
+        RepairOperation::FillHoles,
+        RepairOperation::UnifyWinding,
+        RepairOperation::RemoveUnreferencedVertices,
+    ];
+
+    /// Applies the operation and returns how many elements it changed.
+    pub fn apply(&self, mesh: &mut Mesh) -> usize {
+        match self {
+            RepairOperation::MergeCoincidentVertices => merge_coincident_vertices(mesh),
+            RepairOperation::RemoveInvalidTriangles => remove_invalid_triangles(mesh),
+            RepairOperation::RemoveDuplicateTriangles => remove_duplicate_triangles(mesh),
+            RepairOperation::UnifyWinding => unify_winding(mesh),
+            RepairOperation::FillHoles => fill_holes(mesh),
+            RepairOperation::RemoveUnreferencedVertices => remove_unreferenced_vertices(mesh),
+        }
+    }
+
+    /// What [`RepairOperation::apply`] counts.
+    fn changed_elements(&self) -> &'static str {
+        match self {
+            RepairOperation::MergeCoincidentVertices => "vertices merged",
+            RepairOperation::RemoveInvalidTriangles => "invalid triangles removed",
+            RepairOperation::RemoveDuplicateTriangles => "duplicate triangles removed",
+            RepairOperation::UnifyWinding => "triangles flipped",
+            RepairOperation::FillHoles => "holes filled",
+            RepairOperation::RemoveUnreferencedVertices => "unreferenced vertices removed",
+        }
+    }
+}
+
+impl fmt::Display for RepairOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            RepairOperation::MergeCoincidentVertices => "Merge coincident vertices",
+            RepairOperation::RemoveInvalidTriangles => "Remove degenerate triangles",
+            RepairOperation::RemoveDuplicateTriangles => "Remove duplicate triangles",
+            RepairOperation::UnifyWinding => "Unify winding",
+            RepairOperation::FillHoles => "Fill holes",
+            RepairOperation::RemoveUnreferencedVertices => "Remove unreferenced vertices",
+        };
+        f.write_str(text)
+    }
+}
+
+/// Runs `operations` on every mesh object of the model and replaces the meshes that changed
+/// in the text of their parts, the rest of the text is kept as it is. Objects whose triangles
+/// have material properties of their own are left alone, the repairs would renumber the
+/// triangles. Textures of the parts are loaded again from `package`. Every change is logged,
+/// the total number of changes is returned.
+pub fn repair_model(
+    model_parts: &mut ModelParts,
+    operations: &[RepairOperation],
+    package: Option<&Package>,
+) -> Result<usize> {
+    let mut total = 0;
+
+    for part in model_parts.parts.values_mut() {
+        let mut model = part.model.clone();
+        // positions of the changed meshes among the mesh objects of the part
+        let mut changed_meshes = Vec::new();
+        let mesh_objects = model
+            .resources
+            .object
+            .iter_mut()
+            .filter_map(|object| match &mut object.object {
+                ObjectData::Mesh(mesh) => Some((object.id, mesh)),
+                _ => None,
+            });
+        for (position, (object_id, mesh)) in mesh_objects.enumerate() {
+            if part.materials.has_triangle_properties(object_id) {
+                log::warn!(
+                    "Object {} of {} has triangle properties and is not repaired",
+                    object_id,
+                    part.name
+                );
+                continue;
+            }
+
+            let mut object_changed = 0;
+            for operation in operations {
+                let changed = operation.apply(mesh);
+                if changed > 0 {
+                    log::info!(
+                        "{}: {} {} in object {} of {}",
+                        operation,
+                        changed,
+                        operation.changed_elements(),
+                        object_id,
+                        part.name
+                    );
+                }
+                object_changed += changed;
+            }
+            if object_changed > 0 {
+                changed_meshes.push((position, mesh.clone()));
+                total += object_changed;
+            }
+        }
+
+        if changed_meshes.is_empty() {
+            continue;
+        }
+        let mut xml = part.xml.to_string();
+        let ranges = xml_edit::element_ranges(&xml, &["model", "resources", "object", "mesh"])?;
+        // from the end of the text so the meshes in front keep their place
+        for (position, mesh) in changed_meshes.into_iter().rev() {
+            let range = ranges.get(position).cloned().ok_or_else(|| {
+                anyhow!(
+                    "Mesh {} is missing from the text of {}",
+                    position,
+                    part.name
+                )
+            })?;
+            let line_start = xml[..range.start]
+                .rfind('\n')
+                .map_or(0, |newline| newline + 1);
+            let indent = &xml[line_start..range.start];
+            let own_line = indent.chars().all(char::is_whitespace);
+            let text = threemf_writer::get_3mf_mesh_string(&mesh, own_line.then_some(indent))?;
+            xml.replace_range(range, &text);
+        }
+        part.set_xml(xml, package)?;
+    }
+
+    if total == 0 {
+        log::info!("Nothing to repair");
+    }
+    Ok(total)
+}
+
+pub fn merge_coincident_vertices(mesh: &mut Mesh) -> usize {
+    let mut cells: HashMap<[i64; 3], usize> = HashMap::new();
+    let mut vertices = Vec::new();
+    let remap: Vec<usize> = mesh
+        .vertices
+        .vertex
+        .iter()
+        .map(|vertex| {
+            let cell = [vertex.x, vertex.y, vertex.z]
+                .map(|value| (value / MERGE_TOLERANCE).round() as i64);
+            *cells.entry(cell).or_insert_with(|| {
+                vertices.push(*vertex);
+                vertices.len() - 1
+            })
+        })
+        .collect();
+
+    let merged = mesh.vertices.vertex.len() - vertices.len();
+    if merged > 0 {
+        mesh.vertices.vertex = vertices;
+        remap_triangles(mesh, &remap);
+    }
+    merged
+}
+
+/// Removes triangles with out of range vertex indices, repeated vertices or no area.
+pub fn remove_invalid_triangles(mesh: &mut Mesh) -> usize {
+    let vertices = &mesh.vertices.vertex;
+    let before = mesh.triangles.triangle.len();
+    mesh.triangles.triangle.retain(|triangle| {
+        triangle_positions(vertices, triangle).is_some_and(|[a, b, c]| {
+            let area = (b - a).cross(c - a).length();
+            let longest_edge = (b - a).length().max((c - b).length()).max((a - c).length());
+            triangle.v1 != triangle.v2
+                && triangle.v2 != triangle.v3
+                && triangle.v3 != triangle.v1
+                && area > DEGENERATE_TOLERANCE * longest_edge * longest_edge
+        })
+    });
+    before - mesh.triangles.triangle.len()
+}
+
+/// Removes triangles using the same vertices as an earlier triangle, in either winding.
+pub fn remove_duplicate_triangles(mesh: &mut Mesh) -> usize {
+    let mut seen = HashSet::new();
+    let before = mesh.triangles.triangle.len();
+    mesh.triangles.triangle.retain(|triangle| {
+        let mut corners = corners(triangle);
+        corners.sort_unstable();
+        seen.insert(corners)
+    });
+    before - mesh.triangles.triangle.len()
+}
+
+/// Flips triangles so that neighbours agree on the winding, then turns every connected
+/// piece with a negative volume inside out so its normals point outwards.
+///
+/// Triangles are only neighbours over edges shared by exactly two triangles.
+pub fn unify_winding(mesh: &mut Mesh) -> usize {
+    let vertices = &mesh.vertices.vertex;
+    let triangles = &mesh.triangles.triangle;
+    let valid: Vec<bool> = triangles
+        .iter()
+        .map(|triangle| triangle_positions(vertices, triangle).is_some())
+        .collect();
+
+    let mut edges: HashMap<[usize; 2], Vec<(usize, bool)>> = HashMap::new();
+    for (index, triangle) in triangles.iter().enumerate().filter(|(i, _)| valid[*i]) {
+        for (from, to) in directed_edges(triangle) {
+            edges
+                .entry([from.min(to), from.max(to)])
+                .or_default()
+                .push((index, from < to));
+        }
+    }
+
+    let mut flipped: Vec<Option<bool>> = vec![None; triangles.len()];
+    for start in 0..triangles.len() {
+        if !valid[start] || flipped[start].is_some() {
+            continue;
+        }
+
+        flipped[start] = Some(false);
+        let mut component = vec![start];
+        let mut queue = VecDeque::from([start]);
+        while let Some(current) = queue.pop_front() {
+            for (from, to) in directed_edges(&triangles[current]) {
+                let [first, second] = match edges[&[from.min(to), from.max(to)]].as_slice() {
+                    [first, second] => [*first, *second],
+                    _ => continue,
+                };
+                let ((_, forward), (neighbour, neighbour_forward)) = if first.0 == current {
+                    (first, second)
+                } else {
+                    (second, first)
+                };
+                if flipped[neighbour].is_some() {
+                    continue;
+                }
+
+                // the neighbour has to traverse the shared edge the other way round
+                let current_forward = forward != flipped[current].unwrap_or_default();
+                flipped[neighbour] = Some(neighbour_forward == current_forward);
+                component.push(neighbour);
+                queue.push_back(neighbour);
+            }
+        }
+
+        let volume: f64 = component
+            .iter()
+            .filter_map(|&index| {
+                let [a, b, c] = triangle_positions(vertices, &triangles[index])?;
+                let volume = a.dot(b.cross(c)) / 6.0;
+                Some(if flipped[index] == Some(true) {
+                    -volume
+                } else {
+                    volume
+                })
+            })
+            .sum();
+        if volume < 0.0 {
+            for &index in &component {
+                flipped[index] = flipped[index].map(|flip| !flip);
+            }
+        }
+    }
+
+    let mut count = 0;
+    for (triangle, flip) in mesh.triangles.triangle.iter_mut().zip(flipped) {
+        if flip == Some(true) {
+            std::mem::swap(&mut triangle.v2, &mut triangle.v3);
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Closes every hole whose boundary is a simple loop with a fan of triangles wound
+/// like the surrounding surface. Boundaries touching themselves are left open.
+pub fn fill_holes(mesh: &mut Mesh) -> usize {
+    let vertices = &mesh.vertices.vertex;
+    let mut edge_users: HashMap<[usize; 2], Vec<(usize, usize)>> = HashMap::new();
+    for triangle in &mesh.triangles.triangle {
+        if triangle_positions(vertices, triangle).is_none() {
+            continue;
+        }
+        for (from, to) in directed_edges(triangle) {
+            edge_users
+                .entry([from.min(to), from.max(to)])
+                .or_default()
+                .push((from, to));
+        }
+    }
+
+    // a hole is walked against its boundary edges so the fill faces the same way
+    let mut next: HashMap<usize, usize> = HashMap::new();
+    let mut branching = HashSet::new();
+    for users in edge_users.values() {
+        if let [(from, to)] = users.as_slice() {
+            if next.insert(*to, *from).is_some() {
+                branching.insert(*to);
+            }
+        }
+    }
+
+    let mut starts: Vec<usize> = next.keys().copied().collect();
+    starts.sort_unstable();
+    let mut visited = HashSet::new();
+    let mut filled = 0;
+    for start in starts {
+        if visited.contains(&start) {
+            continue;
+        }
+
+        let mut hole = vec![start];
+        let mut simple = !branching.contains(&start);
+        visited.insert(start);
+        let mut current = start;
+        loop {
+            let Some(&following) = next.get(&current) else {
+                simple = false;
+                break;
+            };
+            if following == start {
+                break;
+            }
+            if !visited.insert(following) || branching.contains(&following) {
+                simple = false;
+                break;
+            }
+            hole.push(following);
+            current = following;
+        }
+
+        if simple && hole.len() >= 3 {
+            for corner in 1..hole.len() - 1 {
+                mesh.triangles.triangle.push(Triangle {
+                    v1: hole[0],
+                    v2: hole[corner],
+                    v3: hole[corner + 1],
+                });
+            }
+            filled += 1;
+        }
+    }
+    filled
+}
+
+pub fn remove_unreferenced_vertices(mesh: &mut Mesh) -> usize {
+    let vertex_count = mesh.vertices.vertex.len();
+    let mut used = vec![false; vertex_count];
+    for triangle in &mesh.triangles.triangle {
+        for corner in corners(triangle) {
+            if corner < vertex_count {
+                used[corner] = true;
+            }
+        }
+    }
+
+    let mut remap = vec![usize::MAX; vertex_count];
+    let mut vertices = Vec::new();
+    for (index, vertex) in mesh.vertices.vertex.iter().enumerate() {
+        if used[index] {
+            remap[index] = vertices.len();
+            vertices.push(*vertex);
+        }
+    }
+
+    let removed = vertex_count - vertices.len();
+    if removed > 0 {
+        mesh.vertices.vertex = vertices;
+        remap_triangles(mesh, &remap);
+    }
+    removed
+}
+
+/// Renumbers the triangle corners, out of range corners are left for the validation
+/// to find.
+fn remap_triangles(mesh: &mut Mesh, remap: &[usize]) {
+    for triangle in mesh.triangles.triangle.iter_mut() {
+        for corner in [&mut triangle.v1, &mut triangle.v2, &mut triangle.v3] {
+            if let Some(&index) = remap.get(*corner) {
+                *corner = index;
+            }
+        }
+    }
+}
+
+fn corners(triangle: &Triangle) -> [usize; 3] {
+    [triangle.v1, triangle.v2, triangle.v3]
+}
+
+fn directed_edges(triangle: &Triangle) -> [(usize, usize); 3] {
+    [
+        (triangle.v1, triangle.v2),
+        (triangle.v2, triangle.v3),
+        (triangle.v3, triangle.v1),
+    ]
+}
+
+fn triangle_positions(vertices: &[Vertex], triangle: &Triangle) -> Option<[DVec3; 3]> {
+    let mut positions = [DVec3::ZERO; 3];
+    for (position, corner) in positions.iter_mut().zip(corners(triangle)) {
+        let vertex = vertices.get(corner)?;
+        *position = DVec3::new(vertex.x, vertex.y, vertex.z);
+    }
+    Some(positions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::validation::validate_mesh;
+    use crate::test_support::{open_file_from_test_resource, tetrahedron, write_and_read_package};
+    use crate::threemf::{
+        threemf_reader::{
+            get_model_from_3mf_model_file_string, get_model_parts_from_package,
+            load_threemf_package,
+        },
+        threemf_writer::{new_package_from_model_parts, update_package_model_parts},
+    };
+    use threemf::model::{Build, Item, Model, Object, Resources, Unit};
+
+    /// A base material group and two tetrahedra with a duplicated triangle, one coloured as
+    /// a whole and one per triangle.
+    const MATERIAL_MODEL: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<model unit="millimeter" xmlns="http://schemas.microsoft.com/3dmanufacturing/core/2015/02">
+  <resources>
+    <basematerials id="3">
+      <base name="Red PLA" displaycolor="#FF0000" />
+      <base name="Blue PLA" displaycolor="#0000FF" />
+    </basematerials>
+    <object id="1" type="model" pid="3" pindex="1">
+      <mesh>
+        <vertices>
+          <vertex x="0" y="0" z="0" />
+          <vertex x="1" y="0" z="0" />
+          <vertex x="0" y="1" z="0" />
+          <vertex x="0" y="0" z="1" />
+        </vertices>
+        <triangles>
+          <triangle v1="0" v2="2" v3="1" />
+          <triangle v1="0" v2="1" v3="3" />
+          <triangle v1="1" v2="2" v3="3" />
+          <triangle v1="0" v2="3" v3="2" />
+          <triangle v1="0" v2="3" v3="2" />
+        </triangles>
+      </mesh>
+    </object>
+    <object id="2" type="model">
+      <mesh>
+        <vertices>
+          <vertex x="0" y="0" z="0" />
+          <vertex x="1" y="0" z="0" />
+          <vertex x="0" y="1" z="0" />
+          <vertex x="0" y="0" z="1" />
+        </vertices>
+        <triangles>
+          <triangle v1="0" v2="2" v3="1" pid="3" p1="0" />
+          <triangle v1="0" v2="1" v3="3" pid="3" p1="1" />
+          <triangle v1="1" v2="2" v3="3" pid="3" p1="0" />
+          <triangle v1="0" v2="3" v3="2" pid="3" p1="1" />
+          <triangle v1="0" v2="3" v3="2" pid="3" p1="1" />
+        </triangles>
+      </mesh>
+    </object>
+  </resources>
+  <build>
+    <item objectid="1" />
+    <item objectid="2" />
+  </build>
+</model>
+"##;
+
+    fn write_and_read(package: &Package) -> ModelParts {
+        get_model_parts_from_package(&write_and_read_package(package)).unwrap()
+    }
+
+    fn triangle_count(model_parts: &ModelParts, part: &str, object_index: usize) -> usize {
+        match &model_parts.parts[part].model.resources.object[object_index].object {
+            ObjectData::Mesh(mesh) => mesh.triangles.triangle.len(),
+            _ => panic!("Not a mesh data"),
+        }
+    }
+
+    fn signed_volume(mesh: &Mesh) -> f64 {
+        mesh.triangles
+            .triangle
+            .iter()
+            .filter_map(|triangle| triangle_positions(&mesh.vertices.vertex, triangle))
+            .map(|[a, b, c]| a.dot(b.cross(c)) / 6.0)
+            .sum()
+    }
+
+    #[test]
+    fn test_coincident_vertices_are_merged() {
+        let mut mesh = tetrahedron();
+        // the last triangle uses a copy of vertex 0
+        mesh.vertices.vertex.push(Vertex {
+            x: 0.0,
+            y: 0.0,
+            z: 1e-9,
+        });
+        mesh.triangles.triangle[3].v1 = 4;
+
+        assert!(
+            merge_coincident_vertices(&mut mesh) == 1,
+            "Copy of the vertex is not merged"
+        );
+        assert!(
+            validate_mesh(&mesh).is_empty(),
+            "Mesh is not closed after merging"
+        );
+    }
+
+    #[test]
+    fn test_invalid_and_duplicate_triangles_are_removed() {
+        let mut mesh = tetrahedron();
+        let triangles = &mut mesh.triangles.triangle;
+        triangles.push(Triangle {
+            v1: 0,
+            v2: 1,
+            v3: 9,
+        });
+        triangles.push(Triangle {
+            v1: 0,
+            v2: 0,
+            v3: 1,
+        });
+        triangles.push(Triangle {
+            v1: 1,
+            v2: 0,
+            v3: 2,
+        });
+
+        assert!(
+            remove_invalid_triangles(&mut mesh) == 2,
+            "Out of range and degenerate triangles are not removed"
+        );
+        assert!(
+            remove_duplicate_triangles(&mut mesh) == 1,
+            "Duplicate triangle is not removed"
+        );
+        assert!(validate_mesh(&mesh).is_empty(), "Mesh still has issues");
+    }
+
+    #[test]
+    fn test_winding_is_unified_outwards() {
+        let mut mesh = tetrahedron();
+        mesh.triangles.triangle[2] = Triangle {
+            v1: 1,
+            v2: 3,
+            v3: 2,
+        };
+        assert!(
+            unify_winding(&mut mesh) == 1,
+            "Only one triangle should flip"
+        );
+        assert!(validate_mesh(&mesh).is_empty(), "Winding is still mixed");
+
+        // an inside out mesh is turned around completely
+        for triangle in mesh.triangles.triangle.iter_mut() {
+            std::mem::swap(&mut triangle.v1, &mut triangle.v2);
+        }
+        assert!(
+            unify_winding(&mut mesh) == 4,
+            "Inside out mesh is not flipped"
+        );
+        assert!(signed_volume(&mesh) > 0.0, "Normals do not point outwards");
+    }
+
+    #[test]
+    fn test_hole_is_filled() {
+        let mut mesh = tetrahedron();
+        mesh.triangles.triangle.remove(1);
+
+        assert!(fill_holes(&mut mesh) == 1, "Hole is not filled");
+        assert!(
+            validate_mesh(&mesh).is_empty(),
+            "Filled mesh still has issues"
+        );
+        assert!(
+            signed_volume(&mesh) > 0.0,
+            "Fill is not wound like the surrounding surface"
+        );
+    }
+
+    #[test]
+    fn test_unreferenced_vertices_are_removed() {
+        let mut mesh = tetrahedron();
+        mesh.vertices.vertex.insert(
+            0,
+            Vertex {
+                x: 5.0,
+                y: 5.0,
+                z: 5.0,
+            },
+        );
+        for triangle in mesh.triangles.triangle.iter_mut() {
+            triangle.v1 += 1;
+            triangle.v2 += 1;
+            triangle.v3 += 1;
+        }
+
+        assert!(
+            remove_unreferenced_vertices(&mut mesh) == 1,
+            "Unused vertex is not removed"
+        );
+        assert!(
+            mesh.vertices.vertex[0].x == 0.0 && validate_mesh(&mesh).is_empty(),
+            "Triangles are not renumbered"
+        );
+    }
+
+    #[test]
+    fn test_repaired_model_is_saved() {
+        let mut mesh = tetrahedron();
+        mesh.triangles.triangle.remove(0);
+        mesh.triangles.triangle.push(Triangle {
+            v1: 1,
+            v2: 1,
+            v3: 2,
+        });
+        let model = Model {
+            xmlns: "http://schemas.microsoft.com/3dmanufacturing/core/2015/02".to_string(),
+            metadata: Vec::new(),
+            resources: Resources {
+                object: vec![Object {
+                    id: 1,
+                    partnumber: None,
+                    name: None,
+                    pid: None,
+                    object: ObjectData::Mesh(mesh),
+                }],
+                basematerials: None,
+            },
+            build: Build {
+                item: vec![Item {
+                    objectid: 1,
+                    transform: None,
+                    partnumber: None,
+                }],
+            },
+            unit: Unit::Millimeter,
+        };
+        let mut model_parts = ModelParts::from_model("/3D/3dmodel.model", model).unwrap();
+
+        let changed = repair_model(&mut model_parts, &RepairOperation::ALL, None).unwrap();
+        assert!(changed == 2, "Number of repairs is wrong");
+
+        let saved = write_and_read(&new_package_from_model_parts(&model_parts));
+
+        if let ObjectData::Mesh(mesh) = &saved.root_part().model.resources.object[0].object {
+            assert!(
+                validate_mesh(mesh).is_empty(),
+                "Saved mesh still has issues"
+            );
+        } else {
+            panic!("Not a mesh data");
+        }
+    }
+
+    #[test]
+    fn test_repair_keeps_production_attributes() {
+        let mut package =
+            load_threemf_package(open_file_from_test_resource("production.3mf")).unwrap();
+        let mut model_parts = get_model_parts_from_package(&package).unwrap();
+        let box_part = model_parts.parts.get_mut("/3D/Objects/box.model").unwrap();
+        let triangle = "<triangle v1=\"3\" v2=\"2\" v3=\"1\" />";
+        let duplicated = box_part.xml.replacen(triangle, &triangle.repeat(2), 1);
+        box_part.set_xml(duplicated, None).unwrap();
+        let production = box_part.production.clone();
+
+        let changed = repair_model(&mut model_parts, &RepairOperation::ALL, None).unwrap();
+        assert!(changed == 1, "Number of repairs is wrong: {}", changed);
+
+        update_package_model_parts(&mut package, &model_parts);
+        let saved = write_and_read(&package);
+        assert!(
+            saved.parts["/3D/Objects/box.model"].production == production,
+            "Production attributes of the repaired part are lost"
+        );
+        assert!(
+            saved
+                .root_part()
+                .production
+                .items
+                .iter()
+                .any(|item| item.path.is_some()),
+            "Paths of the build items are lost"
+        );
+        assert!(
+            triangle_count(&saved, "/3D/Objects/box.model", 0) == 12,
+            "Repaired mesh is not saved"
+        );
+    }
+
+    #[test]
+    fn test_repair_keeps_materials() {
+        let model = get_model_from_3mf_model_file_string(&MATERIAL_MODEL.to_string()).unwrap();
+        let mut model_parts = ModelParts::from_model("/3D/3dmodel.model", model).unwrap();
+        let root = model_parts.root.clone();
+        let part = model_parts.parts.get_mut(&root).unwrap();
+        part.set_xml(MATERIAL_MODEL.to_string(), None).unwrap();
+
+        let changed = repair_model(&mut model_parts, &RepairOperation::ALL, None).unwrap();
+        assert!(changed == 1, "Number of repairs is wrong: {}", changed);
+
+        let saved = write_and_read(&new_package_from_model_parts(&model_parts));
+        let materials = &saved.root_part().materials;
+        assert!(
+            materials.object_color(1) == Some([0.0, 0.0, 1.0]),
+            "Material of the repaired object is lost"
+        );
+        assert!(
+            materials.has_triangle_properties(2) && triangle_count(&saved, &root, 1) == 5,
+            "Object with triangle properties is changed"
+        );
+        assert!(
+            triangle_count(&saved, &root, 0) == 4,
+            "Repaired mesh is not saved"
+        );
+    }
+}
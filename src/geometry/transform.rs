@@ -0,0 +1,147 @@
+use glam::{DMat4, DQuat, DVec3, DVec4, EulerRot};
+
+/// Converts a 3MF `transform` attribute (`m00 m01 m02 m10 m11 m12 m20 m21 m22 m30 m31 m32`)
+/// into a matrix acting on column vectors.
+///
+/// 3MF uses the row vector convention where the last row holds the translation.
+pub fn mat4_from_threemf_transform(m: &[f64; 12]) -> DMat4 {
+    DMat4::from_cols(
+        DVec4::new(m[0], m[1], m[2], 0.0),
+        DVec4::new(m[3], m[4], m[5], 0.0),
+        DVec4::new(m[6], m[7], m[8], 0.0),
+        DVec4::new(m[9], m[10], m[11], 1.0),
+    )
+}
+
+/// Same as [`mat4_from_threemf_transform`] but treats a missing transform as identity.
+pub fn mat4_from_optional_threemf_transform(m: Option<&[f64; 12]>) -> DMat4 {
+    match m {
+        Some(m) => mat4_from_threemf_transform(m),
+        None => DMat4::IDENTITY,
+    }
+}
+
+/// Converts a matrix acting on column vectors back into a 3MF `transform`. The last row
+/// of the matrix is dropped, 3MF transforms are affine.
+pub fn threemf_transform_from_mat4(matrix: &DMat4) -> [f64; 12] {
+    let [x, y, z, w] = matrix.to_cols_array_2d();
+    [
+        x[0], x[1], x[2], y[0], y[1], y[2], z[0], z[1], z[2], w[0], w[1], w[2],
+    ]
+}
+
+/// Formats a 3MF `transform` attribute, rounding away float noise like `0.30000000000000004`.
+pub fn format_threemf_transform(m: &[f64; 12]) -> String {
+    m.iter()
+        .map(|value| {
+            // adding zero turns -0 into 0
+            let rounded = (value * 1e9).round() / 1e9 + 0.0;
+            rounded.to_string()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A transform split into the values shown to a user: a translation, rotations in degrees
+/// applied around X, then Y, then Z, and a scale along the axes of the object.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransformComponents {
+    pub translation: DVec3,
+    pub rotation_degrees: DVec3,
+    pub scale: DVec3,
+}
+
+impl TransformComponents {
+    /// Shear cannot be represented and is lost.
+    pub fn from_mat4(matrix: &DMat4) -> Self {
+        let (scale, rotation, translation) = matrix.to_scale_rotation_translation();
+        let (z, y, x) = rotation.to_euler(EulerRot::ZYX);
+        Self {
+            translation,
+            rotation_degrees: DVec3::new(x.to_degrees(), y.to_degrees(), z.to_degrees()),
+            scale,
+        }
+    }
+
+    pub fn to_mat4(self) -> DMat4 {
+        let [x, y, z] = self.rotation_degrees.to_array().map(f64::to_radians);
+        DMat4::from_scale_rotation_translation(
+            self.scale,
+            DQuat::from_euler(EulerRot::ZYX, z, y, x),
+            self.translation,
+        )
+    }
+}
+
+/// Rounds `value` to the nearest multiple of `step`, steps that are not positive leave it.
+pub fn snap(value: f64, step: f64) -> f64 {
+    if step > 0.0 {
+        (value / step).round() * step
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_threemf_transform_round_trip() {
+        let m = [
+            1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, -1.0, 0.0, 10.0, 20.0, 30.0,
+        ];
+        let matrix = mat4_from_threemf_transform(&m);
+
+        assert!(
+            threemf_transform_from_mat4(&matrix) == m,
+            "Transform changed after converting back"
+        );
+        assert!(
+            format_threemf_transform(&threemf_transform_from_mat4(
+                &(DMat4::from_translation(DVec3::new(0.1, 0.2, 0.0))
+                    * DMat4::from_translation(DVec3::new(0.2, 0.1, -0.0)))
+            )) == "1 0 0 0 1 0 0 0 1 0.3 0.3 0",
+            "Float noise is not rounded away"
+        );
+    }
+
+    #[test]
+    fn test_components_round_trip() {
+        let components = TransformComponents {
+            translation: DVec3::new(1.0, -2.0, 3.0),
+            rotation_degrees: DVec3::new(30.0, -45.0, 90.0),
+            scale: DVec3::new(2.0, 1.0, 0.5),
+        };
+        let read = TransformComponents::from_mat4(&components.to_mat4());
+
+        for (name, value, expected) in [
+            ("translation", read.translation, components.translation),
+            (
+                "rotation",
+                read.rotation_degrees,
+                components.rotation_degrees,
+            ),
+            ("scale", read.scale, components.scale),
+        ] {
+            assert!(
+                value.abs_diff_eq(expected, 1e-9),
+                "The {} changed to {:?}",
+                name,
+                value
+            );
+        }
+        // rotation about X first, so Y ends up along Z
+        let matrix = TransformComponents {
+            rotation_degrees: DVec3::new(90.0, 0.0, 0.0),
+            ..components
+        }
+        .to_mat4();
+        assert!(
+            matrix
+                .transform_vector3(DVec3::Y)
+                .abs_diff_eq(DVec3::new(0.0, 0.0, 1.0), 1e-9),
+            "Rotation about X is wrong"
+        );
+    }
+}
@@ -0,0 +1,312 @@
+use glam::DVec3;
+use std::{collections::HashMap, fmt};
+use threemf::model::{Mesh, ObjectData};
+
+use super::build::TriangleSet;
+use crate::threemf::threemf_reader::ModelParts;
+
+/// Triangles with less area than this relative to their longest edge squared are collinear.
+const DEGENERATE_TOLERANCE: f64 = 1e-10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MeshIssueKind {
+    /// A triangle references a vertex that does not exist.
+    OutOfRangeIndex,
+    /// A triangle repeats a vertex or has no area.
+    DegenerateTriangle,
+    /// A triangle uses the same vertices as an earlier one.
+    DuplicateTriangle,
+    /// An edge shared by more than two triangles.
+    NonManifoldEdge,
+    /// An edge used by a single triangle, the mesh is not closed.
+    OpenEdge,
+    /// Two triangles traverse their shared edge in the same direction.
+    InconsistentWinding,
+}
+
+impl fmt::Display for MeshIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            MeshIssueKind::OutOfRangeIndex => "Out of range vertex index",
+            MeshIssueKind::DegenerateTriangle => "Degenerate triangle",
+            MeshIssueKind::DuplicateTriangle => "Duplicate triangle",
+            MeshIssueKind::NonManifoldEdge => "Non-manifold edge",
+            MeshIssueKind::OpenEdge => "Open edge",
+            MeshIssueKind::InconsistentWinding => "Inconsistent winding",
+        };
+        f.write_str(text)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeshIssue {
+    pub kind: MeshIssueKind,
+    /// Indices of the offending triangles in the mesh.
+    pub triangles: Vec<usize>,
+    /// Vertex indices of the offending edge, for edge issues.
+    pub edge: Option<[usize; 2]>,
+}
+
+/// Issues found in a mesh object of a model part.
+#[derive(Debug, Clone)]
+pub struct ObjectValidation {
+    pub part: String,
+    pub object_id: usize,
+    pub name: Option<String>,
+    pub issues: Vec<MeshIssue>,
+}
+
+impl ObjectValidation {
+    /// Number of issues of each kind, in the order of [`MeshIssueKind`].
+    pub fn issue_counts(&self) -> Vec<(MeshIssueKind, usize)> {
+        let mut counts: Vec<(MeshIssueKind, usize)> = Vec::new();
+        for issue in &self.issues {
+            match counts.iter_mut().find(|(kind, _)| *kind == issue.kind) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((issue.kind, 1)),
+            }
+        }
+        counts.sort();
+        counts
+    }
+}
+
+/// Checks every mesh object of every model part.
+pub fn validate_model(model_parts: &ModelParts) -> Vec<ObjectValidation> {
+    let mut validations = Vec::new();
+    for part in model_parts.parts.values() {
+        for object in &part.model.resources.object {
+            if let ObjectData::Mesh(mesh) = &object.object {
+                validations.push(ObjectValidation {
+                    part: part.name.clone(),
+                    object_id: object.id,
+                    name: object.name.clone(),
+                    issues: validate_mesh(mesh),
+                });
+            }
+        }
+    }
+
+    validations
+}
+
+/// Triangles involved in any issue, for highlighting them in the viewport.
+pub fn defective_triangles(validations: &[ObjectValidation]) -> TriangleSet {
+    let mut triangles = TriangleSet::new();
+    for validation in validations.iter().filter(|v| !v.issues.is_empty()) {
+        triangles
+            .entry((validation.part.clone(), validation.object_id))
+            .or_default()
+            .extend(validation.issues.iter().flat_map(|issue| &issue.triangles));
+    }
+
+    triangles
+}
+
+/// Finds everything that keeps a mesh from being a closed, consistently wound 2-manifold.
+///
+/// Triangles with out of range indices, degenerate and duplicate triangles are reported
+/// on their own and left out of the edge checks so a single bad triangle is not
+/// reported again for each of its edges.
+pub fn validate_mesh(mesh: &Mesh) -> Vec<MeshIssue> {
+    let vertices = &mesh.vertices.vertex;
+    let mut issues = Vec::new();
+    let mut seen_triangles: HashMap<[usize; 3], usize> = HashMap::new();
+    // undirected edge to the triangles using it and whether they traverse it from low to high
+    let mut edges: HashMap<[usize; 2], Vec<(usize, bool)>> = HashMap::new();
+
+    for (index, triangle) in mesh.triangles.triangle.iter().enumerate() {
+        let corners = [triangle.v1, triangle.v2, triangle.v3];
+
+        if corners.iter().any(|&corner| corner >= vertices.len()) {
+            issues.push(triangle_issue(MeshIssueKind::OutOfRangeIndex, vec![index]));
+            continue;
+        }
+
+        let [a, b, c] = corners.map(|corner| {
+            let vertex = &vertices[corner];
+            DVec3::new(vertex.x, vertex.y, vertex.z)
+        });
+        let area = (b - a).cross(c - a).length();
+        let longest_edge = (b - a).length().max((c - b).length()).max((a - c).length());
+        if corners[0] == corners[1]
+            || corners[1] == corners[2]
+            || corners[2] == corners[0]
+            || area <= DEGENERATE_TOLERANCE * longest_edge * longest_edge
+        {
+            issues.push(triangle_issue(
+                MeshIssueKind::DegenerateTriangle,
+                vec![index],
+            ));
+            continue;
+        }
+
+        let mut sorted = corners;
+        sorted.sort_unstable();
+        if let Some(&first) = seen_triangles.get(&sorted) {
+            issues.push(triangle_issue(
+                MeshIssueKind::DuplicateTriangle,
+                vec![first, index],
+            ));
+            continue;
+        }
+        seen_triangles.insert(sorted, index);
+
+        for (from, to) in [(0, 1), (1, 2), (2, 0)] {
+            let (from, to) = (corners[from], corners[to]);
+            edges
+                .entry([from.min(to), from.max(to)])
+                .or_default()
+                .push((index, from < to));
+        }
+    }
+
+    let mut edges: Vec<_> = edges.into_iter().collect();
+    edges.sort_unstable_by_key(|(edge, _)| *edge);
+    for (edge, users) in edges {
+        let kind = match users.as_slice() {
+            [_] => MeshIssueKind::OpenEdge,
+            [(_, first), (_, second)] if first == second => MeshIssueKind::InconsistentWinding,
+            [_, _] => continue,
+            _ => MeshIssueKind::NonManifoldEdge,
+        };
+        issues.push(MeshIssue {
+            kind,
+            triangles: users.iter().map(|(triangle, _)| *triangle).collect(),
+            edge: Some(edge),
+        });
+    }
+
+    issues
+}
+
+fn triangle_issue(kind: MeshIssueKind, triangles: Vec<usize>) -> MeshIssue {
+    MeshIssue {
+        kind,
+        triangles,
+        edge: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{open_model_parts_from_test_resource, tetrahedron};
+    use threemf::model::{Triangle, Vertex};
+
+    fn kinds(issues: &[MeshIssue]) -> Vec<MeshIssueKind> {
+        issues.iter().map(|issue| issue.kind).collect()
+    }
+
+    #[test]
+    fn test_box_is_valid() {
+        let validations = validate_model(&open_model_parts_from_test_resource("box.3mf"));
+
+        assert!(
+            validations.len() == 1,
+            "Number of validated objects is wrong"
+        );
+        assert!(
+            validations[0].issues.is_empty(),
+            "Issues found in a closed box: {:?}",
+            validations[0].issues
+        );
+    }
+
+    #[test]
+    fn test_open_edges_are_found() {
+        let mut mesh = tetrahedron();
+        mesh.triangles.triangle.pop();
+
+        let issues = validate_mesh(&mesh);
+        assert!(
+            kinds(&issues) == vec![MeshIssueKind::OpenEdge; 3],
+            "Missing triangle should leave three open edges: {:?}",
+            issues
+        );
+    }
+
+    #[test]
+    fn test_flipped_triangle_has_inconsistent_winding() {
+        let mut mesh = tetrahedron();
+        mesh.triangles.triangle[2] = Triangle {
+            v1: 1,
+            v2: 3,
+            v3: 2,
+        };
+
+        let issues = validate_mesh(&mesh);
+        assert!(
+            issues.len() == 3
+                && issues
+                    .iter()
+                    .all(|issue| issue.kind == MeshIssueKind::InconsistentWinding
+                        && issue.triangles.contains(&2)),
+            "Every edge of the flipped triangle should be reported: {:?}",
+            issues
+        );
+    }
+
+    #[test]
+    fn test_triangle_issues_are_found() {
+        let mut mesh = tetrahedron();
+        let triangles = &mut mesh.triangles.triangle;
+        triangles.push(Triangle {
+            v1: 0,
+            v2: 1,
+            v3: 9,
+        });
+        triangles.push(Triangle {
+            v1: 0,
+            v2: 0,
+            v3: 1,
+        });
+        triangles.push(Triangle {
+            v1: 1,
+            v2: 0,
+            v3: 2,
+        });
+
+        let issues = validate_mesh(&mesh);
+        assert!(
+            kinds(&issues)
+                == vec![
+                    MeshIssueKind::OutOfRangeIndex,
+                    MeshIssueKind::DegenerateTriangle,
+                    MeshIssueKind::DuplicateTriangle,
+                ],
+            "Triangle issues are wrong: {:?}",
+            issues
+        );
+        assert!(
+            issues[2].triangles == vec![0, 6],
+            "Duplicate should reference the original triangle"
+        );
+    }
+
+    #[test]
+    fn test_non_manifold_edge_is_found() {
+        let mut mesh = tetrahedron();
+        mesh.vertices.vertex.push(Vertex {
+            x: 1.0,
+            y: 1.0,
+            z: -1.0,
+        });
+        mesh.triangles.triangle.push(Triangle {
+            v1: 0,
+            v2: 1,
+            v3: 4,
+        });
+
+        let issues = validate_mesh(&mesh);
+        let non_manifold: Vec<_> = issues
+            .iter()
+            .filter(|issue| issue.kind == MeshIssueKind::NonManifoldEdge)
+            .collect();
+        assert!(
+            non_manifold.len() == 1 && non_manifold[0].edge == Some([0, 1]),
+            "Edge shared by three triangles is not reported: {:?}",
+            issues
+        );
+    }
+}
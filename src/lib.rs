@@ -1,27 +1,144 @@
 // mod threemf_reader;
+mod config;
+mod gcode;
+mod geometry;
+mod obj;
+mod renderer;
+mod slicer;
+mod stl;
+#[cfg(test)]
+mod test_support;
 mod threemf;
 mod widgets;
-use eframe::egui_wgpu::{self, wgpu::util::DeviceExt};
+use config::{printer_profiles::PrinterProfiles, recent_files::RecentFiles};
 use egui_code_editor::{CodeEditor, Syntax};
-use threemf::threemf_reader;
-use wgpu::{self, ColorTargetState, ColorWrites};
-use widgets::tree;
+use gcode::{
+    gcode_reader::{self, GcodeMove, GcodeProgram},
+    gcode_writer::{self, GcodeSettings},
+    print_estimate::{self, FilamentSettings, MachineLimits, PrintEstimate},
+};
+use geometry::{
+    analysis::{self, ModelReport},
+    arrange::{self, ArrangeSettings},
+    build::TriangleSet,
+    build_volume::{self, ItemPlacement},
+    repair::{self, RepairOperation},
+    transform::{mat4_from_optional_threemf_transform, threemf_transform_from_mat4},
+    validation::{self, ObjectValidation},
+};
+use obj::obj_reader::{self, ObjInfo};
+use renderer::{
+    build_plate::build_plate_lines,
+    gizmo::{Gizmo, GizmoTarget, TransformEdit},
+    picking::PickedTriangle,
+    Custom3d, ViewportOutput,
+};
+use slicer::planar_slicer::{self, Layer, SliceSettings};
+use stl::stl_reader::{self, StlFormat, StlInfo};
+use threemf::{
+    threemf_package::Package,
+    threemf_reader::{self, ModelParts},
+    threemf_writer,
+    xml_edit::{self, Splice},
+};
+use widgets::{
+    analysis as analysis_widget,
+    item_properties::item_properties_ui,
+    layer_preview::LayerPreview,
+    print_estimate as estimate_widget,
+    printer::printer_profiles_ui,
+    selection::{self, ModelSelection},
+    text_edits::{self, TextEdits, TextError},
+    thumbnails::{self, RecentFilesGallery, Thumbnail},
+    toolpath::ToolpathView,
+    tree::{self, NodeId, TreeStore},
+    tree_view::{TreeEdit, TreeView},
+    validation as validation_widget,
+};
 
-use std::{ffi::OsStr, fs, path::PathBuf};
+use std::{
+    ffi::OsStr,
+    fs,
+    ops::Range,
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
 use anyhow::{anyhow, Result};
-use egui::{DroppedFile, Layout};
+use egui::{
+    text::{CCursor, CCursorRange},
+    text_edit::TextEditOutput,
+    DroppedFile, Layout,
+};
+
+/// View in which a node of the trees was selected, the other views follow it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SelectionSource {
+    Tree,
+    Text,
+    Viewport,
+}
 
 pub struct MyApp {
     name: String,
     dropped_files: Vec<DroppedFile>,
     file_to_render: Option<String>,
+    /// Text of an imported file whose geometry is shown as a 3MF model.
+    source_text: Option<String>,
+    show_source_text: bool,
     rendered_file_name: Option<String>,
     font_size: f32,
-    trees: Option<Vec<tree::Tree>>,
+    trees: Option<TreeStore>,
+    tree_view: TreeView,
     show_log: bool,
     show_viewport: bool,
+    show_analysis: bool,
+    show_validation: bool,
+    show_layers: bool,
+    show_toolpath: bool,
+    show_estimate: bool,
+    show_properties: bool,
+    highlight_defects: bool,
     render: Option<Custom3d>,
+    source_path: Option<PathBuf>,
+    package: Option<Package>,
+    model_parts: Option<ModelParts>,
+    /// Images of the package and its objects shown next to the file name.
+    thumbnails: Vec<Thumbnail>,
+    recent_files: RecentFiles,
+    recent_files_gallery: RecentFilesGallery,
+    analysis: Option<ModelReport>,
+    validation: Option<Vec<ObjectValidation>>,
+    slice_settings: SliceSettings,
+    layers: Option<Vec<Layer>>,
+    layer_preview: Option<LayerPreview>,
+    gcode_settings: GcodeSettings,
+    /// Imported G-code and what of it is drawn in the viewport.
+    gcode: Option<GcodeProgram>,
+    toolpath_view: Option<ToolpathView>,
+    machine_limits: MachineLimits,
+    filament_settings: FilamentSettings,
+    print_estimate: Option<PrintEstimate>,
+    printer_profiles: PrinterProfiles,
+    arrange_settings: ArrangeSettings,
+    /// Build items of the model checked against the selected printer.
+    build_volume: Option<Vec<ItemPlacement>>,
+    /// Build item of the root model that is edited in the properties panel and the viewport.
+    selected_item: Option<usize>,
+    gizmo: Gizmo,
+    /// Part of the model selected in the tree, the text or the viewport.
+    selection: Option<ModelSelection>,
+    /// Byte range of the text view to select and scroll to in the next frame.
+    text_selection: Option<Range<usize>>,
+    /// Edits made in the tree as the documents they were made in and the splices that undo
+    /// them, the last edit last.
+    undo_edits: Vec<(String, Splice)>,
+    /// Undone edits as the splices that make them again.
+    redo_edits: Vec<(String, Splice)>,
+    /// Typing in the text view, read into the document shown once it stops.
+    text_edits: TextEdits,
+    save_as_path: Option<String>,
+    export_gcode_path: Option<String>,
 }
 
 impl Default for MyApp {
@@ -30,22 +147,251 @@ impl Default for MyApp {
             name: "AMRUST".to_owned(),
             dropped_files: Vec::new(),
             file_to_render: None,
+            source_text: None,
+            show_source_text: false,
             rendered_file_name: None,
             font_size: 14.0,
             trees: None,
+            tree_view: TreeView::default(),
             show_log: false,
             show_viewport: false,
+            show_analysis: true,
+            show_validation: true,
+            show_layers: true,
+            show_toolpath: true,
+            show_estimate: true,
+            show_properties: true,
+            highlight_defects: true,
             render: None,
+            source_path: None,
+            package: None,
+            model_parts: None,
+            thumbnails: Vec::new(),
+            recent_files: RecentFiles::load(),
+            recent_files_gallery: RecentFilesGallery::default(),
+            analysis: None,
+            validation: None,
+            slice_settings: SliceSettings::default(),
+            layers: None,
+            layer_preview: None,
+            gcode_settings: GcodeSettings::default(),
+            gcode: None,
+            toolpath_view: None,
+            machine_limits: MachineLimits::default(),
+            filament_settings: FilamentSettings::default(),
+            print_estimate: None,
+            printer_profiles: PrinterProfiles::load(),
+            arrange_settings: ArrangeSettings::default(),
+            build_volume: None,
+            selected_item: None,
+            gizmo: Gizmo::default(),
+            selection: None,
+            text_selection: None,
+            undo_edits: Vec::new(),
+            redo_edits: Vec::new(),
+            text_edits: TextEdits::default(),
+            save_as_path: None,
+            export_gcode_path: None,
         }
     }
 }
 
 impl eframe::App for MyApp {
     fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        if self.text_edits.pending() {
+            if self.text_edits.due(ctx.input(|i| i.time)) {
+                self.read_text_edits(frame);
+            } else {
+                ctx.request_repaint_after(Duration::from_secs_f64(text_edits::PARSE_DELAY));
+            }
+        }
+        let save = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::S);
+        if ctx.input_mut(|i| i.consume_shortcut(&save)) {
+            if let Err(e) = self.save(frame) {
+                log::error!("{:?}", e);
+            }
+        }
+        // text fields undo their own typing
+        if ctx.memory(|memory| memory.focused().is_none()) {
+            let pressed = |modifiers, key| {
+                ctx.input_mut(|i| i.consume_shortcut(&egui::KeyboardShortcut::new(modifiers, key)))
+            };
+            let redo = pressed(
+                egui::Modifiers::COMMAND | egui::Modifiers::SHIFT,
+                egui::Key::Z,
+            ) || pressed(egui::Modifiers::COMMAND, egui::Key::Y);
+            if redo || pressed(egui::Modifiers::COMMAND, egui::Key::Z) {
+                if let Err(e) = self.undo_edit(frame, redo) {
+                    log::error!("{:?}", e);
+                }
+            }
+        }
+
         egui::TopBottomPanel::top("top panel")
             .resizable(false)
             .show(ctx, |ui| {
                 egui::menu::bar(ui, |ui| {
+                    ui.menu_button("File", |ui| {
+                        if ui
+                            .add_enabled(
+                                self.source_path.is_some(),
+                                egui::Button::new("Save").shortcut_text("Ctrl+S"),
+                            )
+                            .clicked()
+                        {
+                            if let Err(e) = self.save(frame) {
+                                log::error!("{:?}", e);
+                            }
+                            ui.close_menu();
+                        }
+                        if ui
+                            .add_enabled(
+                                self.model_parts.is_some(),
+                                egui::Button::new("Save As..."),
+                            )
+                            .clicked()
+                        {
+                            self.ask_save_as_path();
+                            ui.close_menu();
+                        }
+                    });
+                    ui.menu_button("Edit", |ui| {
+                        for (redo, label, shortcut, enabled) in [
+                            (false, "Undo", "Ctrl+Z", !self.undo_edits.is_empty()),
+                            (true, "Redo", "Ctrl+Y", !self.redo_edits.is_empty()),
+                        ] {
+                            let button = egui::Button::new(label).shortcut_text(shortcut);
+                            if ui.add_enabled(enabled, button).clicked() {
+                                if let Err(e) = self.undo_edit(frame, redo) {
+                                    log::error!("{:?}", e);
+                                }
+                                ui.close_menu();
+                            }
+                        }
+                    });
+                    ui.menu_button("Slice", |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("Layer height");
+                            ui.add(
+                                egui::DragValue::new(&mut self.slice_settings.layer_height)
+                                    .speed(0.01)
+                                    .range(0.01..=10.0),
+                            );
+                        });
+                        if ui
+                            .add_enabled(self.model_parts.is_some(), egui::Button::new("Slice"))
+                            .clicked()
+                        {
+                            if let Err(e) = self.slice_model() {
+                                log::error!("{:?}", e);
+                            }
+                            ui.close_menu();
+                        }
+                        ui.separator();
+                        let settings = &mut self.gcode_settings;
+                        egui::Grid::new("gcode_settings").show(ui, |ui| {
+                            ui.label("Perimeters");
+                            ui.add(egui::DragValue::new(&mut settings.perimeters).range(0..=10));
+                            ui.end_row();
+                            ui.label("Infill density");
+                            ui.add(
+                                egui::DragValue::new(&mut settings.infill_density)
+                                    .speed(0.01)
+                                    .range(0.0..=1.0),
+                            );
+                            ui.end_row();
+                            ui.label("Top layers");
+                            ui.add(egui::DragValue::new(&mut settings.top_layers).range(0..=50));
+                            ui.end_row();
+                            ui.label("Bottom layers");
+                            ui.add(egui::DragValue::new(&mut settings.bottom_layers).range(0..=50));
+                            ui.end_row();
+                            ui.label("Nozzle temperature");
+                            ui.add(
+                                egui::DragValue::new(&mut settings.nozzle_temperature)
+                                    .range(0..=400),
+                            );
+                            ui.end_row();
+                            ui.label("Bed temperature");
+                            ui.add(
+                                egui::DragValue::new(&mut settings.bed_temperature).range(0..=150),
+                            );
+                            ui.end_row();
+                        });
+                        if ui
+                            .add_enabled(
+                                self.layers.is_some(),
+                                egui::Button::new("Export G-code..."),
+                            )
+                            .clicked()
+                        {
+                            self.export_gcode_path = Some(
+                                self.source_path
+                                    .as_ref()
+                                    .map(|path| path.with_extension("gcode").display().to_string())
+                                    .unwrap_or_default(),
+                            );
+                            ui.close_menu();
+                        }
+                    });
+                    ui.menu_button("Printer", |ui| {
+                        if printer_profiles_ui(ui, &mut self.printer_profiles) {
+                            self.check_build_volume();
+                            self.update_build_plate(frame);
+                        }
+                        ui.separator();
+                        if ui.button("Save Profiles").clicked() {
+                            if let Err(e) = self.printer_profiles.save() {
+                                log::error!("{:?}", e);
+                            }
+                            ui.close_menu();
+                        }
+                    });
+                    ui.menu_button("Arrange", |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("Spacing");
+                            ui.add(
+                                egui::DragValue::new(&mut self.arrange_settings.spacing)
+                                    .speed(0.5)
+                                    .range(0.0..=100.0)
+                                    .suffix(" mm"),
+                            );
+                        });
+                        ui.checkbox(&mut self.arrange_settings.allow_rotation, "Allow rotation");
+                        if ui
+                            .add_enabled(self.model_parts.is_some(), egui::Button::new("Arrange"))
+                            .clicked()
+                        {
+                            if let Err(e) = self.arrange_build(frame) {
+                                log::error!("{:?}", e);
+                            }
+                            ui.close_menu();
+                        }
+                    });
+                    ui.menu_button("Repair", |ui| {
+                        let enabled = self.model_parts.is_some();
+                        if ui
+                            .add_enabled(enabled, egui::Button::new("Repair All"))
+                            .clicked()
+                        {
+                            if let Err(e) = self.repair_model(frame, &RepairOperation::ALL) {
+                                log::error!("{:?}", e);
+                            }
+                            ui.close_menu();
+                        }
+                        ui.separator();
+                        for operation in RepairOperation::ALL {
+                            if ui
+                                .add_enabled(enabled, egui::Button::new(operation.to_string()))
+                                .clicked()
+                            {
+                                if let Err(e) = self.repair_model(frame, &[operation]) {
+                                    log::error!("{:?}", e);
+                                }
+                                ui.close_menu();
+                            }
+                        }
+                    });
                     ui.menu_button("View", |ui| {
                         if ui.button("Show Log").clicked() {
                             self.show_log = !self.show_log;
@@ -59,22 +405,167 @@ impl eframe::App for MyApp {
                         {
                             self.show_viewport = true;
                         }
+                        ui.add_enabled(
+                            self.analysis.is_some(),
+                            egui::Checkbox::new(&mut self.show_analysis, "Show Analysis"),
+                        );
+                        ui.add_enabled(
+                            self.validation.is_some(),
+                            egui::Checkbox::new(&mut self.show_validation, "Show Validation"),
+                        );
+                        ui.add_enabled(
+                            self.layers.is_some(),
+                            egui::Checkbox::new(&mut self.show_layers, "Show Layers"),
+                        );
+                        ui.add_enabled(
+                            self.gcode.is_some(),
+                            egui::Checkbox::new(&mut self.show_toolpath, "Show Toolpath"),
+                        );
+                        ui.add_enabled(
+                            self.gcode.is_some(),
+                            egui::Checkbox::new(&mut self.show_estimate, "Show Estimate"),
+                        );
+                        ui.add_enabled(
+                            self.model_parts.is_some(),
+                            egui::Checkbox::new(&mut self.show_properties, "Show Properties"),
+                        );
                     })
                 });
             });
+        self.save_as_window(ctx);
+        self.export_gcode_window(ctx);
+
         if let Some(trees) = &self.trees {
-            egui::SidePanel::left("left_panel")
+            let output = egui::SidePanel::left("left_panel")
                 .resizable(true)
                 .default_width(100.0)
+                .show(ctx, |ui| self.tree_view.ui(ui, trees))
+                .inner;
+            if let Some(id) = output.clicked {
+                if let Err(e) = self.select_node(frame, id, SelectionSource::Tree) {
+                    log::error!("{:?}", e);
+                }
+            }
+            if let Some(edit) = output.edit {
+                if let Err(e) = self.apply_tree_edit(frame, &edit) {
+                    log::error!("Failed to edit the tree: {:?}", e);
+                }
+            }
+        }
+
+        if let (true, Some(report)) = (self.show_analysis, &self.analysis) {
+            egui::SidePanel::right("analysis_panel")
+                .resizable(true)
+                .default_width(220.0)
                 .show(ctx, |ui| {
-                    egui::ScrollArea::both().auto_shrink(false).show(ui, |ui| {
-                        let mut count = 1;
-                        for tree in trees {
-                            tree.ui(ui, 0, &format!("{} - {}", tree.name, count));
-                            count += 1;
-                        }
+                    egui::ScrollArea::vertical()
+                        .auto_shrink(false)
+                        .show(ui, |ui| analysis_widget::model_report_ui(ui, report));
+                });
+        }
+
+        if let (true, Some(validations)) = (self.show_validation, &self.validation) {
+            let mut highlight_defects = self.highlight_defects;
+            egui::SidePanel::right("validation_panel")
+                .resizable(true)
+                .default_width(220.0)
+                .show(ctx, |ui| {
+                    ui.checkbox(&mut highlight_defects, "Highlight defects in viewport");
+                    ui.separator();
+                    egui::ScrollArea::vertical()
+                        .auto_shrink(false)
+                        .show(ui, |ui| validation_widget::validation_ui(ui, validations));
+                });
+            if highlight_defects != self.highlight_defects {
+                self.highlight_defects = highlight_defects;
+                if let Err(e) = self.refresh_model_views(frame) {
+                    log::error!("{:?}", e);
+                }
+            }
+        }
+
+        if let (true, Some(layers), Some(preview)) =
+            (self.show_layers, &self.layers, &mut self.layer_preview)
+        {
+            egui::SidePanel::right("layer_panel")
+                .resizable(true)
+                .default_width(320.0)
+                .show(ctx, |ui| preview.ui(ui, layers));
+        }
+
+        if let (true, Some(program), Some(view)) =
+            (self.show_toolpath, &self.gcode, &mut self.toolpath_view)
+        {
+            let mut changed = false;
+            egui::SidePanel::right("toolpath_panel")
+                .resizable(true)
+                .default_width(220.0)
+                .show(ctx, |ui| changed = view.ui(ui, program));
+            if let (true, Some(render)) = (changed, self.render.as_mut()) {
+                render.update_lines(frame, &view.lines(program));
+            }
+        }
+
+        if let (true, Some(estimate)) = (self.show_estimate, &self.print_estimate) {
+            let mut changed = false;
+            egui::SidePanel::right("estimate_panel")
+                .resizable(true)
+                .default_width(220.0)
+                .show(ctx, |ui| {
+                    egui::CollapsingHeader::new("Printer and filament").show(ui, |ui| {
+                        changed = estimate_widget::estimate_settings_ui(
+                            ui,
+                            &mut self.machine_limits,
+                            &mut self.filament_settings,
+                        );
                     });
+                    ui.separator();
+                    estimate_widget::print_estimate_ui(ui, estimate);
+                });
+            if changed {
+                self.estimate_print();
+            }
+        }
+
+        if let (true, Some(model_parts)) = (self.show_properties, &self.model_parts) {
+            let root = model_parts.root_part();
+            let items: Vec<String> = root
+                .model
+                .build
+                .item
+                .iter()
+                .enumerate()
+                .map(|(index, item)| format!("Item {} (object {})", index, item.objectid))
+                .collect();
+            if self.selected_item.is_some_and(|index| index >= items.len()) {
+                self.selected_item = None;
+            }
+            let transform = self.selected_item.map(|index| {
+                mat4_from_optional_threemf_transform(
+                    root.model.build.item[index].transform.as_ref(),
+                )
+            });
+            let (unit, _) = analysis::unit_info(&root.model.unit);
+
+            let mut edit = None;
+            egui::SidePanel::right("properties_panel")
+                .resizable(true)
+                .default_width(220.0)
+                .show(ctx, |ui| {
+                    edit = item_properties_ui(
+                        ui,
+                        &items,
+                        &mut self.selected_item,
+                        transform,
+                        &mut self.gizmo,
+                        unit,
+                    );
                 });
+            if let (Some(index), Some(edit)) = (self.selected_item, edit) {
+                if let Err(e) = self.edit_item_transform(frame, index, edit) {
+                    log::error!("{:?}", e);
+                }
+            }
         }
 
         if self.show_log {
@@ -86,13 +577,31 @@ impl eframe::App for MyApp {
                 });
         }
         egui::CentralPanel::default().show(ctx, |ui| {
-            if let Some(string) = &self.file_to_render {
-                let mut text_to_display = string.clone();
+            let mut clicked_offset = None;
+            if self.file_to_render.is_some() {
+                let showing_source = self.show_source_text && self.source_text.is_some();
+                let syntax = if showing_source {
+                    Syntax::simple("#")
+                } else if self.gcode.is_some() {
+                    Syntax::simple(";")
+                } else {
+                    Syntax::simple("xml")
+                };
                 ui.vertical(|ui| {
                     ui.horizontal_top(|ui| {
+                        thumbnails::thumbnails_ui(ui, &self.thumbnails);
                         if let Some(file_name) = &self.rendered_file_name {
                             ui.label(file_name);
                         }
+                        if self.text_edits.unsaved {
+                            ui.label(egui::RichText::new("Edited").weak())
+                                .on_hover_text("Ctrl+S saves the text");
+                        }
+                        if self.source_text.is_some() {
+                            ui.separator();
+                            ui.selectable_value(&mut self.show_source_text, false, "3MF Model");
+                            ui.selectable_value(&mut self.show_source_text, true, "Source");
+                        }
 
                         ui.with_layout(Layout::right_to_left(egui::Align::Min), |ui| {
                             if ui.button("Clear content").clicked() {
@@ -114,26 +623,93 @@ impl eframe::App for MyApp {
                             .spacing(10.0),
                     );
 
-                    egui::ScrollArea::both()
+                    let mut text_selection = self.text_selection.take();
+                    if let (Some(error), Some(text)) =
+                        (&self.text_edits.error, &self.file_to_render)
+                    {
+                        let message = match error.offset {
+                            Some(offset) => {
+                                let (line, column) = text_edits::line_column(text, offset);
+                                format!("Line {}, column {}: {}", line, column, error.message)
+                            }
+                            None => error.message.clone(),
+                        };
+                        let label = egui::Label::new(
+                            egui::RichText::new(message).color(ui.visuals().error_fg_color),
+                        );
+                        if ui
+                            .add(label.sense(egui::Sense::click()))
+                            .on_hover_text("Show in the text")
+                            .clicked()
+                        {
+                            text_selection = error.offset.map(|offset| offset..offset);
+                        }
+                    }
+
+                    // the source of an imported mesh is only shown, its model is edited
+                    let mut source_text = self.source_text.clone().filter(|_| showing_source);
+                    let Some(text) = source_text.as_mut().or(self.file_to_render.as_mut()) else {
+                        return;
+                    };
+                    let output = egui::ScrollArea::both()
                         .auto_shrink(false)
                         .scroll_bar_visibility(
                             egui::scroll_area::ScrollBarVisibility::VisibleWhenNeeded,
                         )
                         .show(ui, |ui| {
-                            CodeEditor::default()
+                            let output = CodeEditor::default()
                                 .with_fontsize(self.font_size)
-                                .with_syntax(Syntax::simple("xml"))
+                                .with_syntax(syntax)
                                 .auto_shrink(false)
                                 .with_numlines(false)
-                                .show(ui, &mut text_to_display);
-                        });
+                                .show(ui, text);
+                            if let Some(range) = text_selection {
+                                select_text(ui, &output, text, range);
+                            }
+                            output
+                        })
+                        .inner;
+                    if let (true, Some(cursor)) = (output.response.clicked(), output.cursor_range) {
+                        let index = cursor.primary.ccursor.index;
+                        clicked_offset = Some(
+                            text.char_indices()
+                                .nth(index)
+                                .map_or(text.len(), |(offset, _)| offset),
+                        );
+                    }
+                    if output.response.changed() && !showing_source {
+                        self.text_edits.changed(ui.input(|i| i.time));
+                    }
                 });
+            } else if !self.recent_files.paths.is_empty() {
+                let clicked = egui::ScrollArea::vertical()
+                    .auto_shrink(false)
+                    .show(ui, |ui| {
+                        self.recent_files_gallery.ui(ui, &self.recent_files)
+                    })
+                    .inner;
+                if let Some(path) = clicked {
+                    if let Err(e) = self.processed_file_and_update_app(&path, frame) {
+                        log::error!("{:?}", e);
+                    }
+                }
             } else {
                 ui.centered_and_justified(|ui| {
                     ui.image(egui::include_image!("../assets/ferris.png"));
                 });
             }
 
+            let clicked_node = clicked_offset.and_then(|offset| {
+                self.trees
+                    .as_ref()?
+                    .node_at(self.displayed_document()?, offset)
+            });
+            if let Some(id) = clicked_node {
+                if let Err(e) = self.select_node(frame, id, SelectionSource::Text) {
+                    log::error!("{:?}", e);
+                }
+            }
+
             if !self.dropped_files.is_empty() {
                 for i in 0..self.dropped_files.len() {
                     let file = self.dropped_files[i].clone();
@@ -165,36 +741,45 @@ impl eframe::App for MyApp {
             });
 
             if self.show_viewport {
-                ctx.show_viewport_immediate(
+                let target = self.gizmo_target();
+                let output = ctx.show_viewport_immediate(
                     egui::ViewportId::from_hash_of("immediate_viewport"),
                     egui::ViewportBuilder::default()
-                        .with_title("Immediate Viewport")
-                        .with_inner_size([200.0, 100.0]),
+                        .with_title("Viewport")
+                        .with_inner_size([640.0, 480.0]),
                     |ctx, class| {
                         assert!(
                             class == egui::ViewportClass::Immediate,
                             "This egui backend doesn't support multiple viewports"
                         );
 
+                        let mut output = ViewportOutput::default();
                         egui::CentralPanel::default().show(ctx, |ui| {
-                            ui.vertical(|ui| {
-                                ui.label("Hello from immediate viewport");
-
-                                egui::Frame::canvas(ui.style()).show(ui, |ui| {
-                                    // self.custom_painting(ui);
-                                    if let Some(render_3d) = &self.render.as_ref() {
-                                        render_3d.custom_painting(ui, 45.0);
-                                    }
-                                });
-                                ui.label("Drag to rotate!");
+                            egui::Frame::canvas(ui.style()).show(ui, |ui| {
+                                if let Some(render_3d) = self.render.as_mut() {
+                                    let gizmo =
+                                        target.as_ref().map(|target| (&mut self.gizmo, target));
+                                    output = render_3d.custom_painting(ui, gizmo);
+                                }
                             });
                         });
 
                         if ctx.input(|i| i.viewport().close_requested()) {
                             self.show_viewport = false;
                         }
+                        output
                     },
                 );
+                if let (Some(index), Some(edit)) = (self.selected_item, output.edit) {
+                    if let Err(e) = self.edit_item_transform(frame, index, edit) {
+                        log::error!("{:?}", e);
+                    }
+                }
+                if let Some(picked) = output.picked {
+                    if let Err(e) = self.select_picked(frame, picked) {
+                        log::error!("{:?}", e);
+                    }
+                }
             }
         });
     }
@@ -206,35 +791,71 @@ impl MyApp {
         path: &PathBuf,
         frame: &eframe::Frame,
     ) -> Result<bool> {
+        let mut loaded_package = None;
+        let mut loaded_model_parts = None;
+        let mut source_text = None;
+        let mut loaded_gcode = None;
+        let mut loaded_text = false;
+        let mut loaded_thumbnails = Vec::new();
         let processed_file_and_tree = match path.extension().and_then(OsStr::to_str) {
             Some("3mf") => {
                 let file = fs::File::open(path)?;
-                let file_to_render =
-                    threemf_reader::load_threemf_get_root_model_file_as_string(file)?;
-                let result = tree::Tree::new_trees_from_xml_string(&file_to_render);
+                let package = threemf_reader::load_threemf_package(file)?;
+                let model_parts = threemf_reader::get_model_parts_from_package(&package)?;
+                loaded_thumbnails = thumbnails::package_thumbnails(path, &package, &model_parts);
+                let result = trees_from_model_parts(&model_parts);
                 match result {
                     Ok(trees) => {
                         let trees = Some(trees);
-                        self.render = Some(Custom3d::new(frame));
-                        Ok((Some(file_to_render), trees))
+                        let file_to_render = Some(model_parts.root_part().xml.to_string());
+                        loaded_package = Some((package, model_parts));
+                        Ok((file_to_render, trees))
                     }
                     Err(e) => return Err(e),
                 }
             }
+            Some("stl") => {
+                let (model, info) = stl_reader::load_stl_get_model(fs::File::open(path)?)?;
+                let model_parts = ModelParts::from_model("/3D/3dmodel.model", model)?;
+                let file_to_render = Some(model_parts.root_part().xml.to_string());
+                let trees = Some(TreeStore::from_trees(vec![stl_summary_tree(&info)]));
+                loaded_model_parts = Some(model_parts);
+                Ok((file_to_render, trees))
+            }
             Some("txt") => {
                 let file_to_render = Some(fs::read_to_string(path)?);
+                loaded_text = true;
                 Ok((file_to_render, None))
             }
             Some("obj") => {
-                let file_to_render = Some(fs::read_to_string(path)?);
-                Ok((file_to_render, None))
+                let obj_content = fs::read_to_string(path)?;
+                let (model, info) =
+                    obj_reader::get_model_from_obj_string(&obj_content, |library| {
+                        fs::read_to_string(path.with_file_name(library))
+                    })?;
+                let model_parts =
+                    obj_reader::get_model_parts_from_obj("/3D/3dmodel.model", model, &info)?;
+                let file_to_render = Some(model_parts.root_part().xml.to_string());
+                let trees = Some(TreeStore::from_trees(vec![obj_summary_tree(&info)]));
+                loaded_model_parts = Some(model_parts);
+                source_text = Some(obj_content);
+                Ok((file_to_render, trees))
+            }
+            Some("gcode") => {
+                let text = fs::read_to_string(path)?;
+                let program = gcode_reader::parse_gcode(&text)?;
+                let trees = Some(TreeStore::from_trees(vec![gcode_summary_tree(&program)]));
+                loaded_gcode = Some(program);
+                Ok((Some(text), trees))
             }
             Some("xml") => {
                 let file_to_render = fs::read_to_string(path)?;
-                let result = tree::Tree::new_trees_from_xml_string(&file_to_render);
+                let name = path.file_name().and_then(OsStr::to_str).unwrap_or_default();
+                let result = TreeStore::from_xml(name, file_to_render.as_str());
                 match result {
                     Ok(trees) => {
                         let trees = Some(trees);
+                        loaded_text = true;
                         Ok((Some(file_to_render), trees))
                     }
                     Err(e) => return Err(e),
@@ -247,11 +868,31 @@ impl MyApp {
             Ok((file_to_render, trees)) => {
                 self.clear_state();
                 self.file_to_render = file_to_render;
+                self.source_text = source_text;
                 self.trees = trees;
+                self.thumbnails = loaded_thumbnails;
+                if let Some((package, model_parts)) = loaded_package {
+                    self.package = Some(package);
+                    self.model_parts = Some(model_parts);
+                    self.source_path = Some(path.clone());
+                }
+                if let Some(model_parts) = loaded_model_parts {
+                    self.model_parts = Some(model_parts);
+                    self.source_path = Some(path.clone());
+                }
+                if let Some(program) = loaded_gcode {
+                    self.show_gcode(frame, program);
+                    self.source_path = Some(path.clone());
+                }
+                if loaded_text {
+                    self.source_path = Some(path.clone());
+                }
                 self.rendered_file_name = match path.file_name().and_then(OsStr::to_str) {
                     Some(file_name) => Some(file_name.to_string()),
                     None => None,
                 };
+                self.refresh_model_views(frame)?;
+                self.remember_recent_file(path);
                 Ok(true)
             }
             Err(e) => Err(e),
@@ -308,7 +949,9 @@ impl MyApp {
             Some("txt") => true,
             Some("obj") => true,
             Some("3mf") => true,
+            Some("stl") => true,
             Some("xml") => true,
+            Some("gcode") => true,
             _ => false,
         };
 
@@ -317,186 +960,934 @@ impl MyApp {
 
     fn clear_state(&mut self) {
         self.file_to_render = None;
+        self.source_text = None;
+        self.show_source_text = false;
         self.trees = None;
+        self.tree_view = TreeView::default();
         self.rendered_file_name = None;
+        self.source_path = None;
+        self.package = None;
+        self.model_parts = None;
+        self.thumbnails.clear();
+        self.analysis = None;
+        self.validation = None;
+        self.build_volume = None;
+        self.selected_item = None;
+        self.selection = None;
+        self.text_selection = None;
+        self.undo_edits.clear();
+        self.redo_edits.clear();
+        self.text_edits = TextEdits::default();
+        self.layers = None;
+        self.layer_preview = None;
+        self.gcode = None;
+        self.toolpath_view = None;
+        self.print_estimate = None;
+        self.render = None;
     }
-}
 
-pub struct Custom3d {
-    angle: f32,
-}
+    /// Puts the file first in the recent files and saves them.
+    fn remember_recent_file(&mut self, path: &Path) {
+        self.recent_files.add(path);
+        self.recent_files_gallery.forget(path);
+        if let Err(e) = self.recent_files.save() {
+            log::warn!("Failed to save recent files: {}", e);
+        }
+    }
 
-impl Custom3d {
-    pub fn new<'a>(cc: &'a eframe::Frame) -> Self {
-        // Get the WGPU render state from the eframe creation context. This can also be retrieved
-        // from `eframe::Frame` when you don't have a `CreationContext` available.
-        let binding = cc.wgpu_render_state();
-        let render_state = binding.as_ref().expect("WGPU enabled");
+    /// Runs repair operations on the loaded model and updates the views of the model.
+    /// The changes are logged and kept in memory until the model is saved.
+    fn repair_model(
+        &mut self,
+        frame: &eframe::Frame,
+        operations: &[RepairOperation],
+    ) -> Result<()> {
+        let Some(model_parts) = self.model_parts.as_mut() else {
+            return Ok(());
+        };
+        if repair::repair_model(model_parts, operations, self.package.as_ref())? == 0 {
+            return Ok(());
+        }
 
-        let device = &render_state.device;
+        self.model_text_changed(frame)
+    }
 
-        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
-            label: None,
-            source: wgpu::ShaderSource::Wgsl(include_str!("./custom3d_wgpu_shader.wgsl").into()),
-        });
+    /// Packs the build items on the bed of the selected printer and writes their new
+    /// transforms to the model.
+    fn arrange_build(&mut self, frame: &eframe::Frame) -> Result<()> {
+        let Some(model_parts) = self.model_parts.as_mut() else {
+            return Ok(());
+        };
+        let profile = self.printer_profiles.selected();
+        let arrangement = arrange::arrange_build(model_parts, profile, &self.arrange_settings)?;
 
-        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
-            label: None,
-            entries: &[wgpu::BindGroupLayoutEntry {
-                binding: 0,
-                visibility: wgpu::ShaderStages::VERTEX,
-                ty: wgpu::BindingType::Buffer {
-                    ty: wgpu::BufferBindingType::Uniform,
-                    has_dynamic_offset: false,
-                    min_binding_size: None,
-                },
-                count: None,
-            }],
-        });
+        let root = model_parts.root.clone();
+        let part = model_parts
+            .parts
+            .get_mut(&root)
+            .ok_or_else(|| anyhow!("Root part {} is missing", root))?;
+        for (index, transform) in arrangement.transforms.iter().enumerate() {
+            xml_edit::set_build_item_transform(part, index, transform)?;
+        }
+        if arrangement.fits {
+            log::info!(
+                "Arranged {} build items on {}",
+                arrangement.transforms.len(),
+                profile.name
+            );
+        } else {
+            log::warn!("The build items do not all fit on {}", profile.name);
+        }
 
-        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
-            label: None,
-            bind_group_layouts: &[&bind_group_layout],
-            push_constant_ranges: &[],
-        });
+        self.model_text_changed(frame)
+    }
 
-        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: None,
-            layout: Some(&pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &shader,
-                entry_point: "vs_main",
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
-                buffers: &[],
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &shader,
-                entry_point: "fs_main",
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
-                targets: &[Some(ColorTargetState {
-                    format: render_state.target_format,
-                    blend: None,
-                    write_mask: ColorWrites::all(),
-                })],
-            }),
-            primitive: wgpu::PrimitiveState::default(),
-            depth_stencil: None,
-            multisample: wgpu::MultisampleState::default(),
-            multiview: None,
-        });
+    /// Shows the edited text of the root model and rebuilds what is derived from the model.
+    /// Edits of the tree can no longer be undone, the text they were made in changed.
+    fn model_text_changed(&mut self, frame: &eframe::Frame) -> Result<()> {
+        self.undo_edits.clear();
+        self.redo_edits.clear();
+        self.text_edits.unsaved = true;
+        self.rebuild_from_model_text(frame)
+    }
 
-        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-            label: None,
-            contents: bytemuck::cast_slice(&[0.0]),
-            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::UNIFORM,
-        });
+    fn rebuild_from_model_text(&mut self, frame: &eframe::Frame) -> Result<()> {
+        let Some(model_parts) = &self.model_parts else {
+            return Ok(());
+        };
+        self.file_to_render = Some(model_parts.root_part().xml.to_string());
+        if self.package.is_some() {
+            self.trees = Some(trees_from_model_parts(model_parts)?);
+            self.tree_view.invalidate();
+        }
+        self.refresh_model_views(frame)
+    }
+
+    /// Makes an edit of the tree in the text of the document of the node.
+    fn apply_tree_edit(&mut self, frame: &eframe::Frame, edit: &TreeEdit) -> Result<()> {
+        // the node was read from the text before the typing
+        self.read_pending_text(frame)?;
+        let Some(trees) = &self.trees else {
+            return Ok(());
+        };
+        let node = trees.node(edit.node());
+        let (Some(source), Some(document)) = (&node.source, trees.document_name(edit.node()))
+        else {
+            return Err(anyhow!("{} was not read from a document", node.name));
+        };
+        let xml = trees.document_text(source.document);
+        let (tag, element) = (source.tag.clone(), source.element.clone());
+        let splice = match edit {
+            TreeEdit::SetAttribute { name, value, .. } => {
+                xml_edit::attribute_splice(xml, tag, name, value.as_deref())?
+            }
+            TreeEdit::SetText { text, .. } => xml_edit::text_splice(xml, tag, element, text)?,
+            TreeEdit::AddChild { name, .. } => xml_edit::child_splice(xml, tag, element, name)?,
+            TreeEdit::Remove { .. } => xml_edit::removal_splice(xml, element),
+        };
+
+        let document = document.to_string();
+        let undo = self.splice_document(frame, &document, &splice)?;
+        self.undo_edits.push((document, undo));
+        self.redo_edits.clear();
+        Ok(())
+    }
 
-        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: None,
-            layout: &bind_group_layout,
-            entries: &[wgpu::BindGroupEntry {
-                binding: 0,
-                resource: uniform_buffer.as_entire_binding(),
-            }],
+    /// Undoes the last edit of the tree, or with `redo` makes the last undone edit again.
+    fn undo_edit(&mut self, frame: &eframe::Frame, redo: bool) -> Result<()> {
+        let last = if redo {
+            self.redo_edits.pop()
+        } else {
+            self.undo_edits.pop()
+        };
+        let Some((document, splice)) = last else {
+            return Ok(());
+        };
+        if let Err(e) = self.read_pending_text(frame) {
+            // the edit stays to be undone once the text is fixed
+            if redo {
+                self.redo_edits.push((document, splice));
+            } else {
+                self.undo_edits.push((document, splice));
+            }
+            return Err(e);
+        }
+        let inverse = self.splice_document(frame, &document, &splice)?;
+        if redo {
+            self.undo_edits.push((document, inverse));
+        } else {
+            self.redo_edits.push((document, inverse));
+        }
+        Ok(())
+    }
+
+    /// Changes the text of a document of the trees, a model part or the opened XML file, and
+    /// rebuilds what is read from it. Returns the splice that undoes the change. Text that is
+    /// no longer a valid document is not kept.
+    fn splice_document(
+        &mut self,
+        frame: &eframe::Frame,
+        document: &str,
+        splice: &Splice,
+    ) -> Result<Splice> {
+        let replaced = self.trees.as_ref().and_then(|trees| {
+            let index = trees.document_index(document)?;
+            Some(trees.nodes_starting_in(index, splice.range.clone()))
         });
 
-        // Because the graphics pipeline must have the same lifetime as the egui render pass,
-        // instead of storing the pipeline in our `Custom3D` struct, we insert it into the
-        // `callback_resources` type map, which is stored alongside the render pass.
-        render_state
-            .renderer
-            .write()
-            .callback_resources
-            .insert(TriangleRenderResources {
-                pipeline,
-                bind_group,
-                uniform_buffer,
+        let undo = match self.model_parts.as_mut() {
+            Some(model_parts) => {
+                let part = model_parts
+                    .parts
+                    .get_mut(document)
+                    .ok_or_else(|| anyhow!("Model part {} does not exist", document))?;
+                let mut xml = part.xml.to_string();
+                let undo = splice.apply(&mut xml)?;
+                part.set_xml(xml, self.package.as_ref())?;
+                self.rebuild_from_model_text(frame)?;
+                undo
+            }
+            None => {
+                let mut xml = self
+                    .document_text(document)
+                    .ok_or_else(|| anyhow!("Document {} is not open", document))?
+                    .to_string();
+                let undo = splice.apply(&mut xml)?;
+                self.trees = Some(TreeStore::from_xml(document, xml.as_str())?);
+                self.file_to_render = Some(xml);
+                self.tree_view.invalidate();
+                undo
+            }
+        };
+
+        // nodes after the change moved by the number of nodes added or removed
+        if let (Some(replaced), Some(trees)) = (replaced, &self.trees) {
+            if let Some(index) = trees.document_index(document) {
+                let end = splice.range.start + splice.text.len();
+                let added = trees.nodes_starting_in(index, splice.range.start..end);
+                self.tree_view.nodes_replaced(replaced, added.len());
+            }
+        }
+        self.text_edits.unsaved = true;
+        Ok(undo)
+    }
+
+    /// Text of a document of the trees as it was last read, a model part or the opened XML
+    /// file.
+    fn document_text(&self, document: &str) -> Option<&str> {
+        match &self.model_parts {
+            Some(model_parts) => Some(&model_parts.parts.get(document)?.xml),
+            None => {
+                let trees = self.trees.as_ref()?;
+                Some(trees.document_text(trees.document_index(document)?))
+            }
+        }
+    }
+
+    /// Reads the typing in the text view into the document it shows, unless the text is not
+    /// a valid document. The error is shown with the text until it is fixed.
+    fn read_text_edits(&mut self, frame: &eframe::Frame) {
+        let result = self.parse_text(frame);
+        self.text_edits.read(result);
+    }
+
+    /// Reads typing that is still waiting for the typing to stop. Fails while the text is
+    /// not a valid document.
+    fn read_pending_text(&mut self, frame: &eframe::Frame) -> Result<()> {
+        if self.text_edits.pending() {
+            self.read_text_edits(frame);
+        }
+        match &self.text_edits.error {
+            Some(error) => Err(anyhow!("The text has an error: {}", error.message)),
+            None => Ok(()),
+        }
+    }
+
+    fn parse_text(&mut self, frame: &eframe::Frame) -> Result<(), TextError> {
+        let Some(text) = self.file_to_render.clone() else {
+            return Ok(());
+        };
+        if self.gcode.is_some() {
+            let program = gcode_reader::parse_gcode(&text)?;
+            self.trees = Some(TreeStore::from_trees(vec![gcode_summary_tree(&program)]));
+            self.tree_view.invalidate();
+            self.show_gcode(frame, program);
+            return Ok(());
+        }
+
+        let Some(document) = self.displayed_document_name().map(str::to_string) else {
+            return Ok(());
+        };
+        if let Some(error) = text_edits::xml_syntax_error(&text) {
+            return Err(error);
+        }
+        let old = self
+            .document_text(&document)
+            .ok_or_else(|| anyhow!("Document {} is not open", document))?;
+        // only the changed part is read again, so the tree keeps what is open around it
+        let splice = Splice::between(old, &text);
+        self.splice_document(frame, &document, &splice)?;
+        self.undo_edits.clear();
+        self.redo_edits.clear();
+        Ok(())
+    }
+
+    /// Writes the loaded file back to where it was read from: the model parts into their
+    /// package, the text of XML, G-code and plain text files as it is. Imported meshes are saved as a
+    /// new package.
+    fn save(&mut self, frame: &eframe::Frame) -> Result<()> {
+        if self.model_parts.is_some() {
+            self.read_pending_text(frame)?;
+            match self.source_path.clone() {
+                Some(path) if self.package.is_some() => self.save_threemf(&path)?,
+                _ => self.ask_save_as_path(),
+            }
+            return Ok(());
+        }
+
+        let (Some(path), Some(text)) = (&self.source_path, &self.file_to_render) else {
+            return Ok(());
+        };
+        fs::write(path, text)?;
+        log::info!("Saved {}", path.display());
+        self.text_edits.unsaved = false;
+        Ok(())
+    }
+
+    /// Opens the Save As window with a 3MF next to the loaded file.
+    fn ask_save_as_path(&mut self) {
+        // imported meshes are saved as a new 3MF next to the source
+        self.save_as_path = Some(
+            self.source_path
+                .as_ref()
+                .map(|path| path.with_extension("3mf").display().to_string())
+                .unwrap_or_default(),
+        );
+    }
+
+    /// Shows the toolpath of the program in the viewport and estimates the print.
+    fn show_gcode(&mut self, frame: &eframe::Frame, program: GcodeProgram) {
+        let view = ToolpathView::new(&program);
+        self.render = Some(Custom3d::new_with_lines(frame, &view.lines(&program)));
+        self.toolpath_view = Some(view);
+        self.gcode = Some(program);
+        self.estimate_print();
+        self.update_build_plate(frame);
+    }
+
+    fn estimate_print(&mut self) {
+        let Some(program) = &self.gcode else {
+            return;
+        };
+
+        match print_estimate::estimate_print(program, &self.machine_limits, &self.filament_settings)
+        {
+            Ok(estimate) => {
+                log::info!(
+                    "Estimated {:.0} s and {:.1} g of filament",
+                    estimate.total_time(),
+                    estimate.filament_weight
+                );
+                self.print_estimate = Some(estimate);
+            }
+            Err(e) => log::error!("{:?}", e),
+        }
+    }
+
+    fn slice_model(&mut self) -> Result<()> {
+        let Some(model_parts) = &self.model_parts else {
+            return Ok(());
+        };
+
+        let layers = planar_slicer::slice_model(model_parts, &self.slice_settings)?;
+        // comparable with the volume of the analysis to spot layers that went wrong
+        let sliced_volume: f64 = layers
+            .iter()
+            .map(|layer| layer.area() * self.slice_settings.layer_height)
+            .sum();
+        log::info!(
+            "Sliced {} layers of {} with a volume of {:.3}",
+            layers.len(),
+            self.slice_settings.layer_height,
+            sliced_volume
+        );
+        self.layer_preview = Some(LayerPreview::new(&layers));
+        self.layers = Some(layers);
+        self.show_layers = true;
+        Ok(())
+    }
+
+    /// Recomputes everything derived from the loaded model: the measurements, the mesh
+    /// defects and the meshes of the viewport.
+    fn refresh_model_views(&mut self, frame: &eframe::Frame) -> Result<()> {
+        let Some(model_parts) = &self.model_parts else {
+            return Ok(());
+        };
+
+        match analysis::analyse_model(model_parts) {
+            Ok(report) => self.analysis = Some(report),
+            Err(e) => log::warn!("Model could not be measured: {:?}", e),
+        }
+
+        // layers of the previous state of the model are stale
+        self.layers = None;
+        self.layer_preview = None;
+
+        self.validation = Some(validation::validate_model(model_parts));
+        let highlighted = self.highlighted_triangles();
+        let selected = self.selected_triangles();
+
+        match self.render.as_mut() {
+            Some(render) => render.update_meshes(frame, model_parts, &highlighted, &selected)?,
+            None => self.render = Some(Custom3d::new(frame, model_parts, &highlighted, &selected)?),
+        }
+        self.check_build_volume();
+        self.update_build_plate(frame);
+
+        Ok(())
+    }
+
+    /// Triangles drawn in a signal colour, the defects found by the validation when they
+    /// are highlighted.
+    fn highlighted_triangles(&self) -> TriangleSet {
+        match (&self.validation, self.highlight_defects) {
+            (Some(validations), true) => validation::defective_triangles(validations),
+            _ => TriangleSet::new(),
+        }
+    }
+
+    /// Triangles of the selected part of the model, drawn in the selection colour.
+    fn selected_triangles(&self) -> TriangleSet {
+        let (Some(model_parts), Some(selection)) = (&self.model_parts, &self.selection) else {
+            return TriangleSet::new();
+        };
+        selection::selected_triangles(model_parts, selection).unwrap_or_else(|e| {
+            log::warn!("Selection could not be drawn: {:?}", e);
+            TriangleSet::new()
+        })
+    }
+
+    /// Index in the trees of the document shown in the text view, while the text shown is
+    /// the text the trees were read from.
+    fn displayed_document(&self) -> Option<usize> {
+        if !self.text_edits.is_read() {
+            return None;
+        }
+        self.trees
+            .as_ref()?
+            .document_index(self.displayed_document_name()?)
+    }
+
+    /// Name of the XML document shown in the text view. Plain text is not a document.
+    fn displayed_document_name(&self) -> Option<&str> {
+        if self.show_source_text || self.gcode.is_some() || self.trees.is_none() {
+            return None;
+        }
+        match &self.model_parts {
+            Some(model_parts) => Some(&model_parts.root),
+            None => self.rendered_file_name.as_deref(),
+        }
+    }
+
+    /// Selects the node of the trees in the other views than `source`: the tree scrolls to
+    /// it, the text view selects its element and the viewport draws what of the model it
+    /// is in the selection colour. A build item of the root model is also edited in the
+    /// properties panel.
+    fn select_node(
+        &mut self,
+        frame: &eframe::Frame,
+        id: NodeId,
+        source: SelectionSource,
+    ) -> Result<()> {
+        let displayed_document = self.displayed_document();
+        let Some(trees) = &self.trees else {
+            return Ok(());
+        };
+        if source != SelectionSource::Tree {
+            self.tree_view.select(trees, id);
+        }
+        if source != SelectionSource::Text {
+            if let Some(node_source) = &trees.node(id).source {
+                if Some(node_source.document) == displayed_document {
+                    self.text_selection = Some(node_source.element.clone());
+                }
+            }
+        }
+
+        let model_selection = selection::model_selection(trees, id);
+        if let (Some(ModelSelection::Item { part, index }), Some(model_parts)) =
+            (&model_selection, &self.model_parts)
+        {
+            if *part == model_parts.root {
+                self.selected_item = Some(*index);
+            }
+        }
+        self.set_model_selection(frame, model_selection)
+    }
+
+    /// Selects the node of the triangle picked in the viewport, or only the triangle when
+    /// the trees are a summary, and the build item it was drawn for.
+    fn select_picked(&mut self, frame: &eframe::Frame, picked: PickedTriangle) -> Result<()> {
+        self.selected_item = Some(picked.item);
+        let model_selection = ModelSelection::Triangle {
+            part: picked.part,
+            object_id: picked.object_id,
+            index: picked.triangle,
+        };
+        let node = self
+            .trees
+            .as_ref()
+            .and_then(|trees| selection::selection_node(trees, &model_selection));
+        match node {
+            Some(id) => self.select_node(frame, id, SelectionSource::Viewport),
+            None => self.set_model_selection(frame, Some(model_selection)),
+        }
+    }
+
+    /// Draws the new selection in the viewport.
+    fn set_model_selection(
+        &mut self,
+        frame: &eframe::Frame,
+        model_selection: Option<ModelSelection>,
+    ) -> Result<()> {
+        if self.selection == model_selection {
+            return Ok(());
+        }
+        self.selection = model_selection;
+
+        let highlighted = self.highlighted_triangles();
+        let selected = self.selected_triangles();
+        if let (Some(render), Some(model_parts)) = (self.render.as_mut(), &self.model_parts) {
+            render.update_meshes(frame, model_parts, &highlighted, &selected)?;
+        }
+        Ok(())
+    }
+
+    /// Where the gizmo of the selected build item is drawn, around the measured bounds of
+    /// the item.
+    fn gizmo_target(&self) -> Option<GizmoTarget> {
+        let index = self.selected_item?;
+        let item = self
+            .model_parts
+            .as_ref()?
+            .root_part()
+            .model
+            .build
+            .item
+            .get(index)?;
+        let report = self
+            .analysis
+            .as_ref()?
+            .items
+            .iter()
+            .find(|item| item.index == index)?;
+        let (min, max) = report.report.bounding_box?;
+        Some(GizmoTarget {
+            transform: mat4_from_optional_threemf_transform(item.transform.as_ref()),
+            center: (min + max) / 2.0,
+            size: ((max - min).length() * 0.6).max(f64::EPSILON),
+        })
+    }
+
+    /// Shows a changed transform of build item `index` in the viewport. Finished edits are
+    /// written to the text of the root model and update everything derived from the model.
+    fn edit_item_transform(
+        &mut self,
+        frame: &eframe::Frame,
+        index: usize,
+        edit: TransformEdit,
+    ) -> Result<()> {
+        let highlighted = self.highlighted_triangles();
+        let selected = self.selected_triangles();
+        let Some(model_parts) = self.model_parts.as_mut() else {
+            return Ok(());
+        };
+        let root = model_parts.root.clone();
+        let part = model_parts
+            .parts
+            .get_mut(&root)
+            .ok_or_else(|| anyhow!("Root part {} is missing", root))?;
+
+        match edit {
+            TransformEdit::Dragging(transform) => {
+                let item = part
+                    .model
+                    .build
+                    .item
+                    .get_mut(index)
+                    .ok_or_else(|| anyhow!("Build item {} does not exist", index))?;
+                item.transform = Some(threemf_transform_from_mat4(&transform));
+                if let Some(render) = self.render.as_mut() {
+                    render.update_meshes(frame, model_parts, &highlighted, &selected)?;
+                }
+                Ok(())
+            }
+            TransformEdit::Finished(transform) => {
+                xml_edit::set_build_item_transform(part, index, &transform)?;
+                self.model_text_changed(frame)
+            }
+        }
+    }
+
+    /// Checks the build items against the selected printer, logs items that newly leave
+    /// its build volume and flags them in the tree.
+    fn check_build_volume(&mut self) {
+        let Some(model_parts) = &self.model_parts else {
+            return;
+        };
+        let placements =
+            match build_volume::check_build_volume(model_parts, self.printer_profiles.selected()) {
+                Ok(placements) => placements,
+                Err(e) => {
+                    log::warn!("Build volume could not be checked: {:?}", e);
+                    return;
+                }
+            };
+
+        for placement in placements.iter().filter(|placement| !placement.fits()) {
+            let known = self.build_volume.as_ref().is_some_and(|previous| {
+                previous
+                    .get(placement.index)
+                    .is_some_and(|previous| previous.problems == placement.problems)
             });
+            if !known {
+                log::warn!(
+                    "Build item {} (object {}) does not fit {}: {}",
+                    placement.index,
+                    placement.object_id,
+                    self.printer_profiles.selected().name,
+                    placement.problems.join(", ")
+                );
+            }
+        }
+        if let Some(trees) = self.trees.as_mut() {
+            flag_build_items(trees, &placements);
+        }
+        self.build_volume = Some(placements);
+    }
 
-        Self { angle: 0.0 }
+    /// Draws the bed of the selected printer in the unit of the model, or in millimetres
+    /// under a toolpath.
+    fn update_build_plate(&mut self, frame: &eframe::Frame) {
+        let millimeters_per_unit = match &self.model_parts {
+            Some(model_parts) => analysis::unit_info(&model_parts.root_part().model.unit).1,
+            None => 1.0,
+        };
+        if let Some(render) = self.render.as_mut() {
+            render.update_plate(
+                frame,
+                &build_plate_lines(self.printer_profiles.selected(), millimeters_per_unit),
+            );
+        }
+    }
+
+    /// Writes the loaded package with the current model parts to `path`. Models that were
+    /// imported from other formats get a new package on their first save.
+    fn save_threemf(&mut self, path: &Path) -> Result<()> {
+        let model_parts = self
+            .model_parts
+            .as_ref()
+            .ok_or_else(|| anyhow!("No model is loaded"))?;
+        let package = self
+            .package
+            .get_or_insert_with(|| threemf_writer::new_package_from_model_parts(model_parts));
+        threemf_writer::update_package_model_parts(package, model_parts);
+
+        // write next to the target first so a failed save does not destroy the original
+        let temporary_path = path.with_extension("3mf.tmp");
+        threemf_writer::write_threemf_package(package, fs::File::create(&temporary_path)?)?;
+        fs::rename(&temporary_path, path)?;
+        self.remember_recent_file(path);
+        self.text_edits.unsaved = false;
+
+        log::info!("Saved {}", path.display());
+        Ok(())
+    }
+
+    fn save_as_window(&mut self, ctx: &egui::Context) {
+        let Some(path) = path_window(ctx, "Save As", &mut self.save_as_path) else {
+            return;
+        };
+
+        match self.save_threemf(&path) {
+            Ok(()) => {
+                self.rendered_file_name =
+                    path.file_name().and_then(OsStr::to_str).map(str::to_string);
+                self.source_path = Some(path);
+            }
+            Err(e) => log::error!("{:?}", e),
+        }
+    }
+
+    /// Writes the sliced layers as G-code, scaled to millimetres for models in other units.
+    fn export_gcode(&self, path: &Path) -> Result<()> {
+        let (Some(model_parts), Some(layers)) = (&self.model_parts, &self.layers) else {
+            return Err(anyhow!("The model is not sliced"));
+        };
+
+        let (_, millimeters_per_unit) = analysis::unit_info(&model_parts.root_part().model.unit);
+        let layers: Vec<Layer> = layers
+            .iter()
+            .map(|layer| layer.scaled(millimeters_per_unit))
+            .collect();
+        let gcode = gcode_writer::generate_gcode(
+            &layers,
+            self.slice_settings.layer_height * millimeters_per_unit,
+            &self.gcode_settings,
+        )?;
+        fs::write(path, gcode)?;
+
+        log::info!("Exported {}", path.display());
+        Ok(())
+    }
+
+    fn export_gcode_window(&mut self, ctx: &egui::Context) {
+        if let Some(path) = path_window(ctx, "Export G-code", &mut self.export_gcode_path) {
+            if let Err(e) = self.export_gcode(&path) {
+                log::error!("{:?}", e);
+            }
+        }
     }
 }
 
-impl Custom3d {
-    fn custom_painting(&self, ui: &mut egui::Ui, angle: f32) {
-        let (rect, response) =
-            ui.allocate_exact_size(egui::Vec2::splat(300.0), egui::Sense::drag());
+/// Selects the byte range of the text of the editor and scrolls to it. The selection shows
+/// from the next frame on.
+fn select_text(ui: &egui::Ui, output: &TextEditOutput, text: &str, range: Range<usize>) {
+    let (Some(before), Some(selected)) = (text.get(..range.start), text.get(range.clone())) else {
+        return;
+    };
+    let start = CCursor::new(before.chars().count());
+    let end = CCursor::new(start.index + selected.chars().count());
 
-        let new_angle = angle + response.drag_delta().x * 0.01;
+    let mut state = output.state.clone();
+    state
+        .cursor
+        .set_char_range(Some(CCursorRange::two(start, end)));
+    state.store(ui.ctx(), output.response.id);
+    let rect = output
+        .galley
+        .pos_from_ccursor(start)
+        .translate(output.galley_pos.to_vec2());
+    ui.scroll_to_rect(rect, Some(egui::Align::Center));
+}
 
-        let cb = egui_wgpu::Callback::new_paint_callback(
-            rect,
-            CustomTriangleCallback { angle: new_angle },
-        );
-        ui.painter().add(cb);
+/// Asks for a file path while `path` is set. Returns the path once it is confirmed,
+/// cancelling or closing the window clears it.
+fn path_window(ctx: &egui::Context, title: &str, path: &mut Option<String>) -> Option<PathBuf> {
+    let mut text = path.take()?;
+
+    let mut open = true;
+    let mut save = false;
+    let mut cancel = false;
+    egui::Window::new(title)
+        .collapsible(false)
+        .resizable(false)
+        .open(&mut open)
+        .show(ctx, |ui| {
+            ui.label("File path");
+            ui.text_edit_singleline(&mut text);
+            ui.horizontal(|ui| {
+                save = ui.button("Save").clicked();
+                cancel = ui.button("Cancel").clicked();
+            });
+        });
+
+    if save {
+        return Some(PathBuf::from(text));
     }
+    if open && !cancel {
+        *path = Some(text);
+    }
+    None
 }
 
-// Callbacks in egui_wgpu have 3 stages:
-// * prepare (per callback impl)
-// * finish_prepare (once)
-// * paint (per callback impl)
-//
-// The prepare callback is called every frame before paint and is given access to the wgpu
-// Device and Queue, which can be used, for instance, to update buffers and uniforms before
-// rendering.
-// If [`egui_wgpu::Renderer`] has [`egui_wgpu::FinishPrepareCallback`] registered,
-// it will be called after all `prepare` callbacks have been called.
-// You can use this to update any shared resources that need to be updated once per frame
-// after all callbacks have been processed.
-//
-// On both prepare methods you can use the main `CommandEncoder` that is passed-in,
-// return an arbitrary number of user-defined `CommandBuffer`s, or both.
-// The main command buffer, as well as all user-defined ones, will be submitted together
-// to the GPU in a single call.
-//
-// The paint callback is called after finish prepare and is given access to egui's main render pass,
-// which can be used to issue draw commands.
-struct CustomTriangleCallback {
-    angle: f32,
+/// Summarises an imported G-code program by layer instead of listing every move.
+fn gcode_summary_tree(program: &GcodeProgram) -> tree::Tree {
+    let layers = program
+        .layers
+        .iter()
+        .enumerate()
+        .map(|(index, layer)| {
+            let moves = &program.moves[layer.moves.clone()];
+            let extrusions = moves.iter().filter(|gcode_move| gcode_move.is_extrusion());
+            let path: f64 = extrusions.clone().map(GcodeMove::length).sum();
+            let extrusion: f64 = extrusions.map(|gcode_move| gcode_move.extrusion).sum();
+            tree::Tree {
+                name: "layer".to_string(),
+                content: None,
+                attributes: Some(vec![
+                    ("index".to_string(), index.to_string()),
+                    ("z".to_string(), format!("{:.3}", layer.z)),
+                    ("moves".to_string(), moves.len().to_string()),
+                    ("path".to_string(), format!("{:.3}", path)),
+                    ("extrusion".to_string(), format!("{:.3}", extrusion)),
+                ]),
+                childs: None,
+                warning: None,
+            }
+        })
+        .collect();
+
+    tree::Tree {
+        name: "gcode".to_string(),
+        content: None,
+        attributes: Some(vec![
+            ("lines".to_string(), program.line_count.to_string()),
+            ("moves".to_string(), program.moves.len().to_string()),
+            ("layers".to_string(), program.layers.len().to_string()),
+        ]),
+        childs: Some(layers),
+        warning: None,
+    }
 }
 
-impl egui_wgpu::CallbackTrait for CustomTriangleCallback {
-    fn prepare(
-        &self,
-        device: &wgpu::Device,
-        queue: &wgpu::Queue,
-        _screen_descriptor: &egui_wgpu::ScreenDescriptor,
-        _egui_encoder: &mut wgpu::CommandEncoder,
-        resources: &mut egui_wgpu::CallbackResources,
-    ) -> Vec<wgpu::CommandBuffer> {
-        let resources: &TriangleRenderResources = resources.get().unwrap();
-        resources.prepare(device, queue, self.angle);
-        Vec::new()
-    }
-
-    fn paint<'a>(
-        &self,
-        _info: egui::PaintCallbackInfo,
-        render_pass: &mut wgpu::RenderPass<'a>,
-        resources: &'a egui_wgpu::CallbackResources,
-    ) {
-        let resources: &TriangleRenderResources = resources.get().unwrap();
-        resources.paint(render_pass);
+/// Summarises an imported STL file instead of listing every vertex of the generated model.
+fn stl_summary_tree(info: &StlInfo) -> tree::Tree {
+    let format = match info.format {
+        StlFormat::Binary => "binary",
+        StlFormat::Ascii => "ascii",
+    };
+    let solids = info
+        .solids
+        .iter()
+        .enumerate()
+        .map(|(index, solid)| tree::Tree {
+            name: "solid".to_string(),
+            content: None,
+            attributes: Some(vec![
+                ("objectid".to_string(), (index + 1).to_string()),
+                ("name".to_string(), solid.name.clone()),
+                ("facets".to_string(), solid.facet_count.to_string()),
+                ("vertices".to_string(), solid.vertex_count.to_string()),
+            ]),
+            childs: None,
+            warning: None,
+        })
+        .collect();
+
+    tree::Tree {
+        name: "stl".to_string(),
+        content: None,
+        attributes: Some(vec![
+            ("format".to_string(), format.to_string()),
+            ("unit".to_string(), "millimeter".to_string()),
+        ]),
+        childs: Some(solids),
+        warning: None,
+    }
+}
+
+/// Lists the groups of an imported OBJ file with the object each became.
+fn obj_summary_tree(info: &ObjInfo) -> tree::Tree {
+    let groups = info
+        .bodies
+        .iter()
+        .map(|body| {
+            let mut attributes = vec![
+                ("objectid".to_string(), body.object_id.to_string()),
+                ("faces".to_string(), body.face_count.to_string()),
+                ("triangles".to_string(), body.triangle_count.to_string()),
+                ("vertices".to_string(), body.vertex_count.to_string()),
+            ];
+            if let Some(material) = &body.material {
+                attributes.push(("material".to_string(), material.clone()));
+            }
+            if let Some([r, g, b]) = body.color {
+                attributes.push(("color".to_string(), format!("{} {} {}", r, g, b)));
+            }
+
+            tree::Tree {
+                name: "group".to_string(),
+                content: Some(body.name.clone()),
+                attributes: Some(attributes),
+                childs: None,
+                warning: None,
+            }
+        })
+        .collect();
+
+    let mut attributes = vec![
+        ("vertices".to_string(), info.vertex_count.to_string()),
+        ("normals".to_string(), info.normal_count.to_string()),
+        (
+            "texture coordinates".to_string(),
+            info.texture_coordinate_count.to_string(),
+        ),
+    ];
+    for library in &info.material_libraries {
+        attributes.push(("mtllib".to_string(), library.clone()));
+    }
+
+    tree::Tree {
+        name: "obj".to_string(),
+        content: None,
+        attributes: Some(attributes),
+        childs: Some(groups),
+        warning: None,
     }
 }
 
-struct TriangleRenderResources {
-    pipeline: wgpu::RenderPipeline,
-    bind_group: wgpu::BindGroup,
-    uniform_buffer: wgpu::Buffer,
+/// Flags the `item` elements of the build of the root model that do not fit the printer,
+/// with the reason as the warning of the node.
+fn flag_build_items(trees: &mut TreeStore, placements: &[ItemPlacement]) {
+    // the root model is the first tree, or the first tree of the root part
+    let model = trees.find_child(None, "model").or_else(|| {
+        let part = trees.roots.first().copied()?;
+        trees.find_child(Some(part), "model")
+    });
+    let Some(build) = model.and_then(|model| trees.find_child(Some(model), "build")) else {
+        return;
+    };
+
+    let items: Vec<tree::NodeId> = trees
+        .node(build)
+        .children
+        .iter()
+        .copied()
+        .filter(|&id| &*trees.node(id).name == "item")
+        .collect();
+    for (item, placement) in items.into_iter().zip(placements) {
+        trees.set_warning(
+            item,
+            (!placement.fits()).then(|| {
+                format!(
+                    "Outside of the build volume: {}",
+                    placement.problems.join(", ")
+                )
+            }),
+        );
+    }
 }
 
-impl TriangleRenderResources {
-    fn prepare(&self, _device: &wgpu::Device, queue: &wgpu::Queue, angle: f32) {
-        // Update our uniform buffer with the angle from the UI
-        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&[angle]));
+/// Builds the trees of every model part. When the package has more than one model part
+/// the trees are grouped under a node named after the part they came from.
+fn trees_from_model_parts(model_parts: &ModelParts) -> Result<TreeStore> {
+    if model_parts.parts.len() == 1 {
+        let root = model_parts.root_part();
+        return TreeStore::from_xml(&root.name, root.xml.clone());
     }
 
-    fn paint<'rpass>(&'rpass self, rpass: &mut wgpu::RenderPass<'rpass>) {
-        // Draw our triangle!
-        rpass.set_pipeline(&self.pipeline);
-        rpass.set_bind_group(0, &self.bind_group, &[]);
-        rpass.draw(0..3, 0..1);
+    let mut trees = TreeStore::default();
+    // the root part first, then the parts it references
+    let parts = std::iter::once(model_parts.root_part()).chain(
+        model_parts
+            .parts
+            .values()
+            .filter(|part| part.name != model_parts.root),
+    );
+    for part in parts {
+        let node = trees.append_tree(
+            None,
+            tree::Tree {
+                name: part.name.clone(),
+                content: None,
+                attributes: None,
+                childs: None,
+                warning: None,
+            },
+        );
+        trees.append_xml(Some(node), &part.name, part.xml.clone())?;
     }
+
+    Ok(trees)
 }
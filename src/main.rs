@@ -7,6 +7,8 @@ fn main() -> eframe::Result {
     let options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default().with_inner_size([800.0, 450.0]),
         centered: true,
+        // the 3D viewport draws into egui's render pass and needs a depth buffer
+        depth_buffer: 24,
         ..Default::default()
     };
     eframe::run_native(
@@ -0,0 +1,483 @@
+use anyhow::{anyhow, Result};
+use std::{collections::HashMap, io};
+use threemf::model::{
+    Build, Item, Mesh, Model, Object, ObjectData, Resources, Triangle, Triangles, Unit, Vertex,
+    Vertices,
+};
+
+use crate::threemf::{materials::BaseMaterial, threemf_reader::ModelParts, xml_edit};
+
+const CORE_NAMESPACE: &str = "http://schemas.microsoft.com/3dmanufacturing/core/2015/02";
+/// Name of the body collecting faces that appear before any `o` or `g` statement.
+const DEFAULT_BODY_NAME: &str = "default";
+
+/// Summary of an imported OBJ file, shown in place of the XML tree.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ObjInfo {
+    pub vertex_count: usize,
+    pub normal_count: usize,
+    pub texture_coordinate_count: usize,
+    pub material_libraries: Vec<String>,
+    pub bodies: Vec<ObjBodyInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjBodyInfo {
+    /// Id of the object the body was imported as.
+    pub object_id: usize,
+    pub name: String,
+    pub material: Option<String>,
+    /// Diffuse colour of the material, `Kd` in the material library.
+    pub color: Option<[f32; 3]>,
+    pub face_count: usize,
+    pub triangle_count: usize,
+    pub vertex_count: usize,
+}
+
+/// Reads a Wavefront OBJ file into a 3MF model with one object and build item per
+/// group or object. Polygons are triangulated as fans, which is correct for the
+/// convex faces exporters write.
+///
+/// `read_material_library` is called with the name of every `mtllib`, libraries that
+/// cannot be read are logged and their colours ignored.
+pub fn get_model_from_obj_string<F>(
+    obj_content: &str,
+    mut read_material_library: F,
+) -> Result<(Model, ObjInfo)>
+where
+    F: FnMut(&str) -> io::Result<String>,
+{
+    let document = parse_obj(obj_content)?;
+
+    let mut colors = HashMap::new();
+    for library in &document.material_libraries {
+        match read_material_library(library) {
+            Ok(mtl_content) => colors.extend(parse_material_colors(&mtl_content)),
+            Err(e) => log::warn!("Material library {} could not be read: {}", library, e),
+        }
+    }
+
+    let mut info = ObjInfo {
+        vertex_count: document.positions.len(),
+        normal_count: document.normal_count,
+        texture_coordinate_count: document.texture_coordinate_count,
+        material_libraries: document.material_libraries.clone(),
+        bodies: Vec::new(),
+    };
+    let mut objects = Vec::new();
+    let mut items = Vec::new();
+
+    for body in document.bodies.iter().filter(|body| !body.faces.is_empty()) {
+        let id = objects.len() + 1;
+        let mesh = body_mesh(&document.positions, &body.faces);
+
+        info.bodies.push(ObjBodyInfo {
+            object_id: id,
+            name: body.name.clone(),
+            material: body.material.clone(),
+            color: body
+                .material
+                .as_ref()
+                .and_then(|material| colors.get(material).copied()),
+            face_count: body.faces.len(),
+            triangle_count: mesh.triangles.triangle.len(),
+            vertex_count: mesh.vertices.vertex.len(),
+        });
+        objects.push(Object {
+            id,
+            partnumber: None,
+            name: Some(body.name.clone()),
+            pid: None,
+            object: ObjectData::Mesh(mesh),
+        });
+        items.push(Item {
+            objectid: id,
+            transform: None,
+            partnumber: None,
+        });
+    }
+
+    let model = Model {
+        xmlns: CORE_NAMESPACE.to_string(),
+        metadata: Vec::new(),
+        resources: Resources {
+            object: objects,
+            basematerials: None,
+        },
+        build: Build { item: items },
+        unit: Unit::Millimeter,
+    };
+
+    Ok((model, info))
+}
+
+/// Wraps an imported model as the single root part `part_name`, with the colours of the
+/// bodies written into the part as their materials.
+pub fn get_model_parts_from_obj(
+    part_name: &str,
+    model: Model,
+    info: &ObjInfo,
+) -> Result<ModelParts> {
+    let mut model_parts = ModelParts::from_model(part_name, model)?;
+    let materials: Vec<_> = info
+        .bodies
+        .iter()
+        .filter_map(|body| {
+            let [r, g, b] = body.color?;
+            let material = BaseMaterial {
+                name: body.material.clone().unwrap_or_default(),
+                display_color: [r, g, b, 1.0],
+            };
+            Some((body.object_id, material))
+        })
+        .collect();
+    if let Some(root) = model_parts.parts.get_mut(part_name) {
+        xml_edit::add_object_materials(root, &materials)?;
+    }
+    Ok(model_parts)
+}
+
+struct ObjDocument {
+    positions: Vec<[f64; 3]>,
+    normal_count: usize,
+    texture_coordinate_count: usize,
+    material_libraries: Vec<String>,
+    bodies: Vec<Body>,
+}
+
+struct Body {
+    name: String,
+    /// First material used by the body.
+    material: Option<String>,
+    /// Zero based position indices of every polygon.
+    faces: Vec<Vec<usize>>,
+}
+
+fn parse_obj(obj_content: &str) -> Result<ObjDocument> {
+    let mut document = ObjDocument {
+        positions: Vec::new(),
+        normal_count: 0,
+        texture_coordinate_count: 0,
+        material_libraries: Vec::new(),
+        bodies: vec![Body {
+            name: DEFAULT_BODY_NAME.to_string(),
+            material: None,
+            faces: Vec::new(),
+        }],
+    };
+    let mut current_body = 0;
+    let mut current_material: Option<String> = None;
+
+    for (line_index, line) in obj_content.lines().enumerate() {
+        let line_number = line_index + 1;
+        let line = line.split('#').next().unwrap_or_default();
+        let mut tokens = line.split_whitespace();
+
+        match tokens.next() {
+            Some("v") => {
+                let mut position = [0.0; 3];
+                for value in position.iter_mut() {
+                    *value = tokens
+                        .next()
+                        .and_then(|token| token.parse::<f64>().ok())
+                        .ok_or_else(|| anyhow!("Line {}: invalid vertex", line_number))?;
+                }
+                document.positions.push(position);
+            }
+            Some("vn") => document.normal_count += 1,
+            Some("vt") => document.texture_coordinate_count += 1,
+            Some("f") => {
+                let mut face = Vec::new();
+                for token in tokens {
+                    face.push(
+                        resolve_face_vertex(&document, token)
+                            .map_err(|e| anyhow!("Line {}: {}", line_number, e))?,
+                    );
+                }
+                if face.len() < 3 {
+                    return Err(anyhow!(
+                        "Line {}: face has {} vertices, at least 3 are needed",
+                        line_number,
+                        face.len()
+                    ));
+                }
+
+                let body = &mut document.bodies[current_body];
+                if body.material.is_none() {
+                    body.material.clone_from(&current_material);
+                }
+                body.faces.push(face);
+            }
+            Some("o") | Some("g") => {
+                let name = tokens.collect::<Vec<_>>().join(" ");
+                let name = if name.is_empty() {
+                    DEFAULT_BODY_NAME.to_string()
+                } else {
+                    name
+                };
+                // faces of a group that is continued later are added to the same body
+                current_body = match document.bodies.iter().position(|body| body.name == name) {
+                    Some(index) => index,
+                    None => {
+                        document.bodies.push(Body {
+                            name,
+                            material: None,
+                            faces: Vec::new(),
+                        });
+                        document.bodies.len() - 1
+                    }
+                };
+            }
+            Some("usemtl") => current_material = tokens.next().map(str::to_string),
+            Some("mtllib") => document
+                .material_libraries
+                .extend(tokens.map(str::to_string)),
+            // smoothing groups, free form geometry and other statements do not
+            // change the mesh
+            _ => {}
+        }
+    }
+
+    Ok(document)
+}
+
+/// Resolves the position index of a `v`, `v/vt`, `v//vn` or `v/vt/vn` face vertex.
+/// Negative indices count back from the last vertex read so far.
+fn resolve_face_vertex(document: &ObjDocument, token: &str) -> Result<usize> {
+    let index = token
+        .split('/')
+        .next()
+        .and_then(|index| index.parse::<i64>().ok())
+        .ok_or_else(|| anyhow!("invalid face vertex {}", token))?;
+    let count = document.positions.len() as i64;
+
+    let resolved = match index {
+        index if index > 0 => index - 1,
+        index if index < 0 => count + index,
+        _ => return Err(anyhow!("face vertex index 0 is not valid")),
+    };
+    if resolved < 0 || resolved >= count {
+        return Err(anyhow!(
+            "face vertex {} references a vertex that does not exist",
+            token
+        ));
+    }
+
+    Ok(resolved as usize)
+}
+
+/// Reads the diffuse colour of every material in a material library.
+fn parse_material_colors(mtl_content: &str) -> HashMap<String, [f32; 3]> {
+    let mut colors = HashMap::new();
+    let mut current_material: Option<String> = None;
+
+    for line in mtl_content.lines() {
+        let mut tokens = line.split_whitespace();
+        match tokens.next() {
+            Some("newmtl") => current_material = tokens.next().map(str::to_string),
+            Some("Kd") => {
+                let values: Vec<f32> = tokens.filter_map(|token| token.parse().ok()).collect();
+                if let (Some(material), [r, g, b, ..]) = (&current_material, values.as_slice()) {
+                    colors.insert(material.clone(), [*r, *g, *b]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    colors
+}
+
+/// Builds the mesh of a body with only the vertices its faces use.
+fn body_mesh(positions: &[[f64; 3]], faces: &[Vec<usize>]) -> Mesh {
+    let mut indices: HashMap<usize, usize> = HashMap::new();
+    let mut vertices = Vec::new();
+    let mut triangles = Vec::new();
+
+    for face in faces {
+        let face: Vec<usize> = face
+            .iter()
+            .map(|&position| {
+                *indices.entry(position).or_insert_with(|| {
+                    let [x, y, z] = positions[position];
+                    vertices.push(Vertex { x, y, z });
+                    vertices.len() - 1
+                })
+            })
+            .collect();
+
+        for corner in 1..face.len() - 1 {
+            triangles.push(Triangle {
+                v1: face[0],
+                v2: face[corner],
+                v3: face[corner + 1],
+            });
+        }
+    }
+
+    Mesh {
+        vertices: Vertices { vertex: vertices },
+        triangles: Triangles {
+            triangle: triangles,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_support::{root_element_name, write_and_read_package},
+        threemf::{threemf_reader::get_model_parts_from_package, threemf_writer},
+    };
+    const CUBE_WITH_GROUPS: &str = "# two groups of a cube
+mtllib cube.mtl
+v 0 0 0
+v 1 0 0
+v 1 1 0
+v 0 1 0
+v 0 0 1
+v 1 0 1
+v 1 1 1
+v 0 1 1
+vt 0 0
+vn 0 0 -1
+g bottom
+usemtl red
+f 1/1/1 4/1/1 3/1/1 2/1/1
+g sides
+usemtl blue
+f 1//1 2//1 6//1 5//1
+f 2 3 7 6
+f 3 4 8 7
+f -8 -4 -1 -5
+g bottom
+f 5 6 7 8
+";
+
+    const CUBE_MATERIALS: &str = "newmtl red
+Kd 1.0 0.0 0.0
+newmtl blue
+Ka 0.1 0.1 0.1
+Kd 0.0 0.0 1.0
+";
+
+    fn read_cube_materials(name: &str) -> io::Result<String> {
+        if name == "cube.mtl" {
+            Ok(CUBE_MATERIALS.to_string())
+        } else {
+            Err(io::Error::from(io::ErrorKind::NotFound))
+        }
+    }
+
+    #[test]
+    fn test_groups_become_objects() {
+        let (model, info) =
+            get_model_from_obj_string(CUBE_WITH_GROUPS, read_cube_materials).unwrap();
+
+        assert!(
+            model.resources.object.len() == 2 && model.build.item.len() == 2,
+            "Every group should become an object with a build item"
+        );
+        assert!(
+            info.bodies[0].name == "bottom" && info.bodies[0].face_count == 2,
+            "Faces of a continued group are not added to the same body"
+        );
+        assert!(
+            info.vertex_count == 8 && info.normal_count == 1 && info.texture_coordinate_count == 1,
+            "Vertex counts are wrong"
+        );
+    }
+
+    #[test]
+    fn test_polygons_are_triangulated() {
+        let (model, info) =
+            get_model_from_obj_string(CUBE_WITH_GROUPS, read_cube_materials).unwrap();
+
+        if let ObjectData::Mesh(mesh) = &model.resources.object[1].object {
+            assert!(
+                mesh.triangles.triangle.len() == 8,
+                "Quads are not split into two triangles"
+            );
+            assert!(
+                mesh.vertices.vertex.len() == 8,
+                "Body vertices are not shared between faces"
+            );
+        } else {
+            panic!("Not a mesh data");
+        }
+        assert!(
+            info.bodies[1].triangle_count == 8,
+            "Triangle count of the body is wrong"
+        );
+    }
+
+    #[test]
+    fn test_material_colors_are_read() {
+        let (_, info) = get_model_from_obj_string(CUBE_WITH_GROUPS, read_cube_materials).unwrap();
+
+        assert!(
+            info.bodies[0].color == Some([1.0, 0.0, 0.0]),
+            "Diffuse colour of the first material is wrong"
+        );
+        assert!(
+            info.bodies[1].material.as_deref() == Some("blue")
+                && info.bodies[1].color == Some([0.0, 0.0, 1.0]),
+            "Diffuse colour of the second material is wrong"
+        );
+    }
+
+    #[test]
+    fn test_missing_material_library_is_ignored() {
+        let (model, info) = get_model_from_obj_string(CUBE_WITH_GROUPS, |_| {
+            Err(io::Error::from(io::ErrorKind::NotFound))
+        })
+        .unwrap();
+
+        assert!(
+            model.resources.object.len() == 2,
+            "Geometry is not loaded without materials"
+        );
+        assert!(
+            info.bodies.iter().all(|body| body.color.is_none()),
+            "Colours are set without a material library"
+        );
+    }
+
+    #[test]
+    fn test_imported_colors_are_saved_as_materials() {
+        let (model, info) =
+            get_model_from_obj_string(CUBE_WITH_GROUPS, read_cube_materials).unwrap();
+        let model_parts = get_model_parts_from_obj("/3D/3dmodel.model", model, &info).unwrap();
+        let package = threemf_writer::new_package_from_model_parts(&model_parts);
+
+        let saved = get_model_parts_from_package(&write_and_read_package(&package)).unwrap();
+        let root = saved.root_part();
+
+        let root_name = root_element_name(&root.xml);
+        assert!(
+            root_name == "model",
+            "Root element of the saved OBJ is {}",
+            root_name
+        );
+        assert!(
+            root.model.resources.object.len() == 2,
+            "Objects are lost when saving"
+        );
+        assert!(
+            root.materials.object_color(1) == Some([1.0, 0.0, 0.0])
+                && root.materials.object_color(2) == Some([0.0, 0.0, 1.0]),
+            "Colours are not saved: {:?}",
+            root.materials
+        );
+    }
+
+    #[test]
+    fn test_error_returned_when_face_vertex_is_out_of_range() {
+        let result = get_model_from_obj_string("v 0 0 0\nv 1 0 0\nf 1 2 3\n", read_cube_materials);
+        assert!(
+            result.is_err(),
+            "Face referencing a missing vertex was accepted"
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use glam::{DVec2, DVec3};
+use std::f64::consts::TAU;
+
+use super::ColoredLine;
+use crate::config::printer_profiles::{BedShape, PrinterProfile};
+
+/// Millimetres between the lines of the grid on the bed.
+const GRID_SPACING: f64 = 10.0;
+const CIRCLE_SEGMENTS: usize = 96;
+const OUTLINE_COLOR: [f32; 3] = [0.75, 0.75, 0.75];
+const GRID_COLOR: [f32; 3] = [0.35, 0.35, 0.35];
+const VOLUME_COLOR: [f32; 3] = [0.35, 0.4, 0.6];
+
+/// Outline and grid of the bed and the edges of the build volume, in model units.
+pub fn build_plate_lines(profile: &PrinterProfile, millimeters_per_unit: f64) -> Vec<ColoredLine> {
+    let mut lines = Vec::new();
+    let mut line = |from: DVec3, to: DVec3, color| {
+        lines.push(ColoredLine {
+            from: from / millimeters_per_unit,
+            to: to / millimeters_per_unit,
+            color,
+        })
+    };
+    let height = profile.max_height;
+    let center = profile.bed_center();
+    let half = profile.bed_size() / 2.0;
+
+    let outline: Vec<DVec2> = match profile.shape {
+        BedShape::Rectangular => vec![
+            center + DVec2::new(-half.x, -half.y),
+            center + DVec2::new(half.x, -half.y),
+            center + DVec2::new(half.x, half.y),
+            center + DVec2::new(-half.x, half.y),
+        ],
+        BedShape::Circular => (0..CIRCLE_SEGMENTS)
+            .map(|index| {
+                center + DVec2::from_angle(index as f64 / CIRCLE_SEGMENTS as f64 * TAU) * half.x
+            })
+            .collect(),
+    };
+    for (index, &point) in outline.iter().enumerate() {
+        let next = outline[(index + 1) % outline.len()];
+        line(point.extend(0.0), next.extend(0.0), OUTLINE_COLOR);
+        line(point.extend(height), next.extend(height), VOLUME_COLOR);
+    }
+    // vertical edges at the corners, or at the quarters of a round volume
+    let step = outline.len() / 4;
+    for point in outline.iter().step_by(step.max(1)) {
+        line(point.extend(0.0), point.extend(height), VOLUME_COLOR);
+    }
+
+    // grid lines through the origin of the printer, clipped to the bed
+    let (min, max) = (center - half, center + half);
+    for axis in 0..2 {
+        let mut offset = (min[axis] / GRID_SPACING).floor() * GRID_SPACING;
+        while offset <= max[axis] {
+            let (start, end) = match profile.shape {
+                BedShape::Rectangular => (min[1 - axis], max[1 - axis]),
+                BedShape::Circular => {
+                    let distance = offset - center[axis];
+                    let chord = (half.x * half.x - distance * distance).max(0.0).sqrt();
+                    (center[1 - axis] - chord, center[1 - axis] + chord)
+                }
+            };
+            if offset > min[axis] && offset < max[axis] && end > start {
+                let point = |along: f64| {
+                    let mut point = DVec2::ZERO;
+                    point[axis] = offset;
+                    point[1 - axis] = along;
+                    point.extend(0.0)
+                };
+                line(point(start), point(end), GRID_COLOR);
+            }
+            offset += GRID_SPACING;
+        }
+    }
+
+    lines
+}
@@ -0,0 +1,170 @@
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use glam::{Mat4, Vec3};
+
+/// Keeps the orbit away from the poles where the Z up vector becomes degenerate.
+const MAX_PITCH: f32 = FRAC_PI_2 - 0.001;
+const ORBIT_SPEED: f32 = 0.01;
+const ZOOM_SPEED: f32 = 0.002;
+
+/// Fixed view directions offered in the viewport toolbar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewPreset {
+    Front,
+    Top,
+    Iso,
+}
+
+/// An orbit camera looking at `target` with Z up, as used by 3MF build plates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Camera {
+    pub target: Vec3,
+    pub distance: f32,
+    /// Rotation around the Z axis in radians, 0 looks along -X.
+    pub yaw: f32,
+    /// Elevation above the XY plane in radians.
+    pub pitch: f32,
+    /// Vertical field of view in radians.
+    pub fov_y: f32,
+    scene_center: Vec3,
+    scene_radius: f32,
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+        let mut camera = Self {
+            target: Vec3::ZERO,
+            distance: 1.0,
+            yaw: 0.0,
+            pitch: 0.0,
+            fov_y: 45.0_f32.to_radians(),
+            scene_center: Vec3::ZERO,
+            scene_radius: 1.0,
+        };
+        camera.set_view(ViewPreset::Iso);
+        camera
+    }
+}
+
+impl Camera {
+    /// Creates a camera in the isometric view that fits the given bounds.
+    pub fn new_fitted(min: Vec3, max: Vec3) -> Self {
+        let mut camera = Self::default();
+        camera.set_scene_bounds(min, max);
+        camera.fit_to_scene();
+        camera
+    }
+
+    /// Remembers the bounds of the scene for [`Camera::fit_to_scene`] and the clip planes.
+    pub fn set_scene_bounds(&mut self, min: Vec3, max: Vec3) {
+        self.scene_center = (min + max) * 0.5;
+        self.scene_radius = ((max - min).length() * 0.5).max(f32::EPSILON);
+    }
+
+    /// Centres the scene and moves back until its bounding sphere fills the view.
+    pub fn fit_to_scene(&mut self) {
+        self.target = self.scene_center;
+        self.distance = self.scene_radius / (self.fov_y * 0.5).sin() * 1.1;
+    }
+
+    pub fn set_view(&mut self, preset: ViewPreset) {
+        let (yaw, pitch) = match preset {
+            ViewPreset::Front => (-FRAC_PI_2, 0.0),
+            ViewPreset::Top => (-FRAC_PI_2, MAX_PITCH),
+            ViewPreset::Iso => (-FRAC_PI_4, (1.0 / 2.0_f32.sqrt()).atan()),
+        };
+        self.yaw = yaw;
+        self.pitch = pitch;
+    }
+
+    /// Rotates around the target by a mouse drag in points.
+    pub fn orbit(&mut self, delta: egui::Vec2) {
+        self.yaw -= delta.x * ORBIT_SPEED;
+        self.pitch = (self.pitch + delta.y * ORBIT_SPEED).clamp(-MAX_PITCH, MAX_PITCH);
+    }
+
+    /// Moves the target so the scene follows the mouse in a viewport `viewport_height` points high.
+    pub fn pan(&mut self, delta: egui::Vec2, viewport_height: f32) {
+        let world_per_point =
+            2.0 * self.distance * (self.fov_y * 0.5).tan() / viewport_height.max(1.0);
+        let forward = (self.target - self.eye()).normalize();
+        let right = forward.cross(Vec3::Z).normalize();
+        let up = right.cross(forward);
+
+        self.target += (-right * delta.x + up * delta.y) * world_per_point;
+    }
+
+    /// Zooms towards the target, positive `scroll` moves closer.
+    pub fn zoom(&mut self, scroll: f32) {
+        self.distance = (self.distance * (-scroll * ZOOM_SPEED).exp())
+            .clamp(self.scene_radius * 0.01, self.scene_radius * 100.0);
+    }
+
+    pub fn eye(&self) -> Vec3 {
+        let direction = Vec3::new(
+            self.pitch.cos() * self.yaw.cos(),
+            self.pitch.cos() * self.yaw.sin(),
+            self.pitch.sin(),
+        );
+        self.target + direction * self.distance
+    }
+
+    pub fn view_projection(&self, aspect_ratio: f32) -> Mat4 {
+        let view = Mat4::look_at_rh(self.eye(), self.target, Vec3::Z);
+
+        // keep the whole scene between the clip planes wherever the target was panned to
+        let far = self.distance + self.target.distance(self.scene_center) + self.scene_radius * 2.0;
+        let near = (far * 0.0005).max(self.distance * 0.01);
+        let projection = Mat4::perspective_rh(self.fov_y, aspect_ratio, near, far);
+
+        projection * view
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fitted_camera_looks_at_scene_center() {
+        let camera = Camera::new_fitted(Vec3::ZERO, Vec3::new(10.0, 20.0, 30.0));
+        let center = camera
+            .view_projection(1.0)
+            .project_point3(Vec3::new(5.0, 10.0, 15.0));
+
+        assert!(
+            center.x.abs() < 1e-5 && center.y.abs() < 1e-5,
+            "Scene center is not in the middle of the view"
+        );
+        assert!(
+            center.z > 0.0 && center.z < 1.0,
+            "Scene center is clipped by the depth range"
+        );
+    }
+
+    #[test]
+    fn test_top_view_looks_down() {
+        let mut camera = Camera::new_fitted(Vec3::ZERO, Vec3::ONE);
+        camera.set_view(ViewPreset::Top);
+
+        let direction = (camera.target - camera.eye()).normalize();
+        assert!(
+            direction.z < -0.99,
+            "Top view does not look down the Z axis"
+        );
+    }
+
+    #[test]
+    fn test_pan_keeps_view_direction() {
+        let mut camera = Camera::new_fitted(Vec3::ZERO, Vec3::ONE);
+        let before = (camera.target - camera.eye()).normalize();
+        camera.pan(egui::vec2(25.0, -10.0), 300.0);
+        let after = (camera.target - camera.eye()).normalize();
+
+        assert!(
+            camera.target != Vec3::splat(0.5),
+            "Pan did not move the target"
+        );
+        assert!(before.abs_diff_eq(after, 1e-5), "Pan rotated the camera");
+    }
+}
@@ -0,0 +1,55 @@
+struct VertexIn {
+    @location(0) position: vec3<f32>,
+    @location(1) color: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+};
+
+struct VertexOut {
+    @builtin(position) position: vec4<f32>,
+    @location(0) world_position: vec3<f32>,
+    @location(1) color: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+};
+
+struct Uniforms {
+    view_proj: mat4x4<f32>,
+};
+
+@group(0) @binding(0)
+var<uniform> uniforms: Uniforms;
+
+// Texture of the triangles drawn, a white pixel for triangles without one.
+@group(1) @binding(0)
+var texture: texture_2d<f32>;
+@group(1) @binding(1)
+var texture_sampler: sampler;
+
+const LIGHT_DIRECTION: vec3<f32> = vec3<f32>(0.4, 0.5, 0.77);
+
+@vertex
+fn vs_main(in: VertexIn) -> VertexOut {
+    var out: VertexOut;
+
+    out.position = uniforms.view_proj * vec4<f32>(in.position, 1.0);
+    out.world_position = in.position;
+    out.color = in.color;
+    out.uv = in.uv;
+
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOut) -> @location(0) vec4<f32> {
+    // Flat shading, the face normal is recovered from the screen space derivatives
+    // so the vertex buffers can share vertices between triangles.
+    let normal = normalize(cross(dpdx(in.world_position), dpdy(in.world_position)));
+    let diffuse = abs(dot(normal, normalize(LIGHT_DIRECTION)));
+    let color = in.color * textureSample(texture, texture_sampler, in.uv).rgb;
+
+    return vec4<f32>(color * (0.25 + 0.75 * diffuse), 1.0);
+}
+
+@fragment
+fn fs_unlit(in: VertexOut) -> @location(0) vec4<f32> {
+    return vec4<f32>(in.color, 1.0);
+}
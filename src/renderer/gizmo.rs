@@ -0,0 +1,426 @@
+use glam::{DMat4, DVec3, Mat4};
+use std::f32::consts::PI;
+
+use crate::geometry::transform::snap;
+
+/// Distance in points within which a handle is grabbed.
+const PICK_DISTANCE: f32 = 8.0;
+const RING_SEGMENTS: usize = 64;
+/// Radius of the rotation rings relative to the length of the axes.
+const RING_RADIUS: f64 = 0.8;
+const HANDLE_WIDTH: f32 = 2.5;
+const AXIS_COLORS: [egui::Color32; 3] = [
+    egui::Color32::from_rgb(225, 65, 65),
+    egui::Color32::from_rgb(70, 190, 70),
+    egui::Color32::from_rgb(75, 115, 235),
+];
+const ACTIVE_COLOR: egui::Color32 = egui::Color32::from_rgb(250, 210, 60);
+/// Scales are kept above this so the transform stays invertible.
+const MIN_SCALE: f64 = 0.001;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GizmoMode {
+    Translate,
+    Rotate,
+    Scale,
+}
+
+/// Steps edits are rounded to when snapping is on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapSettings {
+    pub enabled: bool,
+    /// In the unit of the model.
+    pub translation: f64,
+    pub rotation_degrees: f64,
+    pub scale: f64,
+}
+
+impl Default for SnapSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            translation: 1.0,
+            rotation_degrees: 15.0,
+            scale: 0.1,
+        }
+    }
+}
+
+/// A change of a transform. It is shown while `Dragging` and kept once `Finished`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransformEdit {
+    Dragging(DMat4),
+    Finished(DMat4),
+}
+
+/// What the gizmo moves, in the coordinates of the build.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GizmoTarget {
+    pub transform: DMat4,
+    /// Centre of the bounds of the item, the handles start here.
+    pub center: DVec3,
+    /// Length of the handles.
+    pub size: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct GizmoDrag {
+    axis: usize,
+    start: DMat4,
+    center: DVec3,
+    /// Distance moved, degrees turned or change of the scale factor so far.
+    amount: f64,
+}
+
+/// Handles drawn over the viewport to move, turn and scale a build item.
+///
+/// Items are moved and turned along the axes of the build and scaled along their own axes,
+/// always about the centre of their bounds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gizmo {
+    pub mode: GizmoMode,
+    pub snap: SnapSettings,
+    drag: Option<GizmoDrag>,
+}
+
+impl Default for Gizmo {
+    fn default() -> Self {
+        Self {
+            mode: GizmoMode::Translate,
+            snap: SnapSettings::default(),
+            drag: None,
+        }
+    }
+}
+
+impl Gizmo {
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Draws the handles into the viewport of `response` and moves the target while a
+    /// handle is dragged.
+    pub fn ui(
+        &mut self,
+        ui: &egui::Ui,
+        response: &egui::Response,
+        view_proj: Mat4,
+        eye: DVec3,
+        target: &GizmoTarget,
+    ) -> Option<TransformEdit> {
+        let rect = response.rect;
+        let project = |point: DVec3| {
+            let clip = view_proj * point.as_vec3().extend(1.0);
+            (clip.w > 0.0).then(|| {
+                let ndc = clip.truncate() / clip.w;
+                egui::pos2(
+                    rect.left() + (ndc.x + 1.0) * 0.5 * rect.width(),
+                    rect.top() + (1.0 - ndc.y) * 0.5 * rect.height(),
+                )
+            })
+        };
+
+        // the handles follow the item while it is dragged
+        let (transform, center) = match &self.drag {
+            Some(drag) => {
+                let transform = self.dragged_transform(drag);
+                let moved = transform * drag.start.inverse();
+                (transform, moved.transform_point3(drag.center))
+            }
+            None => (target.transform, target.center),
+        };
+        let axes = self.handle_axes(&transform);
+        let handles: Vec<Vec<egui::Pos2>> = axes
+            .iter()
+            .map(|&axis| {
+                self.handle_points(center, axis, target.size)
+                    .into_iter()
+                    .filter_map(project)
+                    .collect()
+            })
+            .collect();
+
+        let hovered = response.hover_pos().and_then(|pointer| {
+            handles
+                .iter()
+                .enumerate()
+                .map(|(axis, points)| (axis, distance_to_polyline(pointer, points)))
+                .filter(|(_, distance)| *distance < PICK_DISTANCE)
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(axis, _)| axis)
+        });
+
+        if response.drag_started_by(egui::PointerButton::Primary) {
+            if let Some(axis) = hovered {
+                self.drag = Some(GizmoDrag {
+                    axis,
+                    start: target.transform,
+                    center: target.center,
+                    amount: 0.0,
+                });
+            }
+        }
+
+        let mut edit = None;
+        if let (Some(mut drag), Some(pointer)) =
+            (self.drag.clone(), response.interact_pointer_pos())
+        {
+            let delta = response.drag_delta();
+            let axis = self.handle_axes(&drag.start)[drag.axis];
+            match self.mode {
+                GizmoMode::Translate | GizmoMode::Scale => {
+                    // how far the pointer moved along the axis on screen, in build units
+                    if let (Some(from), Some(to)) =
+                        (project(center), project(center + axis * target.size))
+                    {
+                        let along = to - from;
+                        if along.length_sq() > f32::EPSILON {
+                            let moved = (delta.dot(along) / along.length_sq()) as f64;
+                            drag.amount += match self.mode {
+                                GizmoMode::Scale => moved,
+                                _ => moved * target.size,
+                            };
+                        }
+                    }
+                }
+                GizmoMode::Rotate => {
+                    if let Some(origin) = project(center) {
+                        let before = (pointer - delta - origin).angle();
+                        let after = (pointer - origin).angle();
+                        let mut turn = after - before;
+                        if turn > PI {
+                            turn -= 2.0 * PI;
+                        } else if turn < -PI {
+                            turn += 2.0 * PI;
+                        }
+                        // screen angles grow clockwise, turns about an axis that points at
+                        // the viewer look counterclockwise
+                        let towards_viewer = axis.dot(eye - center) > 0.0;
+                        let degrees = (turn as f64).to_degrees();
+                        drag.amount += if towards_viewer { -degrees } else { degrees };
+                    }
+                }
+            }
+            self.drag = Some(drag.clone());
+            edit = Some(TransformEdit::Dragging(self.dragged_transform(&drag)));
+        }
+        if response.drag_stopped() {
+            if let Some(drag) = self.drag.take() {
+                edit = Some(TransformEdit::Finished(self.dragged_transform(&drag)));
+            }
+        }
+
+        let active = self.drag.as_ref().map(|drag| drag.axis).or(hovered);
+        let painter = ui.painter_at(rect);
+        for (axis, points) in handles.iter().enumerate() {
+            let color = if active == Some(axis) {
+                ACTIVE_COLOR
+            } else {
+                AXIS_COLORS[axis]
+            };
+            painter.add(egui::Shape::line(
+                points.clone(),
+                egui::Stroke::new(HANDLE_WIDTH, color),
+            ));
+            if let Some(&end) = points.last() {
+                match self.mode {
+                    GizmoMode::Translate => {
+                        painter.circle_filled(end, 5.0, color);
+                    }
+                    GizmoMode::Scale => {
+                        painter.rect_filled(
+                            egui::Rect::from_center_size(end, egui::vec2(9.0, 9.0)),
+                            0.0,
+                            color,
+                        );
+                    }
+                    GizmoMode::Rotate => {}
+                }
+            }
+        }
+
+        edit
+    }
+
+    fn dragged_transform(&self, drag: &GizmoDrag) -> DMat4 {
+        apply_drag(
+            self.mode,
+            drag.axis,
+            drag.amount,
+            &drag.start,
+            drag.center,
+            &self.snap,
+        )
+    }
+
+    /// Build axes to move and turn about, the axes of the item to scale along.
+    fn handle_axes(&self, transform: &DMat4) -> [DVec3; 3] {
+        match self.mode {
+            GizmoMode::Scale => [
+                transform.x_axis.truncate(),
+                transform.y_axis.truncate(),
+                transform.z_axis.truncate(),
+            ]
+            .map(|axis| axis.try_normalize().unwrap_or(DVec3::ZERO)),
+            _ => [DVec3::X, DVec3::Y, DVec3::Z],
+        }
+    }
+
+    /// Points of the line drawn for an axis, a ring around it when rotating.
+    fn handle_points(&self, center: DVec3, axis: DVec3, size: f64) -> Vec<DVec3> {
+        match self.mode {
+            GizmoMode::Rotate => {
+                let u = axis.any_orthonormal_vector();
+                let v = axis.cross(u);
+                (0..=RING_SEGMENTS)
+                    .map(|index| {
+                        let angle = index as f64 / RING_SEGMENTS as f64 * std::f64::consts::TAU;
+                        center + (u * angle.cos() + v * angle.sin()) * size * RING_RADIUS
+                    })
+                    .collect()
+            }
+            _ => vec![center, center + axis * size],
+        }
+    }
+}
+
+/// Transform after dragging the handle of `axis` by `amount` from `start`, snapped when
+/// enabled. Amounts are distances for moves, degrees for turns and the change of the
+/// scale factor for scaling.
+pub fn apply_drag(
+    mode: GizmoMode,
+    axis: usize,
+    amount: f64,
+    start: &DMat4,
+    center: DVec3,
+    snap_settings: &SnapSettings,
+) -> DMat4 {
+    let snapped = |value: f64, step: f64| {
+        if snap_settings.enabled {
+            snap(value, step)
+        } else {
+            value
+        }
+    };
+    let about_center =
+        |change: DMat4| DMat4::from_translation(center) * change * DMat4::from_translation(-center);
+    let world_axis = [DVec3::X, DVec3::Y, DVec3::Z][axis];
+
+    match mode {
+        GizmoMode::Translate => {
+            DMat4::from_translation(world_axis * snapped(amount, snap_settings.translation))
+                * *start
+        }
+        GizmoMode::Rotate => {
+            let degrees = snapped(amount, snap_settings.rotation_degrees);
+            about_center(DMat4::from_axis_angle(world_axis, degrees.to_radians())) * *start
+        }
+        GizmoMode::Scale => {
+            let mut scale = DVec3::ONE;
+            scale[axis] = snapped(1.0 + amount, snap_settings.scale).max(MIN_SCALE);
+            // along the axes of the item, about its centre in its own coordinates
+            let local_center = start.inverse().transform_point3(center);
+            *start
+                * DMat4::from_translation(local_center)
+                * DMat4::from_scale(scale)
+                * DMat4::from_translation(-local_center)
+        }
+    }
+}
+
+fn distance_to_polyline(point: egui::Pos2, points: &[egui::Pos2]) -> f32 {
+    points
+        .windows(2)
+        .map(|segment| {
+            let (a, b) = (segment[0], segment[1]);
+            let along = b - a;
+            let t = if along.length_sq() > 0.0 {
+                ((point - a).dot(along) / along.length_sq()).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            point.distance(a + along * t)
+        })
+        .fold(f32::INFINITY, f32::min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_moves_snap_to_the_step() {
+        let start = DMat4::from_translation(DVec3::new(0.3, 0.0, 0.0));
+        let moved = apply_drag(
+            GizmoMode::Translate,
+            0,
+            2.6,
+            &start,
+            DVec3::ZERO,
+            &SnapSettings::default(),
+        );
+
+        assert!(
+            moved
+                .w_axis
+                .truncate()
+                .abs_diff_eq(DVec3::new(3.3, 0.0, 0.0), 1e-9),
+            "Move is not snapped relative to the start: {:?}",
+            moved.w_axis
+        );
+    }
+
+    #[test]
+    fn test_turns_keep_the_center_in_place() {
+        let center = DVec3::new(5.0, 5.0, 1.0);
+        let turned = apply_drag(
+            GizmoMode::Rotate,
+            2,
+            44.0,
+            &DMat4::IDENTITY,
+            center,
+            &SnapSettings::default(),
+        );
+
+        assert!(
+            turned.transform_point3(center).abs_diff_eq(center, 1e-9),
+            "Centre moved while turning"
+        );
+        assert!(
+            turned
+                .transform_vector3(DVec3::X)
+                .abs_diff_eq(DVec3::new(0.5_f64.sqrt(), 0.5_f64.sqrt(), 0.0), 1e-9),
+            "Turn of 44 degrees is not snapped to 45"
+        );
+    }
+
+    #[test]
+    fn test_scale_follows_the_axes_of_the_item() {
+        // turned a quarter about Z, so the X axis of the item lies along Y
+        let start = DMat4::from_rotation_z(std::f64::consts::FRAC_PI_2);
+        let scaled = apply_drag(
+            GizmoMode::Scale,
+            0,
+            1.0,
+            &start,
+            DVec3::ZERO,
+            &SnapSettings {
+                enabled: false,
+                ..Default::default()
+            },
+        );
+
+        assert!(
+            scaled
+                .transform_vector3(DVec3::X)
+                .abs_diff_eq(DVec3::new(0.0, 2.0, 0.0), 1e-9),
+            "Item is not scaled along its own X axis"
+        );
+        assert!(
+            scaled
+                .transform_vector3(DVec3::Y)
+                .abs_diff_eq(DVec3::new(-1.0, 0.0, 0.0), 1e-9),
+            "Other axes of the item are scaled"
+        );
+    }
+}
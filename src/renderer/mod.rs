@@ -0,0 +1,734 @@
+use anyhow::Result;
+use eframe::egui_wgpu::{self, wgpu::util::DeviceExt};
+use glam::{DVec3, Mat4};
+use std::{collections::HashMap, ops::Range};
+use wgpu::{self, ColorTargetState, ColorWrites};
+
+use crate::{
+    geometry::build::{bounds_of_build, flatten_build, BuildMesh, TriangleSet},
+    threemf::{materials::TextureImage, threemf_reader::ModelParts},
+};
+
+pub mod build_plate;
+pub mod camera;
+pub mod gizmo;
+pub mod picking;
+use camera::{Camera, ViewPreset};
+use gizmo::{Gizmo, GizmoTarget, TransformEdit};
+use picking::PickedTriangle;
+
+/// Depth format of the egui render pass, must match `depth_buffer` in the native options.
+pub const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth24Plus;
+/// Colour of highlighted triangles, e.g. mesh defects.
+const HIGHLIGHT_COLOR: [f32; 3] = [0.9, 0.1, 0.1];
+/// Colour of selected triangles, drawn over highlights.
+const SELECTION_COLOR: [f32; 3] = [0.2, 0.5, 1.0];
+
+/// A line drawn without shading, e.g. a move of a toolpath.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColoredLine {
+    pub from: DVec3,
+    pub to: DVec3,
+    pub color: [f32; 3],
+}
+
+/// What was done in the viewport in a frame.
+#[derive(Debug, Default)]
+pub struct ViewportOutput {
+    /// Edit of the dragged gizmo handle.
+    pub edit: Option<TransformEdit>,
+    /// Triangle that was clicked.
+    pub picked: Option<PickedTriangle>,
+}
+
+pub struct Custom3d {
+    camera: Camera,
+    /// The drawn build, kept to find the triangles under the pointer.
+    meshes: Vec<BuildMesh>,
+}
+
+impl Custom3d {
+    /// Uploads every build item of the model to the GPU, drawing `highlighted` triangles
+    /// in a signal colour and `selected` triangles in the selection colour.
+    pub fn new(
+        cc: &eframe::Frame,
+        model_parts: &ModelParts,
+        highlighted: &TriangleSet,
+        selected: &TriangleSet,
+    ) -> Result<Self> {
+        let meshes = flatten_build(model_parts)?;
+        let camera = match bounds_of_build(&meshes) {
+            Some((min, max)) => Camera::new_fitted(min.as_vec3(), max.as_vec3()),
+            None => Camera::default(),
+        };
+
+        // Get the WGPU render state from the eframe creation context. This can also be retrieved
+        // from `eframe::Frame` when you don't have a `CreationContext` available.
+        let binding = cc.wgpu_render_state();
+        let render_state = binding.as_ref().expect("WGPU enabled");
+
+        let mut resources = MeshRenderResources::new(render_state);
+        resources.upload_meshes(
+            &render_state.device,
+            &render_state.queue,
+            model_parts,
+            &meshes,
+            highlighted,
+            selected,
+        );
+
+        // Because the graphics pipeline must have the same lifetime as the egui render pass,
+        // instead of storing the pipeline in our `Custom3D` struct, we insert it into the
+        // `callback_resources` type map, which is stored alongside the render pass.
+        render_state
+            .renderer
+            .write()
+            .callback_resources
+            .insert(resources);
+
+        Ok(Self { camera, meshes })
+    }
+
+    /// Shows lines instead of a model, e.g. the moves of a G-code program.
+    pub fn new_with_lines(cc: &eframe::Frame, lines: &[ColoredLine]) -> Self {
+        let camera = match bounds_of_lines(lines) {
+            Some((min, max)) => Camera::new_fitted(min.as_vec3(), max.as_vec3()),
+            None => Camera::default(),
+        };
+
+        let binding = cc.wgpu_render_state();
+        let render_state = binding.as_ref().expect("WGPU enabled");
+        let mut resources = MeshRenderResources::new(render_state);
+        resources.lines = gpu_lines(&render_state.device, lines);
+        render_state
+            .renderer
+            .write()
+            .callback_resources
+            .insert(resources);
+
+        Self {
+            camera,
+            meshes: Vec::new(),
+        }
+    }
+
+    /// Replaces the lines on the GPU, keeping the camera.
+    pub fn update_lines(&mut self, cc: &eframe::Frame, lines: &[ColoredLine]) {
+        let binding = cc.wgpu_render_state();
+        let render_state = binding.as_ref().expect("WGPU enabled");
+        let gpu_lines = gpu_lines(&render_state.device, lines);
+        if let Some(resources) = render_state
+            .renderer
+            .write()
+            .callback_resources
+            .get_mut::<MeshRenderResources>()
+        {
+            resources.lines = gpu_lines;
+        }
+    }
+
+    /// Replaces the lines of the build plate, which are drawn under the model or toolpath.
+    pub fn update_plate(&mut self, cc: &eframe::Frame, lines: &[ColoredLine]) {
+        let binding = cc.wgpu_render_state();
+        let render_state = binding.as_ref().expect("WGPU enabled");
+        let gpu_lines = gpu_lines(&render_state.device, lines);
+        if let Some(resources) = render_state
+            .renderer
+            .write()
+            .callback_resources
+            .get_mut::<MeshRenderResources>()
+        {
+            resources.plate = gpu_lines;
+        }
+    }
+
+    /// Replaces the meshes on the GPU after the model or its highlights changed, keeping
+    /// the camera.
+    pub fn update_meshes(
+        &mut self,
+        cc: &eframe::Frame,
+        model_parts: &ModelParts,
+        highlighted: &TriangleSet,
+        selected: &TriangleSet,
+    ) -> Result<()> {
+        let meshes = flatten_build(model_parts)?;
+        if let Some((min, max)) = bounds_of_build(&meshes) {
+            self.camera.set_scene_bounds(min.as_vec3(), max.as_vec3());
+        }
+
+        let binding = cc.wgpu_render_state();
+        let render_state = binding.as_ref().expect("WGPU enabled");
+        if let Some(resources) = render_state
+            .renderer
+            .write()
+            .callback_resources
+            .get_mut::<MeshRenderResources>()
+        {
+            resources.upload_meshes(
+                &render_state.device,
+                &render_state.queue,
+                model_parts,
+                &meshes,
+                highlighted,
+                selected,
+            );
+        }
+        self.meshes = meshes;
+
+        Ok(())
+    }
+}
+
+/// Uploads the lines as pairs of interleaved position, colour and texture coordinate
+/// vertices. Lines are not textured.
+fn gpu_lines(device: &wgpu::Device, lines: &[ColoredLine]) -> Option<GpuLines> {
+    if lines.is_empty() {
+        return None;
+    }
+
+    let vertices: Vec<[f32; 8]> = lines
+        .iter()
+        .flat_map(|line| {
+            let [r, g, b] = line.color;
+            [line.from, line.to].map(|point| {
+                let [x, y, z] = point.as_vec3().to_array();
+                [x, y, z, r, g, b, 0.0, 0.0]
+            })
+        })
+        .collect();
+    Some(GpuLines {
+        vertex_buffer: device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("line vertices"),
+            contents: bytemuck::cast_slice(&vertices),
+            usage: wgpu::BufferUsages::VERTEX,
+        }),
+        vertex_count: vertices.len() as u32,
+    })
+}
+
+/// Uploads the image as a texture and binds it with the sampler. Images without pixels or
+/// larger than the device allows are logged and left out.
+fn texture_bind_group(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    layout: &wgpu::BindGroupLayout,
+    sampler: &wgpu::Sampler,
+    image: &TextureImage,
+) -> Option<wgpu::BindGroup> {
+    let max_size = device.limits().max_texture_dimension_2d;
+    if image.width == 0 || image.height == 0 || image.width.max(image.height) > max_size {
+        log::warn!(
+            "Texture of {} by {} pixels cannot be drawn",
+            image.width,
+            image.height
+        );
+        return None;
+    }
+
+    let texture = device.create_texture_with_data(
+        queue,
+        &wgpu::TextureDescriptor {
+            label: Some("build item texture"),
+            size: wgpu::Extent3d {
+                width: image.width,
+                height: image.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            // the pixels are used as they are, like the colours of the vertices
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        },
+        wgpu::util::TextureDataOrder::LayerMajor,
+        bytemuck::cast_slice(&image.pixels),
+    );
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+    Some(device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: None,
+        layout,
+        entries: &[
+            wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&view),
+            },
+            wgpu::BindGroupEntry {
+                binding: 1,
+                resource: wgpu::BindingResource::Sampler(sampler),
+            },
+        ],
+    }))
+}
+
+fn bounds_of_lines(lines: &[ColoredLine]) -> Option<(DVec3, DVec3)> {
+    lines
+        .iter()
+        .flat_map(|line| [line.from, line.to])
+        .fold(None, |bounds, point| match bounds {
+            None => Some((point, point)),
+            Some((min, max)) => Some((point.min(min), point.max(max))),
+        })
+}
+
+impl Custom3d {
+    /// Draws the view toolbar and the build, filling the remaining space of `ui`. With a
+    /// `gizmo` its handles are drawn over the target and the edit of a dragged handle is
+    /// returned.
+    ///
+    /// Click to pick a triangle, drag to orbit, middle or shift drag to pan, scroll to zoom
+    /// and double click to fit.
+    pub fn custom_painting(
+        &mut self,
+        ui: &mut egui::Ui,
+        gizmo: Option<(&mut Gizmo, &GizmoTarget)>,
+    ) -> ViewportOutput {
+        ui.horizontal(|ui| {
+            if ui.button("Fit").clicked() {
+                self.camera.fit_to_scene();
+            }
+            ui.separator();
+            for (label, preset) in [
+                ("Front", ViewPreset::Front),
+                ("Top", ViewPreset::Top),
+                ("Iso", ViewPreset::Iso),
+            ] {
+                if ui.button(label).clicked() {
+                    self.camera.set_view(preset);
+                }
+            }
+        });
+
+        let (rect, response) =
+            ui.allocate_exact_size(ui.available_size(), egui::Sense::click_and_drag());
+        let view_proj = self.camera.view_projection(rect.aspect_ratio());
+
+        let cb = egui_wgpu::Callback::new_paint_callback(rect, CustomMeshCallback { view_proj });
+        ui.painter().add(cb);
+
+        // handles are painted over the build and take drags that start on them
+        let mut edit = None;
+        let mut gizmo_dragging = false;
+        if let Some((gizmo, target)) = gizmo {
+            edit = gizmo.ui(
+                ui,
+                &response,
+                view_proj,
+                self.camera.eye().as_dvec3(),
+                target,
+            );
+            gizmo_dragging = gizmo.is_dragging() || edit.is_some();
+        }
+
+        let pan_modifier = ui.input(|i| i.modifiers.shift);
+        if response.dragged_by(egui::PointerButton::Middle)
+            || (pan_modifier && response.dragged_by(egui::PointerButton::Primary))
+        {
+            self.camera.pan(response.drag_delta(), rect.height());
+        } else if !gizmo_dragging && response.dragged_by(egui::PointerButton::Primary) {
+            self.camera.orbit(response.drag_delta());
+        }
+
+        if response.hovered() {
+            let scroll = ui.input(|i| i.smooth_scroll_delta.y);
+            if scroll != 0.0 {
+                self.camera.zoom(scroll);
+            }
+        }
+
+        if response.double_clicked() {
+            self.camera.fit_to_scene();
+        }
+
+        let mut picked = None;
+        if let (true, false, Some(pointer)) = (
+            response.clicked(),
+            gizmo_dragging,
+            response.interact_pointer_pos(),
+        ) {
+            let (origin, direction) = picking::ray_through(view_proj, rect, pointer);
+            picked = picking::pick_triangle(&self.meshes, origin, direction);
+        }
+
+        ViewportOutput { edit, picked }
+    }
+}
+
+// Callbacks in egui_wgpu have 3 stages:
+// * prepare (per callback impl)
+// * finish_prepare (once)
+// * paint (per callback impl)
+//
+// The prepare callback is called every frame before paint and is given access to the wgpu
+// Device and Queue, which can be used, for instance, to update buffers and uniforms before
+// rendering.
+// If [`egui_wgpu::Renderer`] has [`egui_wgpu::FinishPrepareCallback`] registered,
+// it will be called after all `prepare` callbacks have been called.
+// You can use this to update any shared resources that need to be updated once per frame
+// after all callbacks have been processed.
+//
+// On both prepare methods you can use the main `CommandEncoder` that is passed-in,
+// return an arbitrary number of user-defined `CommandBuffer`s, or both.
+// The main command buffer, as well as all user-defined ones, will be submitted together
+// to the GPU in a single call.
+//
+// The paint callback is called after finish prepare and is given access to egui's main render pass,
+// which can be used to issue draw commands.
+struct CustomMeshCallback {
+    view_proj: Mat4,
+}
+
+impl egui_wgpu::CallbackTrait for CustomMeshCallback {
+    fn prepare(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        _screen_descriptor: &egui_wgpu::ScreenDescriptor,
+        _egui_encoder: &mut wgpu::CommandEncoder,
+        resources: &mut egui_wgpu::CallbackResources,
+    ) -> Vec<wgpu::CommandBuffer> {
+        let resources: &MeshRenderResources = resources.get().unwrap();
+        resources.prepare(device, queue, self.view_proj);
+        Vec::new()
+    }
+
+    fn paint<'a>(
+        &self,
+        _info: egui::PaintCallbackInfo,
+        render_pass: &mut wgpu::RenderPass<'a>,
+        resources: &'a egui_wgpu::CallbackResources,
+    ) {
+        let resources: &MeshRenderResources = resources.get().unwrap();
+        resources.paint(render_pass);
+    }
+}
+
+struct GpuMesh {
+    vertex_buffer: wgpu::Buffer,
+    index_buffer: wgpu::Buffer,
+    /// Ranges of the index buffer with the texture they are drawn with, an index into the
+    /// textures of the resources. Ranges without one are drawn in the vertex colours.
+    draws: Vec<(Option<usize>, Range<u32>)>,
+}
+
+struct GpuLines {
+    vertex_buffer: wgpu::Buffer,
+    vertex_count: u32,
+}
+
+struct MeshRenderResources {
+    pipeline: wgpu::RenderPipeline,
+    line_pipeline: wgpu::RenderPipeline,
+    bind_group: wgpu::BindGroup,
+    uniform_buffer: wgpu::Buffer,
+    texture_bind_group_layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+    /// Texture of what is not textured, a white pixel.
+    plain_texture: wgpu::BindGroup,
+    /// Textures of the meshes.
+    textures: Vec<wgpu::BindGroup>,
+    meshes: Vec<GpuMesh>,
+    lines: Option<GpuLines>,
+    plate: Option<GpuLines>,
+}
+
+impl MeshRenderResources {
+    /// Creates the pipelines for triangles and lines, with nothing to draw yet.
+    fn new(render_state: &egui_wgpu::RenderState) -> Self {
+        let device = &render_state.device;
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: None,
+            source: wgpu::ShaderSource::Wgsl(include_str!("./custom3d_wgpu_shader.wgsl").into()),
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: None,
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+
+        let texture_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: None,
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                ],
+            });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: None,
+            bind_group_layouts: &[&bind_group_layout, &texture_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let create_pipeline = |topology, fragment_entry_point| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: None,
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                    buffers: &[wgpu::VertexBufferLayout {
+                        array_stride: std::mem::size_of::<[f32; 8]>() as wgpu::BufferAddress,
+                        step_mode: wgpu::VertexStepMode::Vertex,
+                        attributes: &wgpu::vertex_attr_array![
+                            0 => Float32x3,
+                            1 => Float32x3,
+                            2 => Float32x2
+                        ],
+                    }],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: fragment_entry_point,
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                    targets: &[Some(ColorTargetState {
+                        format: render_state.target_format,
+                        blend: None,
+                        write_mask: ColorWrites::all(),
+                    })],
+                }),
+                // 3MF files in the wild do not always have a consistent winding, so both
+                // sides are drawn.
+                primitive: wgpu::PrimitiveState {
+                    topology,
+                    cull_mode: None,
+                    ..Default::default()
+                },
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: DEPTH_FORMAT,
+                    depth_write_enabled: true,
+                    depth_compare: wgpu::CompareFunction::Less,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+            })
+        };
+        let pipeline = create_pipeline(wgpu::PrimitiveTopology::TriangleList, "fs_main");
+        // lines have no surface to shade
+        let line_pipeline = create_pipeline(wgpu::PrimitiveTopology::LineList, "fs_unlit");
+
+        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: None,
+            contents: bytemuck::cast_slice(&Mat4::IDENTITY.to_cols_array()),
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::UNIFORM,
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: uniform_buffer.as_entire_binding(),
+            }],
+        });
+
+        // 3MF textures repeat outside of 0 to 1
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            address_mode_u: wgpu::AddressMode::Repeat,
+            address_mode_v: wgpu::AddressMode::Repeat,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+        let white = TextureImage {
+            width: 1,
+            height: 1,
+            pixels: vec![[255; 4]],
+        };
+        let plain_texture = texture_bind_group(
+            device,
+            &render_state.queue,
+            &texture_bind_group_layout,
+            &sampler,
+            &white,
+        )
+        .expect("a pixel fits every device");
+
+        Self {
+            pipeline,
+            line_pipeline,
+            bind_group,
+            uniform_buffer,
+            texture_bind_group_layout,
+            sampler,
+            plain_texture,
+            textures: Vec::new(),
+            meshes: Vec::new(),
+            lines: None,
+            plate: None,
+        }
+    }
+
+    /// Uploads the build meshes as interleaved position, colour and texture coordinate
+    /// vertices, and the images of their textures. Selected and highlighted triangles and
+    /// triangles with colours or textures of their own get vertices of their own so their
+    /// colour does not bleed into neighbours.
+    fn upload_meshes(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        model_parts: &ModelParts,
+        meshes: &[BuildMesh],
+        highlighted: &TriangleSet,
+        selected: &TriangleSet,
+    ) {
+        self.textures.clear();
+        // textures shared by build items are uploaded once
+        let mut uploaded: HashMap<(String, usize), Option<usize>> = HashMap::new();
+        let mut gpu_meshes = Vec::with_capacity(meshes.len());
+        for mesh in meshes.iter().filter(|mesh| !mesh.triangles.is_empty()) {
+            // triangles of textures that cannot be uploaded keep the colours of their corners
+            let mut textures = Vec::with_capacity(mesh.textures.len());
+            for key in &mesh.textures {
+                if !uploaded.contains_key(key) {
+                    let (part, id) = key;
+                    let bind_group = model_parts
+                        .parts
+                        .get(part)
+                        .and_then(|part| part.materials.textures.get(id)?.image.as_ref())
+                        .and_then(|image| {
+                            texture_bind_group(
+                                device,
+                                queue,
+                                &self.texture_bind_group_layout,
+                                &self.sampler,
+                                image,
+                            )
+                        });
+                    let index = bind_group.map(|bind_group| {
+                        self.textures.push(bind_group);
+                        self.textures.len() - 1
+                    });
+                    uploaded.insert(key.clone(), index);
+                }
+                textures.push(uploaded[key]);
+            }
+
+            let mut vertices: Vec<[f32; 8]> = mesh
+                .positions
+                .iter()
+                .zip(&mesh.colors)
+                .map(|(position, [r, g, b])| {
+                    let [x, y, z] = position.as_vec3().to_array();
+                    [x, y, z, *r, *g, *b, 0.0, 0.0]
+                })
+                .collect();
+            let mut plain: Vec<u32> = Vec::with_capacity(mesh.triangles.len() * 3);
+            let mut textured: Vec<Vec<u32>> = vec![Vec::new(); self.textures.len()];
+            for (index, triangle) in mesh.triangles.iter().enumerate() {
+                let texture = mesh.triangle_textures[index].and_then(|(texture, coordinates)| {
+                    Some((textures[texture as usize]?, coordinates))
+                });
+                let no_coordinates = [[0.0; 2]; 3];
+                let (colors, coordinates, indices) = if !selected.is_empty()
+                    && mesh.contains_triangle(selected, index)
+                {
+                    ([SELECTION_COLOR; 3], no_coordinates, &mut plain)
+                } else if !highlighted.is_empty() && mesh.contains_triangle(highlighted, index) {
+                    ([HIGHLIGHT_COLOR; 3], no_coordinates, &mut plain)
+                } else if let Some((texture, coordinates)) = texture {
+                    ([[1.0; 3]; 3], coordinates, &mut textured[texture])
+                } else if let Some(colors) = mesh.triangle_colors[index] {
+                    (colors, no_coordinates, &mut plain)
+                } else {
+                    plain.extend(triangle);
+                    continue;
+                };
+                for ((&corner, [r, g, b]), [u, v]) in triangle.iter().zip(colors).zip(coordinates) {
+                    let [x, y, z] = mesh.positions[corner as usize].as_vec3().to_array();
+                    indices.push(vertices.len() as u32);
+                    // texture coordinates start at the bottom left, images at the top left
+                    vertices.push([x, y, z, r, g, b, u as f32, 1.0 - v as f32]);
+                }
+            }
+
+            let mut draws = vec![(None, 0..plain.len() as u32)];
+            let mut indices = plain;
+            for (texture, textured) in textured.into_iter().enumerate() {
+                if !textured.is_empty() {
+                    let start = indices.len() as u32;
+                    indices.extend(textured);
+                    draws.push((Some(texture), start..indices.len() as u32));
+                }
+            }
+
+            gpu_meshes.push(GpuMesh {
+                vertex_buffer: device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("build item vertices"),
+                    contents: bytemuck::cast_slice(&vertices),
+                    usage: wgpu::BufferUsages::VERTEX,
+                }),
+                index_buffer: device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("build item indices"),
+                    contents: bytemuck::cast_slice(&indices),
+                    usage: wgpu::BufferUsages::INDEX,
+                }),
+                draws,
+            });
+        }
+        self.meshes = gpu_meshes;
+    }
+
+    fn prepare(&self, _device: &wgpu::Device, queue: &wgpu::Queue, view_proj: Mat4) {
+        // Update our uniform buffer with the camera from the UI
+        queue.write_buffer(
+            &self.uniform_buffer,
+            0,
+            bytemuck::cast_slice(&view_proj.to_cols_array()),
+        );
+    }
+
+    fn paint<'rpass>(&'rpass self, rpass: &mut wgpu::RenderPass<'rpass>) {
+        rpass.set_pipeline(&self.pipeline);
+        rpass.set_bind_group(0, &self.bind_group, &[]);
+        for mesh in &self.meshes {
+            rpass.set_vertex_buffer(0, mesh.vertex_buffer.slice(..));
+            rpass.set_index_buffer(mesh.index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+            for (texture, range) in &mesh.draws {
+                let texture = match texture {
+                    Some(texture) => &self.textures[*texture],
+                    None => &self.plain_texture,
+                };
+                rpass.set_bind_group(1, texture, &[]);
+                rpass.draw_indexed(range.clone(), 0, 0..1);
+            }
+        }
+
+        for lines in [&self.lines, &self.plate].into_iter().flatten() {
+            rpass.set_pipeline(&self.line_pipeline);
+            rpass.set_bind_group(0, &self.bind_group, &[]);
+            rpass.set_bind_group(1, &self.plain_texture, &[]);
+            rpass.set_vertex_buffer(0, lines.vertex_buffer.slice(..));
+            rpass.draw(0..lines.vertex_count, 0..1);
+        }
+    }
+}
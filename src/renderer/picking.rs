@@ -0,0 +1,156 @@
+use glam::{DVec3, Mat4};
+
+use crate::geometry::build::BuildMesh;
+
+/// A triangle of the build under the pointer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PickedTriangle {
+    /// Index of the build item the triangle was drawn for.
+    pub item: usize,
+    pub part: String,
+    pub object_id: usize,
+    /// Index of the triangle in the mesh of the object.
+    pub triangle: usize,
+}
+
+/// Ray from the near plane through `point` of the viewport `rect`, as origin and
+/// direction in world space.
+pub fn ray_through(view_proj: Mat4, rect: egui::Rect, point: egui::Pos2) -> (DVec3, DVec3) {
+    let inverse = view_proj.as_dmat4().inverse();
+    let x = ((point.x - rect.left()) / rect.width() * 2.0 - 1.0) as f64;
+    let y = (1.0 - (point.y - rect.top()) / rect.height() * 2.0) as f64;
+    let near = inverse.project_point3(DVec3::new(x, y, 0.0));
+    let far = inverse.project_point3(DVec3::new(x, y, 1.0));
+    (near, (far - near).normalize())
+}
+
+/// Nearest triangle of the build meshes hit by the ray. Both sides of a triangle can be
+/// hit, like both sides are drawn.
+pub fn pick_triangle(
+    meshes: &[BuildMesh],
+    origin: DVec3,
+    direction: DVec3,
+) -> Option<PickedTriangle> {
+    let mut nearest: Option<(f64, usize, usize)> = None;
+    for (item, mesh) in meshes.iter().enumerate() {
+        for (index, triangle) in mesh.triangles.iter().enumerate() {
+            let corners = triangle.map(|corner| mesh.positions[corner as usize]);
+            let Some(distance) = ray_triangle_distance(origin, direction, corners) else {
+                continue;
+            };
+            if nearest.is_some_and(|(nearest, _, _)| nearest <= distance) {
+                continue;
+            }
+            nearest = Some((distance, item, index));
+        }
+    }
+
+    let (_, item, index) = nearest?;
+    let mesh = &meshes[item];
+    let (object, triangle) = mesh.triangle_sources[index];
+    let (part, object_id) = mesh.objects[object as usize].clone();
+    Some(PickedTriangle {
+        item,
+        part,
+        object_id,
+        triangle: triangle as usize,
+    })
+}
+
+/// Distance along the ray to where it crosses the triangle, after Möller and Trumbore.
+fn ray_triangle_distance(origin: DVec3, direction: DVec3, [a, b, c]: [DVec3; 3]) -> Option<f64> {
+    let (edge1, edge2) = (b - a, c - a);
+    let p = direction.cross(edge2);
+    let determinant = edge1.dot(p);
+    // the ray runs along the plane of the triangle, or the triangle has no area
+    if determinant.abs() <= f64::EPSILON * edge1.length() * edge2.length() {
+        return None;
+    }
+
+    let s = origin - a;
+    let u = s.dot(p) / determinant;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let q = s.cross(edge1);
+    let v = direction.dot(q) / determinant;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let distance = edge2.dot(q) / determinant;
+    (distance > 0.0).then_some(distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::camera::Camera;
+    use glam::Vec3;
+
+    #[test]
+    fn test_nearest_triangle_is_picked() {
+        // two squares of the same object above each other, the upper one last
+        let square = |z: f64| {
+            [
+                DVec3::new(0.0, 0.0, z),
+                DVec3::new(1.0, 0.0, z),
+                DVec3::new(1.0, 1.0, z),
+                DVec3::new(0.0, 1.0, z),
+            ]
+        };
+        let mesh = BuildMesh {
+            object_id: 3,
+            positions: [square(0.0), square(1.0)].concat(),
+            colors: vec![[1.0; 3]; 8],
+            triangles: vec![[0, 1, 2], [0, 2, 3], [4, 5, 6], [4, 6, 7]],
+            triangle_colors: vec![None; 4],
+            triangle_textures: vec![None; 4],
+            textures: Vec::new(),
+            triangle_sources: vec![(0, 0), (0, 1), (0, 2), (0, 3)],
+            objects: vec![("/3D/3dmodel.model".to_string(), 3)],
+        };
+        let meshes = [mesh.clone(), mesh];
+
+        let picked = pick_triangle(&meshes, DVec3::new(0.25, 0.75, 5.0), DVec3::NEG_Z).unwrap();
+        assert!(
+            picked
+                == PickedTriangle {
+                    item: 0,
+                    part: "/3D/3dmodel.model".to_string(),
+                    object_id: 3,
+                    triangle: 3
+                },
+            "Upper triangle of the first item is not picked: {:?}",
+            picked
+        );
+        let below = pick_triangle(&meshes, DVec3::new(0.75, 0.25, -5.0), DVec3::Z).unwrap();
+        assert!(
+            below.triangle == 0,
+            "Lower side is not picked from below: {:?}",
+            below
+        );
+        assert!(
+            pick_triangle(&meshes, DVec3::new(2.0, 0.5, 5.0), DVec3::NEG_Z).is_none(),
+            "Ray next to the squares picks a triangle"
+        );
+    }
+
+    #[test]
+    fn test_ray_through_the_middle_hits_the_target() {
+        let camera = Camera::new_fitted(Vec3::ZERO, Vec3::new(10.0, 20.0, 30.0));
+        let rect = egui::Rect::from_min_size(egui::pos2(100.0, 50.0), egui::vec2(400.0, 300.0));
+        let (origin, direction) = ray_through(
+            camera.view_projection(rect.aspect_ratio()),
+            rect,
+            rect.center(),
+        );
+
+        let target = camera.target.as_dvec3();
+        let miss = (target - origin).cross(direction).length();
+        assert!(
+            miss < 1e-3 && (target - origin).dot(direction) > 0.0,
+            "Ray through the middle misses the target by {}",
+            miss
+        );
+    }
+}
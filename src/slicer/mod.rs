@@ -0,0 +1 @@
+pub mod planar_slicer;
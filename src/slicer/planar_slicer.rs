@@ -0,0 +1,464 @@
+use anyhow::{anyhow, Result};
+use glam::{DVec2, DVec3};
+use std::collections::HashMap;
+
+use crate::{
+    geometry::build::{bounds_of_build, flatten_build, BuildMesh},
+    threemf::threemf_reader::ModelParts,
+};
+
+/// Layers are not added for a remainder thinner than this fraction of the layer height.
+const LAYER_COUNT_EPSILON: f64 = 1e-6;
+/// Points closer than this, in model units, are merged when contours are simplified.
+const POINT_EPSILON: f64 = 1e-9;
+/// Longest offset of a corner relative to the offset distance, sharper corners are cut short.
+const MITER_LIMIT: f64 = 4.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SliceSettings {
+    /// Height of every layer in model units.
+    pub layer_height: f64,
+}
+
+impl Default for SliceSettings {
+    fn default() -> Self {
+        Self { layer_height: 0.2 }
+    }
+}
+
+/// A closed polygon of a layer. Outer contours run counter clockwise and holes
+/// clockwise seen from above, the closing edge back to the first point is implied.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contour {
+    pub points: Vec<DVec2>,
+    pub is_hole: bool,
+}
+
+impl Contour {
+    /// Signed area, positive for outer contours and negative for holes.
+    pub fn area(&self) -> f64 {
+        signed_area(&self.points)
+    }
+
+    /// Moves every edge by `distance` into the material, so outer contours shrink and
+    /// holes grow. Returns `None` when the contour collapses, as it does for walls
+    /// thinner than twice the distance.
+    ///
+    /// Self intersections of the result are not resolved, an edge turning around is
+    /// taken as a collapse instead.
+    pub fn offset(&self, distance: f64) -> Option<Contour> {
+        let count = self.points.len();
+        // material is on the left of every edge given the orientation of the contours
+        let normals: Vec<DVec2> = (0..count)
+            .map(|index| {
+                (self.points[(index + 1) % count] - self.points[index])
+                    .normalize_or_zero()
+                    .perp()
+            })
+            .collect();
+
+        let points: Vec<DVec2> = (0..count)
+            .map(|index| {
+                let before = normals[(index + count - 1) % count];
+                let after = normals[index];
+                // corner of the two moved edges, |before + after| / (1 + cos) = 1 / cos(half angle)
+                let scale = (1.0 + before.dot(after)).max(2.0 / (MITER_LIMIT * MITER_LIMIT));
+                self.points[index] + (before + after) * (distance / scale)
+            })
+            .collect();
+
+        let turned = (0..count).any(|index| {
+            let old = self.points[(index + 1) % count] - self.points[index];
+            let new = points[(index + 1) % count] - points[index];
+            old.dot(new) <= 0.0
+        });
+        let contour = Contour {
+            points,
+            is_hole: self.is_hole,
+        };
+        if turned || (contour.area() > 0.0) == self.is_hole {
+            return None;
+        }
+        Some(contour)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layer {
+    pub index: usize,
+    /// Top of the layer. Contours are cut through the middle of the layer.
+    pub z: f64,
+    pub contours: Vec<Contour>,
+}
+
+impl Layer {
+    /// Area of the material in the layer, holes subtracted.
+    pub fn area(&self) -> f64 {
+        self.contours.iter().map(Contour::area).sum()
+    }
+
+    /// The layer with every coordinate multiplied by `factor`, e.g. to convert units.
+    pub fn scaled(&self, factor: f64) -> Layer {
+        Layer {
+            index: self.index,
+            z: self.z * factor,
+            contours: self
+                .contours
+                .iter()
+                .map(|contour| Contour {
+                    points: contour.points.iter().map(|point| *point * factor).collect(),
+                    is_hole: contour.is_hole,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Slices every build item of the model.
+pub fn slice_model(model_parts: &ModelParts, settings: &SliceSettings) -> Result<Vec<Layer>> {
+    slice_meshes(&flatten_build(model_parts)?, settings)
+}
+
+/// Cuts the meshes with horizontal planes from the bottom of the lowest mesh, one per
+/// layer, and stitches the cuts into contours.
+pub fn slice_meshes(meshes: &[BuildMesh], settings: &SliceSettings) -> Result<Vec<Layer>> {
+    if settings.layer_height.is_nan() || settings.layer_height <= 0.0 {
+        return Err(anyhow!(
+            "Layer height must be positive, not {}",
+            settings.layer_height
+        ));
+    }
+    let Some((min, max)) = bounds_of_build(meshes) else {
+        return Ok(Vec::new());
+    };
+
+    let height = settings.layer_height;
+    let layer_count = ((max.z - min.z) / height - LAYER_COUNT_EPSILON)
+        .ceil()
+        .max(0.0) as usize;
+    let mut layers = Vec::with_capacity(layer_count);
+    let mut open_chains = 0;
+    for index in 0..layer_count {
+        let plane = min.z + (index as f64 + 0.5) * height;
+        let mut loops = Vec::new();
+        for mesh in meshes {
+            let (mesh_loops, open) = slice_mesh(mesh, plane);
+            loops.extend(mesh_loops);
+            open_chains += open;
+        }
+
+        layers.push(Layer {
+            index,
+            z: min.z + (index as f64 + 1.0) * height,
+            contours: classify_contours(loops),
+        });
+    }
+
+    if open_chains > 0 {
+        log::warn!(
+            "{} contour pieces were dropped because the mesh is not closed",
+            open_chains
+        );
+    }
+    Ok(layers)
+}
+
+/// Intersects a mesh with the plane `z = plane` and returns the closed contours and the
+/// number of chains that did not close.
+///
+/// Segment ends are identified by the mesh edge they lie on, so stitching is exact for
+/// meshes sharing their vertices. Vertices on the plane count as above it.
+fn slice_mesh(mesh: &BuildMesh, plane: f64) -> (Vec<Vec<DVec2>>, usize) {
+    let mut segments: Vec<[[u32; 2]; 2]> = Vec::new();
+    let mut points: HashMap<[u32; 2], DVec2> = HashMap::new();
+
+    for triangle in &mesh.triangles {
+        let mut ends = Vec::with_capacity(2);
+        for (from, to) in [(0, 1), (1, 2), (2, 0)] {
+            let (a, b) = (triangle[from], triangle[to]);
+            let (pa, pb) = (mesh.positions[a as usize], mesh.positions[b as usize]);
+            if (pa.z >= plane) == (pb.z >= plane) {
+                continue;
+            }
+
+            let edge = [a.min(b), a.max(b)];
+            points
+                .entry(edge)
+                .or_insert_with(|| edge_intersection(mesh, edge, plane));
+            ends.push(edge);
+        }
+        if let [first, second] = ends[..] {
+            segments.push([first, second]);
+        }
+    }
+
+    let mut segments_at: HashMap<[u32; 2], Vec<usize>> = HashMap::new();
+    for (index, segment) in segments.iter().enumerate() {
+        for end in segment {
+            segments_at.entry(*end).or_default().push(index);
+        }
+    }
+
+    let mut used = vec![false; segments.len()];
+    let mut loops = Vec::new();
+    let mut open_chains = 0;
+    for start in 0..segments.len() {
+        if used[start] {
+            continue;
+        }
+        used[start] = true;
+
+        let [first, mut current] = segments[start];
+        let mut chain = vec![points[&first]];
+        let closed = loop {
+            if current == first {
+                break true;
+            }
+            chain.push(points[&current]);
+            let next = segments_at[&current]
+                .iter()
+                .copied()
+                .find(|&segment| !used[segment]);
+            let Some(next) = next else {
+                break false;
+            };
+            used[next] = true;
+            let [a, b] = segments[next];
+            current = if a == current { b } else { a };
+        };
+
+        if !closed {
+            open_chains += 1;
+            continue;
+        }
+        let simplified = simplify(chain);
+        if simplified.len() >= 3 {
+            loops.push(simplified);
+        }
+    }
+
+    (loops, open_chains)
+}
+
+fn edge_intersection(mesh: &BuildMesh, edge: [u32; 2], plane: f64) -> DVec2 {
+    let a: DVec3 = mesh.positions[edge[0] as usize];
+    let b: DVec3 = mesh.positions[edge[1] as usize];
+    let t = (plane - a.z) / (b.z - a.z);
+    a.lerp(b, t).truncate()
+}
+
+/// Removes repeated points and points in the middle of straight runs, e.g. where the
+/// diagonal of a quad was cut.
+fn simplify(mut points: Vec<DVec2>) -> Vec<DVec2> {
+    let mut changed = true;
+    while changed && points.len() >= 3 {
+        changed = false;
+        let mut index = 0;
+        while index < points.len() && points.len() >= 3 {
+            let previous = points[(index + points.len() - 1) % points.len()];
+            let current = points[index];
+            let next = points[(index + 1) % points.len()];
+
+            // distance of the point from the line through its neighbours
+            let collinear = (next - previous).perp_dot(current - previous).abs()
+                <= POINT_EPSILON * (next - previous).length();
+            if current.distance(previous) <= POINT_EPSILON || collinear {
+                points.remove(index);
+                changed = true;
+            } else {
+                index += 1;
+            }
+        }
+    }
+    points
+}
+
+/// Marks contours inside an odd number of other contours as holes and orients every
+/// contour accordingly.
+fn classify_contours(loops: Vec<Vec<DVec2>>) -> Vec<Contour> {
+    let depths: Vec<usize> = loops
+        .iter()
+        .enumerate()
+        .map(|(index, points)| {
+            loops
+                .iter()
+                .enumerate()
+                .filter(|(other, other_points)| {
+                    *other != index && contains_point(other_points, points[0])
+                })
+                .count()
+        })
+        .collect();
+
+    loops
+        .into_iter()
+        .zip(depths)
+        .map(|(mut points, depth)| {
+            let is_hole = depth % 2 == 1;
+            if (signed_area(&points) < 0.0) != is_hole {
+                points.reverse();
+            }
+            Contour { points, is_hole }
+        })
+        .collect()
+}
+
+fn signed_area(points: &[DVec2]) -> f64 {
+    let mut area = 0.0;
+    for (index, point) in points.iter().enumerate() {
+        let next = points[(index + 1) % points.len()];
+        area += point.perp_dot(next);
+    }
+    area / 2.0
+}
+
+/// Even-odd test of a point against a closed polygon.
+fn contains_point(polygon: &[DVec2], point: DVec2) -> bool {
+    let mut inside = false;
+    let mut previous = polygon[polygon.len() - 1];
+    for &current in polygon {
+        if (current.y > point.y) != (previous.y > point.y) {
+            let x = previous.x
+                + (point.y - previous.y) / (current.y - previous.y) * (current.x - previous.x);
+            if point.x < x {
+                inside = !inside;
+            }
+        }
+        previous = current;
+    }
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::open_model_parts_from_test_resource;
+
+    fn square(min: f64, max: f64) -> Vec<DVec2> {
+        vec![
+            DVec2::new(min, min),
+            DVec2::new(max, min),
+            DVec2::new(max, max),
+            DVec2::new(min, max),
+        ]
+    }
+
+    #[test]
+    fn test_box_gives_identical_rectangles() {
+        let model_parts = open_model_parts_from_test_resource("box.3mf");
+
+        for layer_height in [1.0, 0.2, 0.3] {
+            let settings = SliceSettings { layer_height };
+            let layers = slice_model(&model_parts, &settings).unwrap();
+
+            assert!(
+                layers.len() == (30.0 / layer_height).round() as usize,
+                "{} layers for a layer height of {}",
+                layers.len(),
+                layer_height
+            );
+            let first = &layers[0].contours;
+            assert!(
+                first.len() == 1 && first[0].points.len() == 4 && !first[0].is_hole,
+                "Layer of the box is not a single rectangle: {:?}",
+                first
+            );
+            assert!(
+                (layers[0].area() - 200.0).abs() < 1e-9,
+                "Area of the rectangle is {} instead of 200",
+                layers[0].area()
+            );
+            assert!(
+                layers.iter().all(|layer| &layer.contours == first),
+                "Layers of the box are not identical"
+            );
+            assert!(
+                (layers.last().unwrap().z - 30.0).abs() < 1e-9,
+                "Last layer does not end at the top of the box"
+            );
+        }
+    }
+
+    #[test]
+    fn test_contours_are_oriented_by_nesting() {
+        // given clockwise, the outer square has to be turned around
+        let mut outer = square(0.0, 10.0);
+        outer.reverse();
+        let contours = classify_contours(vec![square(2.0, 4.0), outer]);
+
+        assert!(
+            contours[0].is_hole && contours[0].area() < 0.0,
+            "Inner square is not a clockwise hole"
+        );
+        assert!(
+            !contours[1].is_hole && contours[1].area() > 0.0,
+            "Outer square is not counter clockwise"
+        );
+        let layer = Layer {
+            index: 0,
+            z: 0.2,
+            contours,
+        };
+        assert!(
+            (layer.area() - 96.0).abs() < 1e-9,
+            "Hole is not subtracted from the layer area"
+        );
+    }
+
+    #[test]
+    fn test_collinear_points_are_removed() {
+        let mut points = square(0.0, 2.0);
+        points.insert(1, DVec2::new(1.0, 0.0));
+        points.insert(0, DVec2::new(0.0, 0.0));
+
+        let simplified = simplify(points);
+        assert!(
+            simplified.len() == 4
+                && square(0.0, 2.0)
+                    .iter()
+                    .all(|corner| simplified.contains(corner)),
+            "Collinear and repeated points are not removed: {:?}",
+            simplified
+        );
+    }
+
+    #[test]
+    fn test_offset_shrinks_outer_contours_and_grows_holes() {
+        let outer = Contour {
+            points: square(0.0, 10.0),
+            is_hole: false,
+        };
+        let mut hole_points = square(4.0, 6.0);
+        hole_points.reverse();
+        let hole = Contour {
+            points: hole_points,
+            is_hole: true,
+        };
+
+        let shrunk = outer.offset(1.0).unwrap();
+        assert!(
+            shrunk
+                .points
+                .iter()
+                .zip(square(1.0, 9.0))
+                .all(|(point, expected)| point.distance(expected) < 1e-9),
+            "Outer square is not shrunk by the distance: {:?}",
+            shrunk.points
+        );
+        assert!(
+            (hole.offset(1.0).unwrap().area() + 16.0).abs() < 1e-9,
+            "Hole is not grown by the distance"
+        );
+        assert!(outer.offset(5.5).is_none(), "Outer square did not collapse");
+    }
+
+    #[test]
+    fn test_error_returned_when_layer_height_is_not_positive() {
+        let model_parts = open_model_parts_from_test_resource("box.3mf");
+        let settings = SliceSettings { layer_height: 0.0 };
+        assert!(
+            slice_model(&model_parts, &settings).is_err(),
+            "Layer height of 0 was accepted"
+        );
+    }
+}
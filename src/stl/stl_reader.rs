@@ -0,0 +1,412 @@
+use anyhow::{anyhow, Result};
+use std::{collections::HashMap, io};
+use threemf::model::{
+    Build, Item, Mesh, Model, Object, ObjectData, Resources, Triangle, Triangles, Unit, Vertex,
+    Vertices,
+};
+
+const BINARY_HEADER_SIZE: usize = 80;
+const BINARY_FACET_SIZE: usize = 50;
+const CORE_NAMESPACE: &str = "http://schemas.microsoft.com/3dmanufacturing/core/2015/02";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StlFormat {
+    Binary,
+    Ascii,
+}
+
+/// Summary of an imported STL file, shown in place of the XML tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StlInfo {
+    pub format: StlFormat,
+    pub solids: Vec<StlSolidInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StlSolidInfo {
+    pub name: String,
+    pub facet_count: usize,
+    /// Vertices left after coincident facet corners were welded together.
+    pub vertex_count: usize,
+}
+
+/// Reads a binary or ASCII STL file into a 3MF model with one object and build item
+/// per solid. STL has no unit so millimeters are assumed.
+pub fn load_stl_get_model<R: io::Read>(mut reader: R) -> Result<(Model, StlInfo)> {
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data)?;
+    get_model_from_stl_bytes(&data)
+}
+
+pub fn get_model_from_stl_bytes(data: &[u8]) -> Result<(Model, StlInfo)> {
+    let format = detect_stl_format(data)?;
+    let solids = match format {
+        StlFormat::Binary => vec![read_binary_stl(data)?],
+        StlFormat::Ascii => read_ascii_stl(data)?,
+    };
+
+    let mut objects = Vec::with_capacity(solids.len());
+    let mut items = Vec::with_capacity(solids.len());
+    let mut infos = Vec::with_capacity(solids.len());
+    for (index, solid) in solids.into_iter().enumerate() {
+        let id = index + 1;
+        let mesh = weld_facets(&solid.facets);
+
+        infos.push(StlSolidInfo {
+            name: solid.name.clone(),
+            facet_count: solid.facets.len(),
+            vertex_count: mesh.vertices.vertex.len(),
+        });
+        objects.push(Object {
+            id,
+            partnumber: None,
+            name: (!solid.name.is_empty()).then_some(solid.name),
+            pid: None,
+            object: ObjectData::Mesh(mesh),
+        });
+        items.push(Item {
+            objectid: id,
+            transform: None,
+            partnumber: None,
+        });
+    }
+
+    let model = Model {
+        xmlns: CORE_NAMESPACE.to_string(),
+        metadata: Vec::new(),
+        resources: Resources {
+            object: objects,
+            basematerials: None,
+        },
+        build: Build { item: items },
+        unit: Unit::Millimeter,
+    };
+
+    Ok((
+        model,
+        StlInfo {
+            format,
+            solids: infos,
+        },
+    ))
+}
+
+/// Binary files are recognised by their facet count matching the file size, because
+/// many exporters also start the binary header with `solid`.
+pub fn detect_stl_format(data: &[u8]) -> Result<StlFormat> {
+    if let Some(facet_count) = binary_facet_count(data) {
+        if data.len() == BINARY_HEADER_SIZE + 4 + facet_count * BINARY_FACET_SIZE {
+            return Ok(StlFormat::Binary);
+        }
+    }
+
+    let text_start = data
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(data.len());
+    if data[text_start..].starts_with(b"solid") {
+        return Ok(StlFormat::Ascii);
+    }
+
+    if binary_facet_count(data).is_some() {
+        Ok(StlFormat::Binary)
+    } else {
+        Err(anyhow!("File is neither a binary nor an ASCII STL"))
+    }
+}
+
+struct Solid {
+    name: String,
+    facets: Vec<[[f32; 3]; 3]>,
+}
+
+fn binary_facet_count(data: &[u8]) -> Option<usize> {
+    let count = data.get(BINARY_HEADER_SIZE..BINARY_HEADER_SIZE + 4)?;
+    Some(u32::from_le_bytes([count[0], count[1], count[2], count[3]]) as usize)
+}
+
+fn read_binary_stl(data: &[u8]) -> Result<Solid> {
+    let facet_count =
+        binary_facet_count(data).ok_or_else(|| anyhow!("Binary STL header is truncated"))?;
+    let expected_size = BINARY_HEADER_SIZE + 4 + facet_count * BINARY_FACET_SIZE;
+    if data.len() < expected_size {
+        return Err(anyhow!(
+            "Binary STL declares {} facets but only has data for {}",
+            facet_count,
+            (data.len().saturating_sub(BINARY_HEADER_SIZE + 4)) / BINARY_FACET_SIZE
+        ));
+    }
+
+    let read_f32 = |offset: usize| {
+        f32::from_le_bytes([
+            data[offset],
+            data[offset + 1],
+            data[offset + 2],
+            data[offset + 3],
+        ])
+    };
+
+    let mut facets = Vec::with_capacity(facet_count);
+    for facet in 0..facet_count {
+        // skip the facet normal, it is recomputed from the winding when needed
+        let offset = BINARY_HEADER_SIZE + 4 + facet * BINARY_FACET_SIZE + 12;
+        let mut corners = [[0.0; 3]; 3];
+        for (corner, position) in corners.iter_mut().enumerate() {
+            for (axis, value) in position.iter_mut().enumerate() {
+                *value = read_f32(offset + corner * 12 + axis * 4);
+            }
+        }
+        facets.push(corners);
+    }
+
+    let name = String::from_utf8_lossy(&data[..BINARY_HEADER_SIZE])
+        .trim_end_matches('\0')
+        .trim()
+        .to_string();
+
+    Ok(Solid { name, facets })
+}
+
+fn read_ascii_stl(data: &[u8]) -> Result<Vec<Solid>> {
+    let text = std::str::from_utf8(data).map_err(|_| anyhow!("ASCII STL is not valid UTF-8"))?;
+
+    let mut solids = Vec::new();
+    let mut current: Option<Solid> = None;
+    let mut corners: Vec<[f32; 3]> = Vec::with_capacity(3);
+
+    for (line_index, line) in text.lines().enumerate() {
+        let line_number = line_index + 1;
+        let mut tokens = line.split_whitespace();
+        match tokens.next() {
+            Some("solid") => {
+                if current.is_some() {
+                    return Err(anyhow!("Line {}: solid is not closed", line_number));
+                }
+                current = Some(Solid {
+                    name: tokens.collect::<Vec<_>>().join(" "),
+                    facets: Vec::new(),
+                });
+            }
+            Some("facet") => corners.clear(),
+            Some("vertex") => {
+                let mut position = [0.0; 3];
+                for value in position.iter_mut() {
+                    *value = tokens
+                        .next()
+                        .and_then(|token| token.parse::<f32>().ok())
+                        .ok_or_else(|| anyhow!("Line {}: invalid vertex", line_number))?;
+                }
+                corners.push(position);
+            }
+            Some("endfacet") => {
+                let solid = current
+                    .as_mut()
+                    .ok_or_else(|| anyhow!("Line {}: facet outside of a solid", line_number))?;
+                if corners.len() != 3 {
+                    return Err(anyhow!(
+                        "Line {}: facet has {} vertices instead of 3",
+                        line_number,
+                        corners.len()
+                    ));
+                }
+                solid.facets.push([corners[0], corners[1], corners[2]]);
+            }
+            Some("endsolid") => {
+                let solid = current
+                    .take()
+                    .ok_or_else(|| anyhow!("Line {}: endsolid without solid", line_number))?;
+                solids.push(solid);
+            }
+            Some("outer") | Some("endloop") | None => {}
+            Some(token) => {
+                return Err(anyhow!(
+                    "Line {}: unexpected keyword {}",
+                    line_number,
+                    token
+                ))
+            }
+        }
+    }
+
+    // be lenient with files that end without `endsolid`
+    if let Some(solid) = current {
+        solids.push(solid);
+    }
+    if solids.is_empty() {
+        return Err(anyhow!("ASCII STL contains no solid"));
+    }
+
+    Ok(solids)
+}
+
+/// Merges facet corners with identical coordinates into shared vertices.
+fn weld_facets(facets: &[[[f32; 3]; 3]]) -> Mesh {
+    let mut indices: HashMap<[u32; 3], usize> = HashMap::new();
+    let mut vertices = Vec::new();
+    let mut triangles = Vec::with_capacity(facets.len());
+
+    for facet in facets {
+        let mut triangle = [0; 3];
+        for (corner, position) in triangle.iter_mut().zip(facet) {
+            // +0.0 and -0.0 are the same point
+            let key = position.map(|value| (value + 0.0).to_bits());
+            *corner = *indices.entry(key).or_insert_with(|| {
+                vertices.push(Vertex {
+                    x: position[0] as f64,
+                    y: position[1] as f64,
+                    z: position[2] as f64,
+                });
+                vertices.len() - 1
+            });
+        }
+        triangles.push(Triangle {
+            v1: triangle[0],
+            v2: triangle[1],
+            v3: triangle[2],
+        });
+    }
+
+    Mesh {
+        vertices: Vertices { vertex: vertices },
+        triangles: Triangles {
+            triangle: triangles,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_support::{root_element_name, write_and_read_package},
+        threemf::{
+            threemf_reader::{get_model_from_3mf_model_file_string, ModelParts},
+            threemf_writer,
+        },
+    };
+    const CUBE_FACETS: [[[f32; 3]; 3]; 12] = [
+        [[0.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0]],
+        [[0.0, 0.0, 0.0], [1.0, 1.0, 0.0], [1.0, 0.0, 0.0]],
+        [[0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [1.0, 1.0, 1.0]],
+        [[0.0, 0.0, 1.0], [1.0, 1.0, 1.0], [0.0, 1.0, 1.0]],
+        [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 0.0, 1.0]],
+        [[0.0, 0.0, 0.0], [1.0, 0.0, 1.0], [0.0, 0.0, 1.0]],
+        [[1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [1.0, 1.0, 1.0]],
+        [[1.0, 0.0, 0.0], [1.0, 1.0, 1.0], [1.0, 0.0, 1.0]],
+        [[1.0, 1.0, 0.0], [0.0, 1.0, 0.0], [0.0, 1.0, 1.0]],
+        [[1.0, 1.0, 0.0], [0.0, 1.0, 1.0], [1.0, 1.0, 1.0]],
+        [[0.0, 1.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 1.0]],
+        [[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [-0.0, 1.0, 1.0]],
+    ];
+
+    fn binary_cube(header: &[u8]) -> Vec<u8> {
+        let mut data = vec![0; BINARY_HEADER_SIZE];
+        data[..header.len()].copy_from_slice(header);
+        data.extend_from_slice(&(CUBE_FACETS.len() as u32).to_le_bytes());
+        for facet in CUBE_FACETS {
+            data.extend_from_slice(&[0; 12]);
+            for value in facet.iter().flatten() {
+                data.extend_from_slice(&value.to_le_bytes());
+            }
+            data.extend_from_slice(&[0; 2]);
+        }
+        data
+    }
+
+    fn ascii_solid(name: &str) -> String {
+        let mut text = format!("solid {}\n", name);
+        for facet in CUBE_FACETS {
+            text.push_str("  facet normal 0 0 0\n    outer loop\n");
+            for [x, y, z] in facet {
+                text.push_str(&format!("      vertex {:e} {:e} {:e}\n", x, y, z));
+            }
+            text.push_str("    endloop\n  endfacet\n");
+        }
+        text.push_str(&format!("endsolid {}\n", name));
+        text
+    }
+
+    fn assert_welded_cube(model: &Model, object_index: usize) {
+        if let ObjectData::Mesh(mesh) = &model.resources.object[object_index].object {
+            assert!(
+                mesh.vertices.vertex.len() == 8,
+                "Coincident vertices are not welded"
+            );
+            assert!(
+                mesh.triangles.triangle.len() == 12,
+                "Number of triangles is wrong"
+            );
+        } else {
+            panic!("Not a mesh data");
+        }
+    }
+
+    #[test]
+    fn test_binary_stl_is_read_and_welded() {
+        let (model, info) = get_model_from_stl_bytes(&binary_cube(b"cube")).unwrap();
+
+        assert!(info.format == StlFormat::Binary, "Format is not detected");
+        assert!(info.solids[0].name == "cube", "Header name is not read");
+        assert!(model.build.item.len() == 1, "Build item is missing");
+        assert_welded_cube(&model, 0);
+    }
+
+    #[test]
+    fn test_binary_stl_starting_with_solid_is_detected() {
+        let data = binary_cube(b"solid exported by a binary writer");
+        assert!(
+            detect_stl_format(&data).unwrap() == StlFormat::Binary,
+            "Binary STL with a solid header is detected as ASCII"
+        );
+    }
+
+    #[test]
+    fn test_ascii_stl_with_multiple_solids() {
+        let text = ascii_solid("first") + &ascii_solid("second");
+        let (model, info) = get_model_from_stl_bytes(text.as_bytes()).unwrap();
+
+        assert!(info.format == StlFormat::Ascii, "Format is not detected");
+        assert!(
+            model.resources.object.len() == 2 && model.build.item.len() == 2,
+            "Every solid should become an object with a build item"
+        );
+        assert!(
+            model.resources.object[1].name.as_deref() == Some("second"),
+            "Solid name is not read"
+        );
+        assert_welded_cube(&model, 1);
+    }
+
+    #[test]
+    fn test_error_returned_when_binary_stl_is_truncated() {
+        let mut data = binary_cube(b"cube");
+        data.truncate(data.len() - 60);
+        assert!(
+            get_model_from_stl_bytes(&data).is_err(),
+            "Truncated binary STL was accepted"
+        );
+    }
+
+    #[test]
+    fn test_error_returned_when_ascii_facet_is_incomplete() {
+        let text = "solid broken\nfacet normal 0 0 1\nouter loop\nvertex 0 0 0\nvertex 1 0 0\nendloop\nendfacet\nendsolid broken\n";
+        assert!(
+            get_model_from_stl_bytes(text.as_bytes()).is_err(),
+            "Facet with two vertices was accepted"
+        );
+    }
+
+    #[test]
+    fn test_imported_stl_is_saved_as_model() {
+        let (model, _) = get_model_from_stl_bytes(&binary_cube(b"cube")).unwrap();
+        let model_parts = ModelParts::from_model("/3D/3dmodel.model", model).unwrap();
+        let package = threemf_writer::new_package_from_model_parts(&model_parts);
+
+        let saved = write_and_read_package(&package);
+        let xml = saved.start_part().unwrap().data_as_string().unwrap();
+
+        let root = root_element_name(&xml);
+        assert!(root == "model", "Root element of the saved STL is {}", root);
+        assert_welded_cube(&get_model_from_3mf_model_file_string(&xml).unwrap(), 0);
+    }
+}
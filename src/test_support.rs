@@ -0,0 +1,125 @@
+use quick_xml::{events::Event, Reader};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    env, fs,
+    fs::File,
+    io::Cursor,
+    path::PathBuf,
+};
+use threemf::model::{Mesh, Triangle, Triangles, Vertex, Vertices};
+
+use crate::threemf::{
+    threemf_package::Package,
+    threemf_reader::{get_model_parts_from_package, load_threemf_package, ModelParts},
+    threemf_writer::write_threemf_package,
+};
+
+pub fn test_resource_path(file_name: &str) -> PathBuf {
+    let root_dir = &env::var("CARGO_MANIFEST_DIR").expect("$CARGO_MANIFEST_DIR");
+    let mut test_file_path = PathBuf::from(root_dir);
+    test_file_path.push("test_resources");
+    test_file_path.push(file_name);
+    test_file_path
+}
+
+pub fn open_file_from_test_resource(file_name: &str) -> File {
+    File::open(test_resource_path(file_name)).unwrap()
+}
+
+pub fn get_file_as_string_from_test_resource(file_name: &str) -> String {
+    fs::read_to_string(test_resource_path(file_name)).unwrap()
+}
+
+pub fn open_model_parts_from_test_resource(file_name: &str) -> ModelParts {
+    let package = load_threemf_package(open_file_from_test_resource(file_name)).unwrap();
+    get_model_parts_from_package(&package).unwrap()
+}
+
+/// Name of the root element of a document, empty when it has none.
+pub fn root_element_name(xml: &str) -> String {
+    let mut reader = Reader::from_str(xml);
+    loop {
+        match reader.read_event().unwrap() {
+            Event::Start(element) | Event::Empty(element) => {
+                return String::from_utf8(element.name().as_ref().to_vec()).unwrap()
+            }
+            Event::Eof => return String::new(),
+            _ => {}
+        }
+    }
+}
+
+/// Writes the package into memory and reads it back, as saving and opening it would.
+pub fn write_and_read_package(package: &Package) -> Package {
+    let mut buffer = Cursor::new(Vec::new());
+    write_threemf_package(package, &mut buffer).unwrap();
+    buffer.set_position(0);
+    load_threemf_package(buffer).unwrap()
+}
+
+/// A closed tetrahedron with outward facing triangles.
+pub fn tetrahedron() -> Mesh {
+    let vertices = [
+        (0.0, 0.0, 0.0),
+        (1.0, 0.0, 0.0),
+        (0.0, 1.0, 0.0),
+        (0.0, 0.0, 1.0),
+    ];
+    let triangles = [(0, 2, 1), (0, 1, 3), (1, 2, 3), (0, 3, 2)];
+    Mesh {
+        vertices: Vertices {
+            vertex: vertices
+                .iter()
+                .map(|&(x, y, z)| Vertex { x, y, z })
+                .collect(),
+        },
+        triangles: Triangles {
+            triangle: triangles
+                .iter()
+                .map(|&(v1, v2, v3)| Triangle { v1, v2, v3 })
+                .collect(),
+        },
+    }
+}
+
+thread_local! {
+    /// Bytes allocated and not freed yet by the thread.
+    static ALLOCATED: Cell<isize> = const { Cell::new(0) };
+}
+
+/// The system allocator, counting what each thread allocates.
+struct CountingAllocator;
+
+fn count_allocated(bytes: isize) {
+    // the count is gone while the thread exits
+    let _ = ALLOCATED.try_with(|allocated| allocated.set(allocated.get() + bytes));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count_allocated(layout.size() as isize);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        count_allocated(-(layout.size() as isize));
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count_allocated(new_size as isize - layout.size() as isize);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Runs `f` and returns what it returns with the bytes it allocated and did not free.
+pub fn allocated_by<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATED.with(Cell::get);
+    let result = f();
+    let allocated = ALLOCATED.with(Cell::get) - before;
+    (result, allocated.max(0) as usize)
+}
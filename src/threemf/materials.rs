@@ -0,0 +1,533 @@
+use anyhow::{anyhow, Result};
+use quick_xml::{events::Event, Reader};
+use std::collections::{BTreeMap, HashMap};
+
+use super::threemf_package::{resolve_target, Package};
+
+/// A `base` of a `basematerials` group.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BaseMaterial {
+    pub name: String,
+    /// Linear RGBA between 0 and 1.
+    pub display_color: [f32; 4],
+}
+
+/// A resource properties can point into with a `pid` and an index.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyGroup {
+    BaseMaterials(Vec<BaseMaterial>),
+    /// A `m:colorgroup`, RGBA between 0 and 1.
+    Colors(Vec<[f32; 4]>),
+    /// A `m:texture2dgroup` of `u v` coordinates into a `m:texture2d`.
+    Texture2DGroup {
+        texture_id: usize,
+        coordinates: Vec<[f64; 2]>,
+    },
+}
+
+/// Decoded pixels of a texture, rows from the top.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextureImage {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<[u8; 4]>,
+}
+
+impl TextureImage {
+    /// Colour of the pixel at texture coordinates, which start at the bottom left and
+    /// repeat outside of 0 to 1. An image without pixels has no colour.
+    pub fn sample(&self, [u, v]: [f64; 2]) -> Option<[f32; 3]> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+        let x = (u.rem_euclid(1.0) * self.width as f64) as u32;
+        let y = ((1.0 - v.rem_euclid(1.0)) * self.height as f64) as u32;
+        let index = y.min(self.height - 1) * self.width + x.min(self.width - 1);
+        let [r, g, b, _] = self.pixels.get(index as usize)?;
+        Some([r, g, b].map(|&channel| channel as f32 / 255.0))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Texture2D {
+    /// Absolute name of the part with the image.
+    pub path: String,
+    pub content_type: String,
+    /// Pixels of the image once loaded from the package.
+    pub image: Option<TextureImage>,
+}
+
+/// `pid`, `p1`, `p2` and `p3` of a triangle.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TriangleProperties {
+    pub pid: Option<usize>,
+    pub p1: Option<usize>,
+    pub p2: Option<usize>,
+    pub p3: Option<usize>,
+}
+
+impl TriangleProperties {
+    fn is_empty(&self) -> bool {
+        *self == TriangleProperties::default()
+    }
+}
+
+/// Properties of a mesh object and its triangles.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ObjectProperties {
+    pub pid: Option<usize>,
+    pub pindex: Option<usize>,
+    /// One entry per triangle of the mesh, in document order.
+    pub triangles: Vec<TriangleProperties>,
+}
+
+/// Materials and properties extension resources of a model part, which the `threemf`
+/// model does not keep.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Materials {
+    pub property_groups: BTreeMap<usize, PropertyGroup>,
+    pub textures: BTreeMap<usize, Texture2D>,
+    pub objects: HashMap<usize, ObjectProperties>,
+}
+
+impl Materials {
+    /// Whether triangles of the object have properties of their own.
+    pub fn has_triangle_properties(&self, object_id: usize) -> bool {
+        self.objects
+            .get(&object_id)
+            .is_some_and(|object| object.triangles.iter().any(|t| !t.is_empty()))
+    }
+
+    /// Colour of an object given by its `pid` and `pindex`.
+    pub fn object_color(&self, object_id: usize) -> Option<[f32; 3]> {
+        let object = self.objects.get(&object_id)?;
+        self.property_color(object.pid?, object.pindex?)
+    }
+
+    /// Colours of the corners of a triangle with properties of its own. Triangles
+    /// without them have the colour of their object.
+    ///
+    /// Properties of groups that are not supported, like composite materials, give
+    /// `None` as well.
+    pub fn triangle_colors(&self, object_id: usize, triangle: usize) -> Option<[[f32; 3]; 3]> {
+        let (pid, [p1, p2, p3]) = self.triangle_properties(object_id, triangle)?;
+        Some([
+            self.property_color(pid, p1)?,
+            self.property_color(pid, p2)?,
+            self.property_color(pid, p3)?,
+        ])
+    }
+
+    /// Texture id and the texture coordinates of the corners of a triangle whose
+    /// properties are in a texture group. Textures without a loaded image give `None`.
+    pub fn triangle_texture(
+        &self,
+        object_id: usize,
+        triangle: usize,
+    ) -> Option<(usize, [[f64; 2]; 3])> {
+        let (pid, [p1, p2, p3]) = self.triangle_properties(object_id, triangle)?;
+        let PropertyGroup::Texture2DGroup {
+            texture_id,
+            coordinates,
+        } = self.property_groups.get(&pid)?
+        else {
+            return None;
+        };
+        self.textures.get(texture_id)?.image.as_ref()?;
+        Some((
+            *texture_id,
+            [
+                *coordinates.get(p1)?,
+                *coordinates.get(p2)?,
+                *coordinates.get(p3)?,
+            ],
+        ))
+    }
+
+    /// Property group and the indices into it of the corners of a triangle with
+    /// properties of its own.
+    fn triangle_properties(
+        &self,
+        object_id: usize,
+        triangle: usize,
+    ) -> Option<(usize, [usize; 3])> {
+        let object = self.objects.get(&object_id)?;
+        let properties = object.triangles.get(triangle)?;
+        if properties.is_empty() {
+            return None;
+        }
+
+        let pid = properties.pid.or(object.pid)?;
+        let p1 = properties.p1.or(object.pindex)?;
+        let p2 = properties.p2.unwrap_or(p1);
+        let p3 = properties.p3.unwrap_or(p1);
+        Some((pid, [p1, p2, p3]))
+    }
+
+    fn property_color(&self, pid: usize, index: usize) -> Option<[f32; 3]> {
+        let rgb = |[r, g, b, _]: [f32; 4]| [r, g, b];
+        match self.property_groups.get(&pid)? {
+            PropertyGroup::BaseMaterials(materials) => {
+                Some(rgb(materials.get(index)?.display_color))
+            }
+            PropertyGroup::Colors(colors) => Some(rgb(*colors.get(index)?)),
+            PropertyGroup::Texture2DGroup {
+                texture_id,
+                coordinates,
+            } => {
+                let image = self.textures.get(texture_id)?.image.as_ref()?;
+                image.sample(*coordinates.get(index)?)
+            }
+        }
+    }
+
+    /// Decodes the images of the textures from the package. Images that are missing, empty
+    /// or cannot be decoded are logged and left out, those triangles keep their object
+    /// colour.
+    pub fn load_textures(&mut self, package: &Package) {
+        for (id, texture) in &mut self.textures {
+            let Some(part) = package.part(&texture.path) else {
+                log::warn!("Image {} of texture {} is missing", texture.path, id);
+                continue;
+            };
+            match image::load_from_memory(&part.data) {
+                Ok(image) if image.width() == 0 || image.height() == 0 => {
+                    log::warn!("Image {} of texture {} is empty", texture.path, id)
+                }
+                Ok(image) => {
+                    let image = image.to_rgba8();
+                    texture.image = Some(TextureImage {
+                        width: image.width(),
+                        height: image.height(),
+                        pixels: image.pixels().map(|pixel| pixel.0).collect(),
+                    });
+                }
+                Err(e) => log::warn!(
+                    "Image {} of texture {} could not be decoded: {}",
+                    texture.path,
+                    id,
+                    e
+                ),
+            }
+        }
+    }
+}
+
+/// Reads `#RRGGBB` or `#RRGGBBAA` into RGBA between 0 and 1.
+pub fn parse_color(text: &str) -> Option<[f32; 4]> {
+    let hex = text.trim().strip_prefix('#')?;
+    if !(hex.len() == 6 || hex.len() == 8) || !hex.is_ascii() {
+        return None;
+    }
+    let mut color = [1.0; 4];
+    for (index, channel) in color.iter_mut().take(hex.len() / 2).enumerate() {
+        *channel = u8::from_str_radix(&hex[index * 2..index * 2 + 2], 16).ok()? as f32 / 255.0;
+    }
+    Some(color)
+}
+
+/// Writes an RGB colour between 0 and 1 as `#RRGGBB`, the inverse of [`parse_color`].
+pub fn format_color(color: [f32; 3]) -> String {
+    color.iter().fold(String::from("#"), |text, channel| {
+        text + &format!("{:02X}", (channel.clamp(0.0, 1.0) * 255.0).round() as u8)
+    })
+}
+
+/// Reads the material resources of a model part and the properties of its objects and
+/// triangles. Texture paths are resolved to absolute part names relative to `part_name`,
+/// their images are not loaded.
+pub fn get_materials_from_3mf_model_file_string(
+    part_name: &str,
+    xml_content: &str,
+) -> Result<Materials> {
+    let mut reader = Reader::from_str(xml_content);
+    let mut materials = Materials::default();
+    let mut current_group = None;
+    let mut current_object = None;
+
+    loop {
+        let (start, element) = match reader.read_event()? {
+            Event::Start(element) => (true, element),
+            Event::Empty(element) => (false, element),
+            Event::End(element) => {
+                match element.local_name().as_ref() {
+                    b"basematerials" | b"colorgroup" | b"texture2dgroup" => current_group = None,
+                    b"object" => current_object = None,
+                    _ => {}
+                }
+                continue;
+            }
+            Event::Eof => break,
+            _ => continue,
+        };
+
+        let mut attributes: HashMap<Vec<u8>, String> = HashMap::new();
+        for attribute in element.attributes() {
+            let attribute = attribute?;
+            attributes.insert(
+                attribute.key.local_name().as_ref().to_vec(),
+                attribute.unescape_value()?.to_string(),
+            );
+        }
+        let text = |name: &[u8]| attributes.get(name).map(String::as_str);
+        let index = |name: &[u8]| -> Result<Option<usize>> {
+            text(name)
+                .map(|value| {
+                    value.trim().parse::<usize>().map_err(|_| {
+                        anyhow!(
+                            "Invalid {} {:?} in {}",
+                            String::from_utf8_lossy(name),
+                            value,
+                            part_name
+                        )
+                    })
+                })
+                .transpose()
+        };
+        let name = element.local_name();
+
+        match name.as_ref() {
+            b"basematerials" | b"colorgroup" | b"texture2dgroup" => {
+                let id = index(b"id")?
+                    .ok_or_else(|| anyhow!("Property group without an id in {}", part_name))?;
+                let group = match name.as_ref() {
+                    b"basematerials" => PropertyGroup::BaseMaterials(Vec::new()),
+                    b"colorgroup" => PropertyGroup::Colors(Vec::new()),
+                    _ => PropertyGroup::Texture2DGroup {
+                        texture_id: index(b"texid")?.ok_or_else(|| {
+                            anyhow!("Texture group {} without a texid in {}", id, part_name)
+                        })?,
+                        coordinates: Vec::new(),
+                    },
+                };
+                materials.property_groups.insert(id, group);
+                if start {
+                    current_group = Some(id);
+                }
+            }
+            b"base" | b"color" | b"tex2coord" => {
+                let Some(group) =
+                    current_group.and_then(|id| materials.property_groups.get_mut(&id))
+                else {
+                    continue;
+                };
+                match (group, name.as_ref()) {
+                    (PropertyGroup::BaseMaterials(group), b"base") => {
+                        let color = text(b"displaycolor").unwrap_or_default();
+                        group.push(BaseMaterial {
+                            name: text(b"name").unwrap_or_default().to_string(),
+                            display_color: parse_color(color).ok_or_else(|| {
+                                anyhow!("Invalid displaycolor {:?} in {}", color, part_name)
+                            })?,
+                        });
+                    }
+                    (PropertyGroup::Colors(group), b"color") => {
+                        let color = text(b"color").unwrap_or_default();
+                        group.push(parse_color(color).ok_or_else(|| {
+                            anyhow!("Invalid color {:?} in {}", color, part_name)
+                        })?);
+                    }
+                    (PropertyGroup::Texture2DGroup { coordinates, .. }, b"tex2coord") => {
+                        let coordinate = |axis: &[u8]| -> Result<f64> {
+                            let value = text(axis).unwrap_or_default();
+                            value.trim().parse().map_err(|_| {
+                                anyhow!("Invalid texture coordinate {:?} in {}", value, part_name)
+                            })
+                        };
+                        coordinates.push([coordinate(b"u")?, coordinate(b"v")?]);
+                    }
+                    _ => {}
+                }
+            }
+            b"texture2d" => {
+                let id = index(b"id")?
+                    .ok_or_else(|| anyhow!("Texture without an id in {}", part_name))?;
+                let path = text(b"path")
+                    .ok_or_else(|| anyhow!("Texture {} without a path in {}", id, part_name))?;
+                materials.textures.insert(
+                    id,
+                    Texture2D {
+                        path: resolve_target(part_name, path),
+                        content_type: text(b"contenttype").unwrap_or_default().to_string(),
+                        image: None,
+                    },
+                );
+            }
+            b"object" => {
+                let id = index(b"id")?
+                    .ok_or_else(|| anyhow!("Object without an id in {}", part_name))?;
+                materials.objects.insert(
+                    id,
+                    ObjectProperties {
+                        pid: index(b"pid")?,
+                        pindex: index(b"pindex")?,
+                        triangles: Vec::new(),
+                    },
+                );
+                if start {
+                    current_object = Some(id);
+                }
+            }
+            b"triangle" => {
+                if let Some(object) = current_object.and_then(|id| materials.objects.get_mut(&id)) {
+                    object.triangles.push(TriangleProperties {
+                        pid: index(b"pid")?,
+                        p1: index(b"p1")?,
+                        p2: index(b"p2")?,
+                        p3: index(b"p3")?,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(materials)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MATERIAL_MODEL: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<model unit="millimeter" xmlns="http://schemas.microsoft.com/3dmanufacturing/core/2015/02"
+  xmlns:m="http://schemas.microsoft.com/3dmanufacturing/material/2015/02">
+  <resources>
+    <basematerials id="1">
+      <base name="Red PLA" displaycolor="#FF0000" />
+      <base name="Blue PLA" displaycolor="#0000FFFF" />
+    </basematerials>
+    <m:colorgroup id="2">
+      <m:color color="#00FF00" />
+      <m:color color="#FFFFFF80" />
+    </m:colorgroup>
+    <m:texture2d id="3" path="/3D/Texture/checker.png" contenttype="image/png" />
+    <m:texture2dgroup id="4" texid="3">
+      <m:tex2coord u="0.25" v="0.25" />
+      <m:tex2coord u="0.75" v="0.75" />
+    </m:texture2dgroup>
+    <object id="5" type="model" pid="1" pindex="1">
+      <mesh>
+        <vertices>
+          <vertex x="0" y="0" z="0" />
+          <vertex x="1" y="0" z="0" />
+          <vertex x="0" y="1" z="0" />
+        </vertices>
+        <triangles>
+          <triangle v1="0" v2="1" v3="2" />
+          <triangle v1="0" v2="2" v3="1" p1="0" />
+          <triangle v1="0" v2="1" v3="2" pid="2" p1="0" p2="1" />
+          <triangle v1="0" v2="2" v3="1" pid="4" p1="0" p2="1" p3="1" />
+        </triangles>
+      </mesh>
+    </object>
+  </resources>
+  <build>
+    <item objectid="5" />
+  </build>
+</model>"##;
+
+    #[test]
+    fn test_property_groups_are_read() {
+        let materials =
+            get_materials_from_3mf_model_file_string("/3D/3dmodel.model", MATERIAL_MODEL).unwrap();
+
+        assert!(
+            materials.property_groups[&1]
+                == PropertyGroup::BaseMaterials(vec![
+                    BaseMaterial {
+                        name: "Red PLA".to_string(),
+                        display_color: [1.0, 0.0, 0.0, 1.0],
+                    },
+                    BaseMaterial {
+                        name: "Blue PLA".to_string(),
+                        display_color: [0.0, 0.0, 1.0, 1.0],
+                    },
+                ]),
+            "Base materials are wrong: {:?}",
+            materials.property_groups[&1]
+        );
+        assert!(
+            matches!(&materials.property_groups[&2], PropertyGroup::Colors(colors)
+                if colors.len() == 2 && (colors[1][3] - 128.0 / 255.0).abs() < 1e-6),
+            "Colour group is wrong: {:?}",
+            materials.property_groups[&2]
+        );
+        assert!(
+            materials.textures[&3].path == "/3D/Texture/checker.png",
+            "Texture path is wrong"
+        );
+        assert!(
+            materials.objects[&5].triangles.len() == 4,
+            "Triangle properties are not kept in document order"
+        );
+    }
+
+    #[test]
+    fn test_triangle_properties_are_resolved() {
+        let mut materials =
+            get_materials_from_3mf_model_file_string("/3D/3dmodel.model", MATERIAL_MODEL).unwrap();
+        // left half black, right half white
+        materials.textures.get_mut(&3).unwrap().image = Some(TextureImage {
+            width: 2,
+            height: 1,
+            pixels: vec![[0, 0, 0, 255], [255, 255, 255, 255]],
+        });
+
+        assert!(
+            materials.object_color(5) == Some([0.0, 0.0, 1.0]),
+            "Object colour does not come from its pid and pindex"
+        );
+        assert!(
+            materials.triangle_colors(5, 0).is_none(),
+            "Triangle without properties does not use the object colour"
+        );
+        assert!(
+            materials.triangle_colors(5, 1) == Some([[1.0, 0.0, 0.0]; 3]),
+            "p1 alone does not index the group of the object"
+        );
+        assert!(
+            materials.triangle_colors(5, 2)
+                == Some([[0.0, 1.0, 0.0], [1.0, 1.0, 1.0], [0.0, 1.0, 0.0]]),
+            "Missing p3 does not default to p1"
+        );
+        assert!(
+            materials.triangle_colors(5, 3)
+                == Some([[0.0, 0.0, 0.0], [1.0, 1.0, 1.0], [1.0, 1.0, 1.0]]),
+            "Texture is not sampled at the coordinates"
+        );
+        assert!(
+            materials.triangle_texture(5, 3)
+                == Some((3, [[0.25, 0.25], [0.75, 0.75], [0.75, 0.75]])),
+            "Texture coordinates of the corners are wrong: {:?}",
+            materials.triangle_texture(5, 3)
+        );
+        assert!(
+            materials.triangle_texture(5, 2).is_none(),
+            "Triangle of a colour group has a texture"
+        );
+    }
+
+    #[test]
+    fn test_empty_image_has_no_colour() {
+        let image = TextureImage {
+            width: 0,
+            height: 0,
+            pixels: Vec::new(),
+        };
+        assert!(
+            image.sample([0.5, 0.5]).is_none(),
+            "Empty image has a colour"
+        );
+    }
+
+    #[test]
+    fn test_invalid_color_is_an_error() {
+        assert!(parse_color("#12345").is_none(), "Short colour was accepted");
+        let xml = MATERIAL_MODEL.replace("#00FF00", "green");
+        assert!(
+            get_materials_from_3mf_model_file_string("/3D/3dmodel.model", &xml).is_err(),
+            "Colour name was accepted"
+        );
+    }
+}
@@ -1 +1,5 @@
+pub mod materials;
+pub mod threemf_package;
 pub mod threemf_reader;
+pub mod threemf_writer;
+pub mod xml_edit;
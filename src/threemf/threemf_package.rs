@@ -0,0 +1,618 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    io::{self, Read},
+};
+
+use quick_xml::{de::from_str, escape::escape};
+use serde::Deserialize;
+use zip::{result::ZipError, ZipArchive};
+
+pub const CONTENT_TYPES_PART: &str = "/[Content_Types].xml";
+pub const ROOT_RELATIONSHIPS_PART: &str = "/_rels/.rels";
+
+pub const RELATIONSHIPS_CONTENT_TYPE: &str =
+    "application/vnd.openxmlformats-package.relationships+xml";
+pub const MODEL_CONTENT_TYPE: &str = "application/vnd.ms-package.3dmanufacturing-3dmodel+xml";
+
+/// Relationship type pointing from the package to the root 3D model part.
+pub const START_PART_RELATIONSHIP_TYPE: &str =
+    "http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel";
+/// Relationship type pointing from the package to its thumbnail image.
+pub const THUMBNAIL_RELATIONSHIP_TYPE: &str =
+    "http://schemas.openxmlformats.org/package/2006/relationships/metadata/thumbnail";
+
+/// Errors returned when a 3MF file is not a well formed OPC package.
+#[derive(Debug)]
+pub enum PackageError {
+    Io(io::Error),
+    Zip(ZipError),
+    /// A part required by the package structure does not exist.
+    MissingPart(String),
+    /// The package has no relationship to a 3D model part.
+    MissingStartPart,
+    /// A package level XML part could not be parsed.
+    InvalidXml {
+        part: String,
+        message: String,
+    },
+    /// A part expected to contain text is not UTF-8.
+    NotUtf8(String),
+}
+
+impl fmt::Display for PackageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PackageError::Io(e) => write!(f, "Failed to read package: {}", e),
+            PackageError::Zip(e) => write!(f, "Package is not a valid zip archive: {}", e),
+            PackageError::MissingPart(part) => write!(f, "Package part {} is missing", part),
+            PackageError::MissingStartPart => {
+                write!(f, "Package has no relationship to a 3D model part")
+            }
+            PackageError::InvalidXml { part, message } => {
+                write!(f, "Package part {} is malformed: {}", part, message)
+            }
+            PackageError::NotUtf8(part) => write!(f, "Package part {} is not UTF-8 text", part),
+        }
+    }
+}
+
+impl std::error::Error for PackageError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PackageError::Io(e) => Some(e),
+            PackageError::Zip(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for PackageError {
+    fn from(e: io::Error) -> Self {
+        PackageError::Io(e)
+    }
+}
+
+impl From<ZipError> for PackageError {
+    fn from(e: ZipError) -> Self {
+        PackageError::Zip(e)
+    }
+}
+
+/// A relationship from the package or a part to another part, with the target
+/// already resolved to an absolute part name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Relationship {
+    pub id: String,
+    pub target: String,
+    pub relationship_type: String,
+}
+
+/// A single part of the package.
+#[derive(Debug, Clone)]
+pub struct Part {
+    /// Absolute part name, e.g. `/3D/3dmodel.model`.
+    pub name: String,
+    pub content_type: String,
+    pub data: Vec<u8>,
+    /// Relationships declared by this part in its `_rels/<name>.rels` part.
+    pub relationships: Vec<Relationship>,
+}
+
+impl Part {
+    pub fn data_as_string(&self) -> Result<String, PackageError> {
+        let text = std::str::from_utf8(&self.data)
+            .map_err(|_| PackageError::NotUtf8(self.name.clone()))?;
+        Ok(text.trim_start_matches('\u{feff}').to_string())
+    }
+}
+
+/// An Open Packaging Conventions package as used by 3MF.
+#[derive(Debug, Clone)]
+pub struct Package {
+    parts: BTreeMap<String, Part>,
+    relationships: Vec<Relationship>,
+}
+
+impl Package {
+    /// Reads every part of the package together with its content type and relationships.
+    pub fn from_reader<R: io::Read + io::Seek>(reader: R) -> Result<Self, PackageError> {
+        let mut zip = ZipArchive::new(reader)?;
+
+        let mut raw_parts = BTreeMap::new();
+        for i in 0..zip.len() {
+            let mut file = zip.by_index(i)?;
+            if file.is_dir() {
+                continue;
+            }
+            let mut data = Vec::with_capacity(file.size() as usize);
+            file.read_to_end(&mut data)?;
+            raw_parts.insert(format!("/{}", file.name().trim_start_matches('/')), data);
+        }
+
+        let content_types_data = take_part(&mut raw_parts, CONTENT_TYPES_PART)
+            .ok_or_else(|| PackageError::MissingPart(CONTENT_TYPES_PART.to_string()))?;
+        let content_types = ContentTypes::parse(&content_types_data)?;
+
+        let relationships = match find_part_name(&raw_parts, ROOT_RELATIONSHIPS_PART) {
+            Some(name) => parse_relationships(&name, &raw_parts[&name])?,
+            None => {
+                return Err(PackageError::MissingPart(
+                    ROOT_RELATIONSHIPS_PART.to_string(),
+                ))
+            }
+        };
+
+        let mut part_relationships = BTreeMap::new();
+        for name in raw_parts.keys() {
+            if let Some(rels_name) = find_part_name(&raw_parts, &relationships_part_name(name)) {
+                let relationships = parse_relationships(&rels_name, &raw_parts[&rels_name])?;
+                part_relationships.insert(name.clone(), relationships);
+            }
+        }
+
+        let parts = raw_parts
+            .into_iter()
+            .map(|(name, data)| {
+                let part = Part {
+                    content_type: content_types.content_type_of(&name),
+                    relationships: part_relationships.remove(&name).unwrap_or_default(),
+                    name: name.clone(),
+                    data,
+                };
+                (name, part)
+            })
+            .collect();
+
+        Ok(Self {
+            parts,
+            relationships,
+        })
+    }
+
+    /// Creates a package whose only content is the 3D model part `name`.
+    pub fn with_start_part(name: &str, data: Vec<u8>) -> Self {
+        let relationships = vec![Relationship {
+            id: "rel0".to_string(),
+            target: name.to_string(),
+            relationship_type: START_PART_RELATIONSHIP_TYPE.to_string(),
+        }];
+
+        let mut package = Self {
+            parts: BTreeMap::new(),
+            relationships: relationships.clone(),
+        };
+        package.insert_part(Part {
+            name: ROOT_RELATIONSHIPS_PART.to_string(),
+            content_type: RELATIONSHIPS_CONTENT_TYPE.to_string(),
+            data: relationships_xml(&relationships).into_bytes(),
+            relationships: Vec::new(),
+        });
+        package.insert_part(Part {
+            name: name.to_string(),
+            content_type: MODEL_CONTENT_TYPE.to_string(),
+            data,
+            relationships: Vec::new(),
+        });
+
+        package
+    }
+
+    /// Adds a part, replacing any part with the same name.
+    pub fn insert_part(&mut self, part: Part) {
+        if let Some(existing) = find_part_name(&self.parts, &part.name) {
+            self.parts.remove(&existing);
+        }
+        self.parts.insert(part.name.clone(), part);
+    }
+
+    pub fn part_mut(&mut self, name: &str) -> Option<&mut Part> {
+        find_part_name(&self.parts, name).and_then(move |name| self.parts.get_mut(&name))
+    }
+
+    /// Relationships declared by the package itself in `/_rels/.rels`.
+    pub fn relationships(&self) -> &[Relationship] {
+        &self.relationships
+    }
+
+    /// Every part of the package sorted by name, relationship parts included.
+    pub fn parts(&self) -> impl Iterator<Item = &Part> {
+        self.parts.values()
+    }
+
+    /// Looks up a part by name. Part names are compared case insensitively as required by OPC.
+    pub fn part(&self, name: &str) -> Option<&Part> {
+        find_part_name(&self.parts, name).and_then(|name| self.parts.get(&name))
+    }
+
+    /// The root 3D model part referenced by the package relationships.
+    pub fn start_part(&self) -> Result<&Part, PackageError> {
+        let relationship = self
+            .relationships
+            .iter()
+            .find(|relationship| relationship.relationship_type == START_PART_RELATIONSHIP_TYPE)
+            .ok_or(PackageError::MissingStartPart)?;
+
+        self.part(&relationship.target)
+            .ok_or_else(|| PackageError::MissingPart(relationship.target.clone()))
+    }
+
+    /// The thumbnail image referenced by the package relationships, if it exists.
+    pub fn thumbnail(&self) -> Option<&Part> {
+        let relationship = self
+            .relationships
+            .iter()
+            .find(|relationship| relationship.relationship_type == THUMBNAIL_RELATIONSHIP_TYPE)?;
+        self.part(&relationship.target)
+    }
+}
+
+/// Reads only the package relationships and the thumbnail image they reference, which is
+/// much cheaper than reading the whole package. Returns `None` without a thumbnail.
+pub fn read_thumbnail<R: io::Read + io::Seek>(reader: R) -> Result<Option<Vec<u8>>, PackageError> {
+    let mut zip = ZipArchive::new(reader)?;
+    let entries: BTreeMap<String, String> = zip
+        .file_names()
+        .map(|name| {
+            (
+                format!("/{}", name.trim_start_matches('/')),
+                name.to_string(),
+            )
+        })
+        .collect();
+    let mut read_entry = |name: &str| -> Result<Vec<u8>, PackageError> {
+        let mut file = zip.by_name(&entries[name])?;
+        let mut data = Vec::with_capacity(file.size() as usize);
+        file.read_to_end(&mut data)?;
+        Ok(data)
+    };
+
+    let rels_name = find_part_name(&entries, ROOT_RELATIONSHIPS_PART)
+        .ok_or_else(|| PackageError::MissingPart(ROOT_RELATIONSHIPS_PART.to_string()))?;
+    let relationships = parse_relationships(&rels_name, &read_entry(&rels_name)?)?;
+    let thumbnail = relationships
+        .iter()
+        .find(|relationship| relationship.relationship_type == THUMBNAIL_RELATIONSHIP_TYPE)
+        .and_then(|relationship| find_part_name(&entries, &relationship.target));
+
+    thumbnail.map(|name| read_entry(&name)).transpose()
+}
+
+/// Name of the part holding the relationships of `part_name`,
+/// e.g. `/3D/_rels/3dmodel.model.rels` for `/3D/3dmodel.model`.
+pub fn relationships_part_name(part_name: &str) -> String {
+    let (directory, file_name) = part_name.rsplit_once('/').unwrap_or(("", part_name));
+    format!("{}/_rels/{}.rels", directory, file_name)
+}
+
+/// Resolves a relationship target relative to the part declaring it.
+pub fn resolve_target(source_part: &str, target: &str) -> String {
+    let target = target.split('#').next().unwrap_or(target);
+    let mut segments: Vec<&str> = if target.starts_with('/') {
+        Vec::new()
+    } else {
+        let directory = source_part.rsplit_once('/').map_or("", |(dir, _)| dir);
+        directory.split('/').filter(|s| !s.is_empty()).collect()
+    };
+
+    for segment in target.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+
+    format!("/{}", segments.join("/"))
+}
+
+/// Serialises relationships into the content of a `.rels` part.
+pub fn relationships_xml(relationships: &[Relationship]) -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">\n",
+    );
+    for relationship in relationships {
+        xml.push_str(&format!(
+            "  <Relationship Target=\"{}\" Id=\"{}\" Type=\"{}\" />\n",
+            escape(&relationship.target),
+            escape(&relationship.id),
+            escape(&relationship.relationship_type)
+        ));
+    }
+    xml.push_str("</Relationships>\n");
+    xml
+}
+
+fn find_part_name<T>(parts: &BTreeMap<String, T>, name: &str) -> Option<String> {
+    if parts.contains_key(name) {
+        return Some(name.to_string());
+    }
+    parts
+        .keys()
+        .find(|key| key.eq_ignore_ascii_case(name))
+        .cloned()
+}
+
+fn take_part(parts: &mut BTreeMap<String, Vec<u8>>, name: &str) -> Option<Vec<u8>> {
+    find_part_name(parts, name).and_then(|name| parts.remove(&name))
+}
+
+fn xml_part_as_str<'a>(part: &str, data: &'a [u8]) -> Result<&'a str, PackageError> {
+    let text = std::str::from_utf8(data).map_err(|_| PackageError::NotUtf8(part.to_string()))?;
+    Ok(text.trim_start_matches('\u{feff}'))
+}
+
+fn parse_relationships(part: &str, data: &[u8]) -> Result<Vec<Relationship>, PackageError> {
+    let xml: RelationshipsXml =
+        from_str(xml_part_as_str(part, data)?).map_err(|e| PackageError::InvalidXml {
+            part: part.to_string(),
+            message: e.to_string(),
+        })?;
+
+    // relationships of `/3D/_rels/a.model.rels` are relative to `/3D/a.model`
+    let source = source_part_name(part);
+    Ok(xml
+        .relationship
+        .into_iter()
+        .filter(|relationship| relationship.target_mode.as_deref() != Some("External"))
+        .map(|relationship| Relationship {
+            id: relationship.id,
+            target: resolve_target(&source, &relationship.target),
+            relationship_type: relationship.relationship_type,
+        })
+        .collect())
+}
+
+fn source_part_name(relationships_part: &str) -> String {
+    let without_suffix = relationships_part
+        .strip_suffix(".rels")
+        .unwrap_or(relationships_part);
+    match without_suffix.rsplit_once("/_rels/") {
+        Some((directory, file_name)) => format!("{}/{}", directory, file_name),
+        None => "/".to_string(),
+    }
+}
+
+struct ContentTypes {
+    defaults: Vec<(String, String)>,
+    overrides: Vec<(String, String)>,
+}
+
+impl ContentTypes {
+    fn parse(data: &[u8]) -> Result<Self, PackageError> {
+        let xml: ContentTypesXml =
+            from_str(xml_part_as_str(CONTENT_TYPES_PART, data)?).map_err(|e| {
+                PackageError::InvalidXml {
+                    part: CONTENT_TYPES_PART.to_string(),
+                    message: e.to_string(),
+                }
+            })?;
+
+        let mut defaults = Vec::new();
+        let mut overrides = Vec::new();
+        for entry in xml.entries {
+            match entry {
+                ContentTypeEntry::Default {
+                    extension,
+                    content_type,
+                } => defaults.push((extension, content_type)),
+                ContentTypeEntry::Override {
+                    part_name,
+                    content_type,
+                } => overrides.push((part_name, content_type)),
+            }
+        }
+
+        Ok(Self {
+            defaults,
+            overrides,
+        })
+    }
+
+    fn content_type_of(&self, part_name: &str) -> String {
+        if let Some((_, content_type)) = self
+            .overrides
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(part_name))
+        {
+            return content_type.clone();
+        }
+
+        let extension = part_name.rsplit_once('.').map_or("", |(_, ext)| ext);
+        self.defaults
+            .iter()
+            .find(|(ext, _)| ext.eq_ignore_ascii_case(extension))
+            .map(|(_, content_type)| content_type.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Deserialize)]
+struct ContentTypesXml {
+    #[serde(rename = "$value", default)]
+    entries: Vec<ContentTypeEntry>,
+}
+
+#[derive(Deserialize)]
+enum ContentTypeEntry {
+    Default {
+        #[serde(rename = "@Extension")]
+        extension: String,
+        #[serde(rename = "@ContentType")]
+        content_type: String,
+    },
+    Override {
+        #[serde(rename = "@PartName")]
+        part_name: String,
+        #[serde(rename = "@ContentType")]
+        content_type: String,
+    },
+}
+
+#[derive(Deserialize)]
+struct RelationshipsXml {
+    #[serde(rename = "Relationship", default)]
+    relationship: Vec<RelationshipXml>,
+}
+
+#[derive(Deserialize)]
+struct RelationshipXml {
+    #[serde(rename = "@Id")]
+    id: String,
+    #[serde(rename = "@Target")]
+    target: String,
+    #[serde(rename = "@Type")]
+    relationship_type: String,
+    #[serde(rename = "@TargetMode")]
+    target_mode: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::open_file_from_test_resource;
+    use std::io::{Cursor, Write};
+    use zip::{write::SimpleFileOptions, ZipWriter};
+
+    fn zip_from_entries(entries: &[(&str, &str)]) -> Cursor<Vec<u8>> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, content) in entries {
+            zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+        }
+        let mut cursor = zip.finish().unwrap();
+        cursor.set_position(0);
+        cursor
+    }
+
+    const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+  <Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml" />
+  <Override PartName="/parts/Main.model" ContentType="application/vnd.ms-package.3dmanufacturing-3dmodel+xml" />
+  <Default Extension="model" ContentType="application/vnd.ms-package.3dmanufacturing-3dmodel+xml" />
+</Types>"#;
+
+    #[test]
+    fn test_start_part_of_box() {
+        let package = Package::from_reader(open_file_from_test_resource("box.3mf")).unwrap();
+        let start_part = package.start_part().unwrap();
+
+        assert!(
+            start_part.name == "/3D/3dmodel.model",
+            "Start part is not resolved from the root relationship"
+        );
+        assert!(
+            start_part.content_type == MODEL_CONTENT_TYPE,
+            "Content type of the start part is wrong"
+        );
+        assert!(
+            package.part(ROOT_RELATIONSHIPS_PART).unwrap().content_type
+                == RELATIONSHIPS_CONTENT_TYPE,
+            "Relationship parts are not exposed"
+        );
+    }
+
+    #[test]
+    fn test_start_part_is_not_the_first_model_part() {
+        let reader = zip_from_entries(&[
+            ("[Content_Types].xml", CONTENT_TYPES),
+            ("3D/decoy.model", "decoy"),
+            (
+                "_rels/.rels",
+                r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Target="/parts/Main.model" Id="rel0" Type="http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel" />
+</Relationships>"#,
+            ),
+            ("parts/Main.model", "main"),
+            (
+                "parts/_rels/Main.model.rels",
+                r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Target="../3D/decoy.model" Id="rel1" Type="http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel" />
+</Relationships>"#,
+            ),
+        ]);
+
+        let package = Package::from_reader(reader).unwrap();
+        let start_part = package.start_part().unwrap();
+
+        assert!(
+            start_part.data_as_string().unwrap() == "main",
+            "Start part is not the relationship target"
+        );
+        assert!(
+            start_part.relationships[0].target == "/3D/decoy.model",
+            "Relative relationship target is not resolved against the source part"
+        );
+        assert!(
+            package.part("/3D/DECOY.model").is_some(),
+            "Part lookup is not case insensitive"
+        );
+    }
+
+    #[test]
+    fn test_thumbnail_is_found_through_the_package_relationship() {
+        let entries = [
+            ("[Content_Types].xml", CONTENT_TYPES),
+            (
+                "_rels/.rels",
+                r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Target="/parts/Main.model" Id="rel0" Type="http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel" />
+  <Relationship Target="Metadata/Preview.png" Id="rel1" Type="http://schemas.openxmlformats.org/package/2006/relationships/metadata/thumbnail" />
+</Relationships>"#,
+            ),
+            ("parts/Main.model", "main"),
+            ("Metadata/thumbnail.png", "decoy"),
+            ("Metadata/Preview.png", "png"),
+        ];
+
+        let package = Package::from_reader(zip_from_entries(&entries)).unwrap();
+        assert!(
+            package.thumbnail().unwrap().data == b"png",
+            "Thumbnail is not the relationship target"
+        );
+        assert!(
+            read_thumbnail(zip_from_entries(&entries)).unwrap() == Some(b"png".to_vec()),
+            "Thumbnail read on its own is wrong"
+        );
+        assert!(
+            read_thumbnail(open_file_from_test_resource("box.3mf"))
+                .unwrap()
+                .is_none(),
+            "Package without a thumbnail relationship has a thumbnail"
+        );
+    }
+
+    #[test]
+    fn test_error_returned_when_content_types_are_missing() {
+        let result = Package::from_reader(open_file_from_test_resource("fake-3mf.3mf"));
+
+        assert!(
+            matches!(result, Err(PackageError::MissingPart(ref part)) if part == CONTENT_TYPES_PART),
+            "Package without content types did not return the expected error"
+        );
+    }
+
+    #[test]
+    fn test_error_returned_when_start_part_is_missing() {
+        let reader = zip_from_entries(&[
+            ("[Content_Types].xml", CONTENT_TYPES),
+            (
+                "_rels/.rels",
+                r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships" />"#,
+            ),
+            ("3D/3dmodel.model", "model"),
+        ]);
+
+        let result = Package::from_reader(reader)
+            .unwrap()
+            .start_part()
+            .map(|_| ());
+        assert!(
+            matches!(result, Err(PackageError::MissingStartPart)),
+            "Package without a model relationship did not return the expected error"
+        );
+    }
+}
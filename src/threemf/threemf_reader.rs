@@ -1,27 +1,222 @@
-use anyhow::Result;
-use quick_xml::de::Deserializer;
+use anyhow::{anyhow, Result};
+use quick_xml::{
+    de::Deserializer,
+    events::Event,
+    name::{Namespace, ResolveResult},
+    NsReader,
+};
 use serde::Deserialize;
-use std::io::{self, Read};
-use threemf::model::Model;
-
-use zip::ZipArchive;
-
-pub fn load_threemf_get_root_model_file_as_string<R: io::Read + io::Seek>(
-    reader: R,
-) -> Result<String> {
-    let mut zip = ZipArchive::new(reader)?;
-    let mut list_string = String::new();
-
-    for i in 0..zip.len() {
-        let mut file = zip.by_index(i)?;
-        // let message = format!("File {} is {}", i + 1, file.name());
-        if file.name().ends_with(".model") {
-            file.read_to_string(&mut list_string)?;
-            break;
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    io,
+    sync::Arc,
+};
+use threemf::model::{Model, Object, ObjectData};
+
+use super::{
+    materials::{get_materials_from_3mf_model_file_string, Materials},
+    threemf_package::{resolve_target, Package},
+    threemf_writer::get_3mf_model_file_string_from_model,
+};
+
+pub const PRODUCTION_NAMESPACE: &str =
+    "http://schemas.microsoft.com/3dmanufacturing/production/2015/06";
+
+/// A `p:path` and `p:UUID` pair of the production extension.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProductionReference {
+    /// Absolute name of the model part holding the referenced object.
+    pub path: Option<String>,
+    pub uuid: Option<String>,
+}
+
+/// Production extension attributes of an object and its components.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProductionObject {
+    pub uuid: Option<String>,
+    /// One entry per component, in document order.
+    pub components: Vec<ProductionReference>,
+}
+
+/// Production extension attributes of a model part, which the `threemf` model does not keep.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProductionAttributes {
+    pub build_uuid: Option<String>,
+    /// One entry per build item, in document order.
+    pub items: Vec<ProductionReference>,
+    pub objects: HashMap<usize, ProductionObject>,
+}
+
+/// A model part of the package together with its source text.
+#[derive(Debug, Clone)]
+pub struct ModelPart {
+    pub name: String,
+    /// Shared with the trees of the part, edits replace the whole text.
+    pub xml: Arc<str>,
+    pub model: Model,
+    pub production: ProductionAttributes,
+    pub materials: Materials,
+}
+
+impl ModelPart {
+    /// Name of the part holding the object referenced by component `index` of `object_id`.
+    pub fn component_part(&self, object_id: usize, index: usize) -> &str {
+        self.production
+            .objects
+            .get(&object_id)
+            .and_then(|object| object.components.get(index))
+            .and_then(|reference| reference.path.as_deref())
+            .unwrap_or(&self.name)
+    }
+
+    /// Name of the part holding the object referenced by build item `index`.
+    pub fn item_part(&self, index: usize) -> &str {
+        self.production
+            .items
+            .get(index)
+            .and_then(|reference| reference.path.as_deref())
+            .unwrap_or(&self.name)
+    }
+
+    /// Replaces the text of the part and everything read from it, with the images of
+    /// textures from `package`. The part is kept as it was when the text is not a valid model.
+    pub fn set_xml(&mut self, xml: String, package: Option<&Package>) -> Result<()> {
+        let model = get_model_from_3mf_model_file_string(&xml)
+            .map_err(|e| anyhow!("Failed to parse model part {}: {}", self.name, e))?;
+        let production = get_production_attributes_from_3mf_model_file_string(&self.name, &xml)?;
+        let mut materials = get_materials_from_3mf_model_file_string(&self.name, &xml)?;
+        if let Some(package) = package {
+            materials.load_textures(package);
+        }
+
+        self.xml = xml.into();
+        self.model = model;
+        self.production = production;
+        self.materials = materials;
+        Ok(())
+    }
+}
+
+/// Every model part reachable from the root model of a package.
+#[derive(Debug, Clone)]
+pub struct ModelParts {
+    pub root: String,
+    pub parts: BTreeMap<String, ModelPart>,
+}
+
+impl ModelParts {
+    /// Wraps a model that was not read from a package, e.g. an imported mesh, as the
+    /// single root part `part_name`.
+    pub fn from_model(part_name: &str, model: Model) -> Result<Self> {
+        let xml = get_3mf_model_file_string_from_model(&model)?;
+        let part = ModelPart {
+            name: part_name.to_string(),
+            xml: xml.into(),
+            model,
+            production: ProductionAttributes::default(),
+            materials: Materials::default(),
+        };
+
+        Ok(Self {
+            root: part.name.clone(),
+            parts: BTreeMap::from([(part.name.clone(), part)]),
+        })
+    }
+
+    pub fn root_part(&self) -> &ModelPart {
+        &self.parts[&self.root]
+    }
+
+    pub fn object(&self, part: &str, object_id: usize) -> Option<&Object> {
+        self.parts
+            .get(part)?
+            .model
+            .resources
+            .object
+            .iter()
+            .find(|object| object.id == object_id)
+    }
+}
+
+/// Reads the package and logs its structure.
+pub fn load_threemf_package<R: io::Read + io::Seek>(reader: R) -> Result<Package> {
+    let package = Package::from_reader(reader)?;
+    for relationship in package.relationships() {
+        log::debug!(
+            "Package relationship {} to {}",
+            relationship.relationship_type,
+            relationship.target
+        );
+    }
+    for part in package.parts() {
+        log::debug!(
+            "Part {} of type {} with {} relationships",
+            part.name,
+            part.content_type,
+            part.relationships.len()
+        );
+    }
+
+    Ok(package)
+}
+
+/// Loads the root model of the package and every model part it references through
+/// `p:path` attributes of the production extension.
+pub fn get_model_parts_from_package(package: &Package) -> Result<ModelParts> {
+    let root = package.start_part()?.name.clone();
+    let mut parts = BTreeMap::new();
+    let mut queue = VecDeque::from([root.clone()]);
+
+    while let Some(name) = queue.pop_front() {
+        if parts.contains_key(&name) {
+            continue;
+        }
+
+        let part = package
+            .part(&name)
+            .ok_or_else(|| anyhow!("Model part {} referenced by p:path is missing", name))?;
+        let xml = part.data_as_string()?;
+        let model = get_model_from_3mf_model_file_string(&xml)
+            .map_err(|e| anyhow!("Failed to parse model part {}: {}", part.name, e))?;
+        let production = get_production_attributes_from_3mf_model_file_string(&part.name, &xml)?;
+        let mut materials = get_materials_from_3mf_model_file_string(&part.name, &xml)?;
+        materials.load_textures(package);
+
+        let references = production.items.iter().chain(
+            production
+                .objects
+                .values()
+                .flat_map(|object| object.components.iter()),
+        );
+        for path in references.filter_map(|reference| reference.path.as_ref()) {
+            if !parts.contains_key(path) {
+                queue.push_back(path.clone());
+            }
         }
+
+        parts.insert(
+            part.name.clone(),
+            ModelPart {
+                name: part.name.clone(),
+                xml: xml.into(),
+                model,
+                production,
+                materials,
+            },
+        );
     }
 
-    Ok(list_string)
+    let model_parts = ModelParts {
+        root: parts
+            .keys()
+            .find(|name| name.eq_ignore_ascii_case(&root))
+            .cloned()
+            .unwrap_or(root),
+        parts,
+    };
+    check_production_references(&model_parts)?;
+
+    Ok(model_parts)
 }
 
 pub fn get_model_from_3mf_model_file_string(xml_content: &String) -> Result<Model> {
@@ -31,41 +226,269 @@ pub fn get_model_from_3mf_model_file_string(xml_content: &String) -> Result<Mode
     Ok(model)
 }
 
+/// Reads the production extension attributes of a model part. `p:path` values are
+/// resolved to absolute part names relative to `part_name`.
+pub fn get_production_attributes_from_3mf_model_file_string(
+    part_name: &str,
+    xml_content: &str,
+) -> Result<ProductionAttributes> {
+    let mut reader = NsReader::from_str(xml_content);
+    let mut attributes = ProductionAttributes::default();
+    let mut current_object = None;
+
+    loop {
+        let (start, element) = match reader.read_resolved_event()? {
+            (_, Event::Start(element)) => (true, element),
+            (_, Event::Empty(element)) => (false, element),
+            (_, Event::End(element)) => {
+                if element.local_name().as_ref() == b"object" {
+                    current_object = None;
+                }
+                continue;
+            }
+            (_, Event::Eof) => break,
+            _ => continue,
+        };
+
+        let mut reference = ProductionReference::default();
+        let mut id = None;
+        for attribute in element.attributes() {
+            let attribute = attribute?;
+            let value = attribute.unescape_value()?.to_string();
+            match reader.resolve_attribute(attribute.key) {
+                (ResolveResult::Bound(Namespace(ns)), local)
+                    if ns == PRODUCTION_NAMESPACE.as_bytes() =>
+                {
+                    match local.as_ref() {
+                        b"path" => reference.path = Some(resolve_target(part_name, &value)),
+                        b"UUID" => reference.uuid = Some(value),
+                        _ => {}
+                    }
+                }
+                (ResolveResult::Unbound, local) if local.as_ref() == b"id" => {
+                    id = value.parse::<usize>().ok();
+                }
+                _ => {}
+            }
+        }
+
+        match element.local_name().as_ref() {
+            b"build" => attributes.build_uuid = reference.uuid,
+            b"item" => attributes.items.push(reference),
+            b"object" => {
+                let id = id.ok_or_else(|| anyhow!("Object without an id in {}", part_name))?;
+                attributes.objects.insert(
+                    id,
+                    ProductionObject {
+                        uuid: reference.uuid,
+                        components: Vec::new(),
+                    },
+                );
+                if start {
+                    current_object = Some(id);
+                }
+            }
+            b"component" => {
+                if let Some(object) = current_object.and_then(|id| attributes.objects.get_mut(&id))
+                {
+                    object.components.push(reference);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(attributes)
+}
+
+/// Reads the `thumbnail` attributes of the objects of a model part as absolute part names
+/// by object id.
+pub fn get_object_thumbnails_from_3mf_model_file_string(
+    part_name: &str,
+    xml_content: &str,
+) -> Result<BTreeMap<usize, String>> {
+    let mut reader = NsReader::from_str(xml_content);
+    let mut thumbnails = BTreeMap::new();
+
+    loop {
+        let element = match reader.read_event()? {
+            Event::Start(element) | Event::Empty(element) => element,
+            Event::Eof => break,
+            _ => continue,
+        };
+        if element.local_name().as_ref() != b"object" {
+            continue;
+        }
+
+        let (mut id, mut thumbnail) = (None, None);
+        for attribute in element.attributes() {
+            let attribute = attribute?;
+            match attribute.key.as_ref() {
+                b"id" => id = attribute.unescape_value()?.parse::<usize>().ok(),
+                b"thumbnail" => {
+                    thumbnail = Some(resolve_target(part_name, &attribute.unescape_value()?))
+                }
+                _ => {}
+            }
+        }
+        if let (Some(id), Some(thumbnail)) = (id, thumbnail) {
+            thumbnails.insert(id, thumbnail);
+        }
+    }
+
+    Ok(thumbnails)
+}
+
+/// Makes sure every cross part reference points at an existing object and warns about
+/// production UUIDs that are not unique.
+fn check_production_references(model_parts: &ModelParts) -> Result<()> {
+    let mut uuids: HashMap<&str, &str> = HashMap::new();
+
+    for part in model_parts.parts.values() {
+        for (index, item) in part.model.build.item.iter().enumerate() {
+            let target = part.item_part(index);
+            if model_parts.object(target, item.objectid).is_none() {
+                return Err(anyhow!(
+                    "Build item {} references object {} missing from {}",
+                    index,
+                    item.objectid,
+                    target
+                ));
+            }
+        }
+
+        for object in &part.model.resources.object {
+            if let ObjectData::Components { component } = &object.object {
+                for (index, component) in component.iter().enumerate() {
+                    let target = part.component_part(object.id, index);
+                    if model_parts.object(target, component.objectid).is_none() {
+                        return Err(anyhow!(
+                            "Component of object {} in {} references object {} missing from {}",
+                            object.id,
+                            part.name,
+                            component.objectid,
+                            target
+                        ));
+                    }
+                }
+            }
+        }
+
+        let production_uuids = part
+            .production
+            .objects
+            .values()
+            .filter_map(|object| object.uuid.as_deref())
+            .chain(
+                part.production
+                    .items
+                    .iter()
+                    .filter_map(|item| item.uuid.as_deref()),
+            );
+        for uuid in production_uuids {
+            if let Some(other_part) = uuids.insert(uuid, &part.name) {
+                log::warn!(
+                    "Production UUID {} is used more than once in {} and {}",
+                    uuid,
+                    other_part,
+                    part.name
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use threemf::model::ObjectData;
 
     use super::*;
-    use std::{
-        env::{self},
-        fs::{self, File},
-        path::PathBuf,
-    };
+    use crate::test_support::open_file_from_test_resource;
 
-    fn open_file_from_test_resource(file_name: &str) -> File {
-        let root_dir = &env::var("CARGO_MANIFEST_DIR").expect("$CARGO_MANIFEST_DIR");
-        let mut test_file_path = PathBuf::from(root_dir);
-        test_file_path.push("test_resources\\");
-        test_file_path.push(file_name);
-        // println!("{:?}", test_file_path);
+    #[test]
+    fn test_load_threemf_root_model_part() {
+        let package = load_threemf_package(open_file_from_test_resource("box.3mf")).unwrap();
+        let model_parts = get_model_parts_from_package(&package).unwrap();
+        assert!(
+            model_parts
+                .root_part()
+                .xml
+                .contains("<model unit=\"millimeter\""),
+            "3dmodel.model file is not read correctly"
+        );
+        assert!(
+            model_parts.parts.len() == 1,
+            "A single part package loaded extra parts"
+        );
+    }
+
+    #[test]
+    fn test_root_model_part_is_read_as_string() {
+        let mut archive = zip::ZipArchive::new(open_file_from_test_resource("box.3mf")).unwrap();
+        let mut expected = String::new();
+        io::Read::read_to_string(
+            &mut archive.by_name("3D/3dmodel.model").unwrap(),
+            &mut expected,
+        )
+        .unwrap();
 
-        fs::File::open(test_file_path).unwrap()
+        let package = load_threemf_package(open_file_from_test_resource("box.3mf")).unwrap();
+        let string = package.start_part().unwrap().data_as_string().unwrap();
+        assert!(
+            string == expected,
+            "3dmodel.model file is not read correctly"
+        );
+        assert!(
+            *get_model_parts_from_package(&package)
+                .unwrap()
+                .root_part()
+                .xml
+                == *expected,
+            "Text of the root model part differs from the file"
+        );
     }
 
     #[test]
-    fn test_load_threemf_get_root_model_file_as_string() {
+    fn test_part_is_kept_when_new_text_is_invalid() {
+        let package = load_threemf_package(open_file_from_test_resource("box.3mf")).unwrap();
+        let mut model_parts = get_model_parts_from_package(&package).unwrap();
+        let part = model_parts.parts.values_mut().next().unwrap();
+        let original = part.xml.clone();
+
+        assert!(
+            part.set_xml("<model>".to_string(), Some(&package)).is_err(),
+            "Unfinished model is accepted"
+        );
+        assert!(part.xml == original, "Invalid text replaced the part");
+
+        let edited = original.replacen("<build>", "<build>\n  ", 1);
+        part.set_xml(edited.clone(), Some(&package)).unwrap();
+        assert!(
+            *part.xml == *edited && part.model.build.item.len() == 1,
+            "Valid text is not read into the part"
+        );
+    }
+
+    #[test]
+    fn test_error_returned_when_package_is_malformed() {
         let file = open_file_from_test_resource("fake-3mf.3mf");
-        let string = load_threemf_get_root_model_file_as_string(file).unwrap();
+        let result = load_threemf_package(file);
         assert!(
-            string == "Test Passed",
-            "3dmodel.model file is not read correctly"
+            result.is_err(),
+            "A package without content types and relationships was accepted"
         );
     }
 
     #[test]
     fn test_get_model_from_3mf_model_file_string() {
-        let file = open_file_from_test_resource("box.3mf");
-        let string = load_threemf_get_root_model_file_as_string(file).unwrap();
+        let package = load_threemf_package(open_file_from_test_resource("box.3mf")).unwrap();
+        let string = get_model_parts_from_package(&package)
+            .unwrap()
+            .root_part()
+            .xml
+            .to_string();
         let model = get_model_from_3mf_model_file_string(&string).unwrap();
 
         assert!(
@@ -91,4 +514,57 @@ mod tests {
             assert!(false, "Not a mesh data");
         }
     }
+
+    #[test]
+    fn test_load_threemf_production_model_parts() {
+        let file = open_file_from_test_resource("production.3mf");
+        let package = load_threemf_package(file).unwrap();
+        let model_parts = get_model_parts_from_package(&package).unwrap();
+
+        assert!(
+            model_parts.root == "/3D/3dmodel.model",
+            "Root model part is wrong"
+        );
+        assert!(
+            model_parts.parts.len() == 2,
+            "Model part referenced by p:path was not loaded"
+        );
+
+        let root = model_parts.root_part();
+        assert!(
+            root.item_part(0) == "/3D/Objects/box.model",
+            "p:path of the build item is not resolved"
+        );
+        assert!(
+            root.component_part(2, 0) == "/3D/Objects/box.model",
+            "p:path of the component is not resolved"
+        );
+        assert!(
+            root.production.objects[&2].uuid.as_deref()
+                == Some("1f0a5c52-2c1c-4c7a-9d3e-0a6b1d0c0002"),
+            "p:UUID of the object is not read"
+        );
+        assert!(
+            model_parts.object("/3D/Objects/box.model", 1).is_some(),
+            "Object of the referenced part is not found"
+        );
+    }
+
+    #[test]
+    fn test_object_thumbnails_are_resolved() {
+        let xml = r#"<model xmlns="http://schemas.microsoft.com/3dmanufacturing/core/2015/02">
+  <resources>
+    <object id="1" type="model" thumbnail="../Metadata/box.png"><mesh /></object>
+    <object id="2" type="model"><mesh /></object>
+  </resources>
+</model>"#;
+        let thumbnails =
+            get_object_thumbnails_from_3mf_model_file_string("/3D/3dmodel.model", xml).unwrap();
+
+        assert!(
+            thumbnails == BTreeMap::from([(1, "/Metadata/box.png".to_string())]),
+            "Object thumbnails are wrong: {:?}",
+            thumbnails
+        );
+    }
 }
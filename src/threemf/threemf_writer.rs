@@ -0,0 +1,283 @@
+use anyhow::Result;
+use quick_xml::{escape::escape, se::Serializer};
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    io::{self, Write},
+};
+use threemf::model::{Mesh, Model};
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+use super::{
+    threemf_package::{Package, CONTENT_TYPES_PART},
+    threemf_reader::ModelParts,
+};
+
+/// Used for parts that were read without any content type.
+const FALLBACK_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// Writes every part of the package into a zip archive. `[Content_Types].xml` is
+/// regenerated from the content types of the parts, everything else is written unchanged.
+pub fn write_threemf_package<W: io::Write + io::Seek>(package: &Package, writer: W) -> Result<()> {
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let mut zip = ZipWriter::new(writer);
+
+    zip.start_file(CONTENT_TYPES_PART.trim_start_matches('/'), options)?;
+    zip.write_all(content_types_xml(package).as_bytes())?;
+
+    for part in package.parts() {
+        zip.start_file(part.name.trim_start_matches('/'), options)?;
+        zip.write_all(&part.data)?;
+    }
+
+    zip.finish()?;
+    Ok(())
+}
+
+/// Replaces the model parts of the package with the current text of `model_parts`.
+pub fn update_package_model_parts(package: &mut Package, model_parts: &ModelParts) {
+    for model_part in model_parts.parts.values() {
+        match package.part_mut(&model_part.name) {
+            Some(part) => part.data = model_part.xml.as_bytes().to_vec(),
+            None => log::warn!(
+                "Model part {} is not in the package and was not saved",
+                model_part.name
+            ),
+        }
+    }
+}
+
+/// Creates a package for a model that was not read from a 3MF, like an imported mesh.
+pub fn new_package_from_model_parts(model_parts: &ModelParts) -> Package {
+    let root = model_parts.root_part();
+    let mut package = Package::with_start_part(&root.name, root.xml.as_bytes().to_vec());
+    update_package_model_parts(&mut package, model_parts);
+    package
+}
+
+/// Serialises a model into the text of a 3MF model part.
+pub fn get_3mf_model_file_string_from_model(model: &Model) -> Result<String> {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    // the root element would be named after the struct otherwise
+    model.serialize(Serializer::with_root(&mut xml, Some("model"))?)?;
+
+    Ok(xml)
+}
+
+/// Serialises a mesh into a `mesh` element. With `indent`, the indentation of the line the
+/// element starts on, every child goes on a line of its own, two spaces further in per level.
+pub fn get_3mf_mesh_string(mesh: &Mesh, indent: Option<&str>) -> Result<String> {
+    let mut xml = String::new();
+    let mut serializer = Serializer::with_root(&mut xml, Some("mesh"))?;
+    if indent.is_some() {
+        serializer.indent(' ', 2);
+    }
+    mesh.serialize(serializer)?;
+
+    Ok(match indent {
+        Some(indent) => xml.replace('\n', &format!("\n{}", indent)),
+        None => xml,
+    })
+}
+
+/// Extensions shared by parts of a single content type become `Default` entries,
+/// every other part gets an `Override`.
+fn content_types_xml(package: &Package) -> String {
+    let mut extensions: BTreeMap<String, Option<&str>> = BTreeMap::new();
+    for part in package.parts() {
+        let content_type = content_type_of(&part.content_type);
+        extensions
+            .entry(extension_of(&part.name))
+            .and_modify(|shared| {
+                if *shared != Some(content_type) {
+                    *shared = None;
+                }
+            })
+            .or_insert(Some(content_type));
+    }
+
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <Types xmlns=\"http://schemas.openxmlformats.org/package/2006/content-types\">\n",
+    );
+    for (extension, content_type) in &extensions {
+        if let (false, Some(content_type)) = (extension.is_empty(), content_type) {
+            xml.push_str(&format!(
+                "  <Default Extension=\"{}\" ContentType=\"{}\" />\n",
+                escape(extension),
+                escape(content_type)
+            ));
+        }
+    }
+    for part in package.parts() {
+        let extension = extension_of(&part.name);
+        if extension.is_empty() || extensions[&extension].is_none() {
+            xml.push_str(&format!(
+                "  <Override PartName=\"{}\" ContentType=\"{}\" />\n",
+                escape(&part.name),
+                escape(content_type_of(&part.content_type))
+            ));
+        }
+    }
+    xml.push_str("</Types>\n");
+
+    xml
+}
+
+fn extension_of(part_name: &str) -> String {
+    let file_name = part_name.rsplit('/').next().unwrap_or(part_name);
+    file_name
+        .rsplit_once('.')
+        .map_or(String::new(), |(_, extension)| {
+            extension.to_ascii_lowercase()
+        })
+}
+
+fn content_type_of(content_type: &str) -> &str {
+    if content_type.is_empty() {
+        FALLBACK_CONTENT_TYPE
+    } else {
+        content_type
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        open_file_from_test_resource, root_element_name, write_and_read_package,
+    };
+    use crate::threemf::threemf_reader::{
+        get_model_from_3mf_model_file_string, get_model_parts_from_package, load_threemf_package,
+    };
+    use threemf::model::ObjectData;
+
+    #[test]
+    fn test_read_write_read_box() {
+        let package = load_threemf_package(open_file_from_test_resource("box.3mf")).unwrap();
+        let written = write_and_read_package(&package);
+
+        let parts: Vec<_> = package.parts().map(|part| &part.name).collect();
+        let written_parts: Vec<_> = written.parts().map(|part| &part.name).collect();
+        assert!(parts == written_parts, "Part names changed after writing");
+
+        for part in package.parts() {
+            let written_part = written.part(&part.name).unwrap();
+            assert!(
+                written_part.content_type == part.content_type,
+                "Content type of {} changed after writing",
+                part.name
+            );
+            assert!(
+                written_part.data == part.data,
+                "Content of {} changed after writing",
+                part.name
+            );
+            assert!(
+                written_part.relationships == part.relationships,
+                "Relationships of {} changed after writing",
+                part.name
+            );
+        }
+        assert!(
+            written.relationships() == package.relationships(),
+            "Package relationships changed after writing"
+        );
+        let root = root_element_name(&written.start_part().unwrap().data_as_string().unwrap());
+        assert!(root == "model", "Root element of the model is {}", root);
+    }
+
+    #[test]
+    fn test_extra_parts_are_preserved() {
+        let mut package = load_threemf_package(open_file_from_test_resource("box.3mf")).unwrap();
+        package.insert_part(crate::threemf::threemf_package::Part {
+            name: "/Metadata/thumbnail.png".to_string(),
+            content_type: "image/png".to_string(),
+            data: vec![0x89, b'P', b'N', b'G'],
+            relationships: Vec::new(),
+        });
+        package.insert_part(crate::threemf::threemf_package::Part {
+            name: "/Metadata/LICENSE".to_string(),
+            content_type: String::new(),
+            data: b"MIT".to_vec(),
+            relationships: Vec::new(),
+        });
+
+        let written = write_and_read_package(&package);
+        let thumbnail = written.part("/Metadata/thumbnail.png").unwrap();
+        assert!(
+            thumbnail.content_type == "image/png" && thumbnail.data.len() == 4,
+            "Thumbnail is not preserved"
+        );
+        assert!(
+            written.part("/Metadata/LICENSE").unwrap().content_type == FALLBACK_CONTENT_TYPE,
+            "Part without extension has no content type"
+        );
+    }
+
+    #[test]
+    fn test_modified_model_is_saved() {
+        let mut package = load_threemf_package(open_file_from_test_resource("box.3mf")).unwrap();
+        let mut model_parts = get_model_parts_from_package(&package).unwrap();
+
+        let root = model_parts.root.clone();
+        let root_part = model_parts.parts.get_mut(&root).unwrap();
+        root_part.model.build.item[0].transform =
+            Some([1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 5.0, 0.0, 0.0]);
+        root_part.xml = get_3mf_model_file_string_from_model(&root_part.model)
+            .unwrap()
+            .into();
+
+        update_package_model_parts(&mut package, &model_parts);
+        let written = get_model_parts_from_package(&write_and_read_package(&package)).unwrap();
+        let model = &written.root_part().model;
+        let root = root_element_name(&written.root_part().xml);
+        assert!(
+            root == "model",
+            "Root element of the saved model is {}",
+            root
+        );
+
+        assert!(
+            model.build.item[0].transform.unwrap()[9] == 5.0,
+            "Build item transform is not saved"
+        );
+        if let ObjectData::Mesh(mesh) = &model.resources.object[0].object {
+            assert!(
+                mesh.vertices.vertex.len() == 8 && mesh.triangles.triangle.len() == 12,
+                "Mesh changed after writing"
+            );
+        } else {
+            panic!("Not a mesh data");
+        }
+    }
+
+    #[test]
+    fn test_new_package_from_model() {
+        let package = load_threemf_package(open_file_from_test_resource("box.3mf")).unwrap();
+        let model = get_model_parts_from_package(&package)
+            .unwrap()
+            .root_part()
+            .model
+            .clone();
+
+        let xml = get_3mf_model_file_string_from_model(&model).unwrap();
+        let new_package = Package::with_start_part("/3D/3dmodel.model", xml.into_bytes());
+        let written = write_and_read_package(&new_package);
+        let start_part = written.start_part().unwrap();
+
+        assert!(
+            start_part.name == "/3D/3dmodel.model",
+            "Start part of a new package is wrong"
+        );
+        let root = root_element_name(&start_part.data_as_string().unwrap());
+        assert!(root == "model", "Root element of a new model is {}", root);
+        let written_model =
+            get_model_from_3mf_model_file_string(&start_part.data_as_string().unwrap()).unwrap();
+        assert!(
+            written_model.resources.object.len() == model.resources.object.len()
+                && written_model.build.item.len() == model.build.item.len(),
+            "Model changed after writing a new package"
+        );
+    }
+}
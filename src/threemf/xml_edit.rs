@@ -0,0 +1,604 @@
+use anyhow::{anyhow, Result};
+use glam::DMat4;
+use quick_xml::{
+    escape::escape,
+    events::{BytesStart, Event},
+    Reader, Writer,
+};
+use std::ops::Range;
+
+use super::{
+    materials::{format_color, BaseMaterial},
+    threemf_reader::ModelPart,
+};
+use crate::geometry::transform::{format_threemf_transform, threemf_transform_from_mat4};
+
+/// Byte ranges of the start tags of the elements at `path` in document order. The path
+/// holds the local names of the element and its ancestors, e.g. `["model", "build", "item"]`.
+pub fn element_tag_ranges(xml: &str, path: &[&str]) -> Result<Vec<Range<usize>>> {
+    let mut reader = Reader::from_str(xml);
+    let mut stack: Vec<Vec<u8>> = Vec::new();
+    let mut ranges = Vec::new();
+    let at_path = |stack: &[Vec<u8>]| {
+        stack.len() == path.len()
+            && stack
+                .iter()
+                .zip(path)
+                .all(|(name, expected)| name == expected.as_bytes())
+    };
+
+    loop {
+        let start = reader.buffer_position() as usize;
+        match reader.read_event()? {
+            Event::Start(element) => {
+                stack.push(element.local_name().as_ref().to_vec());
+                if at_path(&stack) {
+                    ranges.push(start..reader.buffer_position() as usize);
+                }
+            }
+            Event::Empty(element) => {
+                stack.push(element.local_name().as_ref().to_vec());
+                if at_path(&stack) {
+                    ranges.push(start..reader.buffer_position() as usize);
+                }
+                stack.pop();
+            }
+            Event::End(_) => {
+                stack.pop();
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(ranges)
+}
+
+/// Byte ranges of the elements at `path` in document order, from their start tag to the
+/// end of their end tag.
+pub fn element_ranges(xml: &str, path: &[&str]) -> Result<Vec<Range<usize>>> {
+    let mut reader = Reader::from_str(xml);
+    let mut stack: Vec<(Vec<u8>, usize)> = Vec::new();
+    let mut ranges = Vec::new();
+    let at_path = |stack: &[(Vec<u8>, usize)]| {
+        stack.len() == path.len()
+            && stack
+                .iter()
+                .zip(path)
+                .all(|((name, _), expected)| name == expected.as_bytes())
+    };
+
+    loop {
+        let start = reader.buffer_position() as usize;
+        match reader.read_event()? {
+            Event::Start(element) => stack.push((element.local_name().as_ref().to_vec(), start)),
+            Event::Empty(element) => {
+                stack.push((element.local_name().as_ref().to_vec(), start));
+                if at_path(&stack) {
+                    ranges.push(start..reader.buffer_position() as usize);
+                }
+                stack.pop();
+            }
+            Event::End(_) => {
+                if at_path(&stack) {
+                    ranges.push(stack[stack.len() - 1].1..reader.buffer_position() as usize);
+                }
+                stack.pop();
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(ranges)
+}
+
+/// Replacement of the bytes `range` of a text with `text`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Splice {
+    pub range: Range<usize>,
+    pub text: String,
+}
+
+impl Splice {
+    /// The splice that turns `old` into `new`, replacing what is between their common start
+    /// and end.
+    pub fn between(old: &str, new: &str) -> Splice {
+        let mut start = old
+            .bytes()
+            .zip(new.bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+        while !old.is_char_boundary(start) {
+            start -= 1;
+        }
+        let mut end = old
+            .bytes()
+            .rev()
+            .zip(new.bytes().rev())
+            .take_while(|(a, b)| a == b)
+            .count()
+            .min(old.len().min(new.len()) - start);
+        while !old.is_char_boundary(old.len() - end) || !new.is_char_boundary(new.len() - end) {
+            end -= 1;
+        }
+        Splice {
+            range: start..old.len() - end,
+            text: new[start..new.len() - end].to_string(),
+        }
+    }
+
+    /// Replaces the range of `xml` with the text and returns the splice that undoes it.
+    pub fn apply(&self, xml: &mut String) -> Result<Splice> {
+        let removed = xml
+            .get(self.range.clone())
+            .ok_or_else(|| anyhow!("Bytes {:?} are not a range of the text", self.range))?
+            .to_string();
+        xml.replace_range(self.range.clone(), &self.text);
+        Ok(Splice {
+            range: self.range.start..self.range.start + self.text.len(),
+            text: removed,
+        })
+    }
+}
+
+/// Sets, or with `None` removes, attribute `name` of the start tag at `tag` and returns the
+/// new text. Everything outside of the tag is kept as it is.
+pub fn set_attribute(
+    xml: &str,
+    tag: Range<usize>,
+    name: &str,
+    value: Option<&str>,
+) -> Result<String> {
+    let mut edited = xml.to_string();
+    attribute_splice(xml, tag, name, value)?.apply(&mut edited)?;
+    Ok(edited)
+}
+
+/// Splice that sets, or with `None` removes, attribute `name` of the start tag at `tag`.
+pub fn attribute_splice(
+    xml: &str,
+    tag: Range<usize>,
+    name: &str,
+    value: Option<&str>,
+) -> Result<Splice> {
+    check_name(name)?;
+    let mut reader = Reader::from_str(&xml[tag.clone()]);
+    let (element, empty) = match reader.read_event()? {
+        Event::Start(element) => (element, false),
+        Event::Empty(element) => (element, true),
+        _ => return Err(anyhow!("No start tag at byte {}", tag.start)),
+    };
+
+    let mut edited = BytesStart::new(std::str::from_utf8(element.name().as_ref())?.to_string());
+    let mut found = false;
+    for attribute in element.attributes() {
+        let attribute = attribute?;
+        if attribute.key.as_ref() != name.as_bytes() {
+            edited.push_attribute(attribute);
+            continue;
+        }
+        // the value takes the place of the first occurrence
+        if let (false, Some(value)) = (found, value) {
+            edited.push_attribute((name, value));
+        }
+        found = true;
+    }
+    if let (false, Some(value)) = (found, value) {
+        edited.push_attribute((name, value));
+    }
+
+    let mut writer = Writer::new(Vec::new());
+    writer.write_event(if empty {
+        Event::Empty(edited)
+    } else {
+        Event::Start(edited)
+    })?;
+    Ok(Splice {
+        range: tag,
+        text: String::from_utf8(writer.into_inner())?,
+    })
+}
+
+/// Splice that replaces the text of the element at `element`, whose start tag is at `tag`.
+/// Elements with child elements are not changed, their text is spread between them.
+pub fn text_splice(
+    xml: &str,
+    tag: Range<usize>,
+    element: Range<usize>,
+    text: &str,
+) -> Result<Splice> {
+    let escaped = escape(text);
+    if tag == element {
+        // an empty element gets an end tag to hold the text
+        let (start, name) = start_tag_of_empty(xml, tag.clone())?;
+        return Ok(Splice {
+            range: tag,
+            text: format!("{}{}</{}>", start, escaped, name),
+        });
+    }
+
+    let content = tag.end..end_tag_start(xml, &element)?;
+    let mut reader = Reader::from_str(&xml[content.clone()]);
+    loop {
+        match reader.read_event()? {
+            Event::Start(_) | Event::Empty(_) => {
+                return Err(anyhow!(
+                    "Element at byte {} has child elements",
+                    element.start
+                ))
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(Splice {
+        range: content,
+        text: escaped.into_owned(),
+    })
+}
+
+/// Splice that adds an empty element `name` as the last child of the element at `element`,
+/// whose start tag is at `tag`. An element on a line of its own gets the child on a line of
+/// its own, indented one step further.
+pub fn child_splice(
+    xml: &str,
+    tag: Range<usize>,
+    element: Range<usize>,
+    name: &str,
+) -> Result<Splice> {
+    check_name(name)?;
+    let line_start = xml[..tag.start]
+        .rfind('\n')
+        .map_or(0, |newline| newline + 1);
+    let indent = &xml[line_start..tag.start];
+    let own_line = indent.chars().all(char::is_whitespace);
+    let child = if own_line {
+        format!("\n{}  <{} />", indent, name)
+    } else {
+        format!("<{} />", name)
+    };
+    let closing_indent = if own_line {
+        format!("\n{}", indent)
+    } else {
+        String::new()
+    };
+
+    if tag == element {
+        let (start, parent) = start_tag_of_empty(xml, tag.clone())?;
+        return Ok(Splice {
+            range: tag,
+            text: format!("{}{}{}</{}>", start, child, closing_indent, parent),
+        });
+    }
+
+    // the child goes before the whitespace in front of the end tag
+    let end_tag = end_tag_start(xml, &element)?;
+    let content = &xml[tag.end..end_tag];
+    let position = tag.end + content.trim_end().len();
+    let trailing = &content[position - tag.end..];
+    let text = if own_line && !trailing.contains('\n') {
+        format!("{}{}", child, closing_indent)
+    } else {
+        child
+    };
+    Ok(Splice {
+        range: position..position,
+        text,
+    })
+}
+
+/// Splice that removes the element at `element`, with the indentation of its line when it
+/// starts on a line of its own.
+pub fn removal_splice(xml: &str, element: Range<usize>) -> Splice {
+    let before = &xml[..element.start];
+    let line_start = before.rfind('\n');
+    let start = match line_start {
+        Some(newline) if before[newline..].chars().all(char::is_whitespace) => newline,
+        _ => element.start,
+    };
+    Splice {
+        range: start..element.end,
+        text: String::new(),
+    }
+}
+
+/// Start tag and name of the empty element tag at `tag`, written as a start tag.
+fn start_tag_of_empty(xml: &str, tag: Range<usize>) -> Result<(String, String)> {
+    let mut reader = Reader::from_str(&xml[tag.clone()]);
+    let Event::Empty(element) = reader.read_event()? else {
+        return Err(anyhow!("No empty element tag at byte {}", tag.start));
+    };
+    let name = std::str::from_utf8(element.name().as_ref())?.to_string();
+    let attributes = xml[tag].trim_end_matches("/>").trim_end();
+    Ok((format!("{}>", attributes), name))
+}
+
+/// Byte where the end tag of the element at `element` starts.
+fn end_tag_start(xml: &str, element: &Range<usize>) -> Result<usize> {
+    xml[element.clone()]
+        .rfind("</")
+        .map(|offset| element.start + offset)
+        .ok_or_else(|| anyhow!("Element at byte {} has no end tag", element.start))
+}
+
+/// Checks that `name` can be written as the name of an element or an attribute.
+fn check_name(name: &str) -> Result<()> {
+    let invalid = |c: char| c.is_whitespace() || "<>&\"'/=".contains(c);
+    if name.is_empty() || name.contains(invalid) {
+        return Err(anyhow!("\"{}\" is not a valid name", name));
+    }
+    Ok(())
+}
+
+/// Changes the transform of build item `index` in the model and in the text of the part.
+pub fn set_build_item_transform(
+    part: &mut ModelPart,
+    index: usize,
+    transform: &DMat4,
+) -> Result<()> {
+    let item = part
+        .model
+        .build
+        .item
+        .get_mut(index)
+        .ok_or_else(|| anyhow!("Build item {} does not exist in {}", index, part.name))?;
+    let tag = element_tag_ranges(&part.xml, &["model", "build", "item"])?
+        .into_iter()
+        .nth(index)
+        .ok_or_else(|| {
+            anyhow!(
+                "Build item {} is missing from the text of {}",
+                index,
+                part.name
+            )
+        })?;
+
+    let transform = threemf_transform_from_mat4(transform);
+    part.xml = set_attribute(
+        &part.xml,
+        tag,
+        "transform",
+        Some(&format_threemf_transform(&transform)),
+    )?
+    .into();
+    item.transform = Some(transform);
+    Ok(())
+}
+
+/// Gives objects of the part a material, e.g. the colours of an imported mesh. The materials
+/// are written as a new `basematerials` resource ahead of the objects, which point into it
+/// with `pid` and `pindex`.
+pub fn add_object_materials(
+    part: &mut ModelPart,
+    materials: &[(usize, BaseMaterial)],
+) -> Result<()> {
+    if materials.is_empty() {
+        return Ok(());
+    }
+
+    // resource ids are shared by objects and property groups
+    let group_id = part
+        .model
+        .resources
+        .object
+        .iter()
+        .map(|object| object.id)
+        .chain(part.materials.property_groups.keys().copied())
+        .chain(part.materials.textures.keys().copied())
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let mut bases: Vec<&BaseMaterial> = Vec::new();
+    let mut xml = part.xml.to_string();
+    let object_tags = element_tag_ranges(&xml, &["model", "resources", "object"])?;
+    let mut properties = Vec::new();
+    for (tag, object) in object_tags.into_iter().zip(&part.model.resources.object) {
+        let Some((_, material)) = materials.iter().find(|(id, _)| *id == object.id) else {
+            continue;
+        };
+        let index = match bases.iter().position(|base| *base == material) {
+            Some(index) => index,
+            None => {
+                bases.push(material);
+                bases.len() - 1
+            }
+        };
+        properties.push((tag, index));
+    }
+    if properties.is_empty() {
+        return Err(anyhow!("Objects with materials are not in {}", part.name));
+    }
+
+    // from the end of the text so the tags in front keep their place
+    for (tag, index) in properties.into_iter().rev() {
+        let mut start_tag = xml[tag.clone()].to_string();
+        for (name, value) in [("pid", group_id), ("pindex", index)] {
+            start_tag = set_attribute(
+                &start_tag,
+                0..start_tag.len(),
+                name,
+                Some(&value.to_string()),
+            )?;
+        }
+        xml.replace_range(tag, &start_tag);
+    }
+
+    let resources = element_tag_ranges(&xml, &["model", "resources"])?
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("{} has no resources", part.name))?;
+    let group = bases.iter().fold(
+        format!("<basematerials id=\"{}\">", group_id),
+        |group, base| {
+            let [r, g, b, _] = base.display_color;
+            group
+                + &format!(
+                    "<base name=\"{}\" displaycolor=\"{}\" />",
+                    escape(&base.name),
+                    format_color([r, g, b])
+                )
+        },
+    ) + "</basematerials>";
+    xml.insert_str(resources.end, &group);
+
+    part.set_xml(xml, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::DVec3;
+
+    const BUILD: &str = r#"<model xmlns:p="http://schemas.microsoft.com/3dmanufacturing/production/2015/06">
+  <resources><item objectid="9" /></resources>
+  <build p:UUID="b">
+    <item objectid="1" p:path="/3D/a.model" />
+    <item objectid="2" transform="1 0 0 0 1 0 0 0 1 0 0 0"></item>
+  </build>
+</model>"#;
+
+    #[test]
+    fn test_element_tag_ranges_follow_the_path() {
+        let ranges = element_tag_ranges(BUILD, &["model", "build", "item"]).unwrap();
+
+        assert!(ranges.len() == 2, "Items outside of the build are counted");
+        assert!(
+            &BUILD[ranges[0].clone()] == r#"<item objectid="1" p:path="/3D/a.model" />"#,
+            "Range of the first item is wrong"
+        );
+    }
+
+    #[test]
+    fn test_set_attribute_keeps_the_rest() {
+        let ranges = element_tag_ranges(BUILD, &["model", "build", "item"]).unwrap();
+
+        let added = set_attribute(BUILD, ranges[0].clone(), "transform", Some("a & b")).unwrap();
+        assert!(
+            added.contains(r#"<item objectid="1" p:path="/3D/a.model" transform="a &amp; b"/>"#),
+            "Attribute is not added: {}",
+            added
+        );
+        assert!(
+            added.replace(r#" transform="a &amp; b"/>"#, " />") == BUILD,
+            "Text outside of the tag changed"
+        );
+
+        let removed = set_attribute(BUILD, ranges[1].clone(), "transform", None).unwrap();
+        assert!(
+            removed.contains(r#"<item objectid="2"></item>"#),
+            "Attribute is not removed: {}",
+            removed
+        );
+    }
+
+    #[test]
+    fn test_splices_edit_elements_and_undo() {
+        let tags = element_tag_ranges(BUILD, &["model", "build", "item"]).unwrap();
+        let build = element_tag_ranges(BUILD, &["model", "build"]).unwrap()[0].clone();
+        let build_element = build.start..BUILD.find("</build>").unwrap() + "</build>".len();
+        let second = tags[1].start..BUILD.find("</item>").unwrap() + "</item>".len();
+
+        let mut xml = BUILD.to_string();
+        let undo = child_splice(BUILD, build, build_element, "item")
+            .unwrap()
+            .apply(&mut xml)
+            .unwrap();
+        assert!(
+            xml.contains("</item>\n    <item />\n  </build>"),
+            "Child is not added as the last line of the build: {}",
+            xml
+        );
+        undo.apply(&mut xml).unwrap();
+        assert!(xml == BUILD, "Undoing the child leaves {}", xml);
+
+        let mut xml = BUILD.to_string();
+        text_splice(BUILD, tags[0].clone(), tags[0].clone(), "a < b")
+            .unwrap()
+            .apply(&mut xml)
+            .unwrap();
+        assert!(
+            xml.contains(r#"<item objectid="1" p:path="/3D/a.model">a &lt; b</item>"#),
+            "Empty element does not get the text: {}",
+            xml
+        );
+        assert!(
+            text_splice(BUILD, tags[1].clone(), second.clone(), "x").unwrap()
+                == Splice {
+                    range: tags[1].end..tags[1].end,
+                    text: "x".to_string()
+                },
+            "Text of an element without children is not replaced"
+        );
+
+        let mut xml = BUILD.to_string();
+        removal_splice(BUILD, second).apply(&mut xml).unwrap();
+        assert!(
+            xml.contains("/>\n  </build>"),
+            "Element is not removed with its line: {}",
+            xml
+        );
+        assert!(
+            child_splice(BUILD, tags[0].clone(), tags[0].clone(), "a b").is_err(),
+            "Name with a space is accepted"
+        );
+    }
+
+    #[test]
+    fn test_splice_between_texts_replaces_the_difference() {
+        let edited = BUILD.replace(r#"objectid="2""#, r#"objectid="12""#);
+        let splice = Splice::between(BUILD, &edited);
+        assert!(
+            splice.text == "1" && splice.range.is_empty(),
+            "Splice is not the inserted digit: {:?}",
+            splice
+        );
+        let mut xml = BUILD.to_string();
+        splice.apply(&mut xml).unwrap();
+        assert!(xml == edited, "Splice does not make the edited text");
+
+        let splice = Splice::between("aäa", "aöa");
+        assert!(
+            splice.range == (1..3) && splice.text == "ö",
+            "Splice splits a character: {:?}",
+            splice
+        );
+    }
+
+    #[test]
+    fn test_build_item_transform_is_set_in_model_and_text() {
+        let xml = r#"<model unit="millimeter" xmlns="http://schemas.microsoft.com/3dmanufacturing/core/2015/02">
+  <resources>
+    <object id="1" type="model"><mesh><vertices /><triangles /></mesh></object>
+  </resources>
+  <build>
+    <item objectid="1" />
+    <item objectid="1" />
+  </build>
+</model>"#
+            .to_string();
+        let mut part = ModelPart {
+            name: "/3D/3dmodel.model".to_string(),
+            model: super::super::threemf_reader::get_model_from_3mf_model_file_string(&xml)
+                .unwrap(),
+            production: Default::default(),
+            materials: Default::default(),
+            xml: xml.into(),
+        };
+
+        let transform = DMat4::from_translation(DVec3::new(5.0, 0.0, 1.5));
+        set_build_item_transform(&mut part, 1, &transform).unwrap();
+
+        assert!(
+            part.xml
+                .contains(r#"<item objectid="1" transform="1 0 0 0 1 0 0 0 1 5 0 1.5"/>"#),
+            "Transform is not written to the text: {}",
+            part.xml
+        );
+        assert!(
+            part.model.build.item[0].transform.is_none()
+                && part.model.build.item[1].transform.unwrap()[9] == 5.0,
+            "Transform is not set on the second item of the model"
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use glam::DVec3;
+
+use crate::geometry::analysis::{MeshReport, ModelReport};
+
+/// Draws the measurements of every object and build item of the model.
+pub fn model_report_ui(ui: &mut egui::Ui, report: &ModelReport) {
+    ui.label(format!(
+        "Unit - {} ({} mm)",
+        report.unit, report.millimeters_per_unit
+    ));
+
+    ui.heading("Build items");
+    for item in &report.items {
+        egui::CollapsingHeader::new(format!("item {} - object {}", item.index, item.object_id))
+            .id_source(("analysis item", item.index))
+            .default_open(true)
+            .show(ui, |ui| mesh_report_ui(ui, &item.report, report.unit));
+    }
+
+    ui.heading("Objects");
+    for object in &report.objects {
+        let name = match &object.name {
+            Some(name) => format!("object {} - {}", object.object_id, name),
+            None => format!("object {}", object.object_id),
+        };
+        egui::CollapsingHeader::new(name)
+            .id_source(("analysis object", &object.part, object.object_id))
+            .show(ui, |ui| {
+                ui.label(format!("part - {}", object.part));
+                mesh_report_ui(ui, &object.report, report.unit);
+            });
+    }
+}
+
+fn mesh_report_ui(ui: &mut egui::Ui, report: &MeshReport, unit: &str) {
+    egui::Grid::new(ui.next_auto_id())
+        .num_columns(2)
+        .striped(true)
+        .show(ui, |ui| {
+            let mut row = |name: &str, value: String| {
+                ui.label(name);
+                ui.label(value);
+                ui.end_row();
+            };
+
+            row("Vertices", report.vertex_count.to_string());
+            row("Triangles", report.triangle_count.to_string());
+            row("Volume", format!("{:.3} {}³", report.volume, unit));
+            row(
+                "Surface area",
+                format!("{:.3} {}²", report.surface_area, unit),
+            );
+            if let Some((min, max)) = report.bounding_box {
+                row("Size", format_size(max - min, unit));
+                row("Min", format_point(min));
+                row("Max", format_point(max));
+            }
+            if let Some(oriented) = &report.oriented_box {
+                row("Oriented size", format_size(oriented.size(), unit));
+            }
+            if let Some(centroid) = report.centroid {
+                row("Centroid", format_point(centroid));
+            }
+        });
+}
+
+fn format_size(size: DVec3, unit: &str) -> String {
+    format!("{:.3} x {:.3} x {:.3} {}", size.x, size.y, size.z, unit)
+}
+
+fn format_point(point: DVec3) -> String {
+    format!("{:.3}, {:.3}, {:.3}", point.x, point.y, point.z)
+}
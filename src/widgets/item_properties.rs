@@ -0,0 +1,142 @@
+use glam::{DMat4, DVec3};
+
+use crate::{
+    geometry::transform::{snap, TransformComponents},
+    renderer::gizmo::{Gizmo, GizmoMode, TransformEdit},
+};
+
+/// Draws the choice of build item, the gizmo settings and the transform of the selected
+/// item as numbers. `items` labels every build item and `transform` is the one of the
+/// selected item, in the unit of the model. Returns the edited transform.
+pub fn item_properties_ui(
+    ui: &mut egui::Ui,
+    items: &[String],
+    selected: &mut Option<usize>,
+    transform: Option<DMat4>,
+    gizmo: &mut Gizmo,
+    unit: &str,
+) -> Option<TransformEdit> {
+    let selected_text = selected
+        .and_then(|index| items.get(index))
+        .cloned()
+        .unwrap_or_else(|| "None".to_string());
+    egui::ComboBox::from_id_source("build_item")
+        .selected_text(selected_text)
+        .show_ui(ui, |ui| {
+            ui.selectable_value(selected, None, "None");
+            for (index, label) in items.iter().enumerate() {
+                ui.selectable_value(selected, Some(index), label);
+            }
+        });
+
+    ui.horizontal(|ui| {
+        for (label, mode) in [
+            ("Move", GizmoMode::Translate),
+            ("Rotate", GizmoMode::Rotate),
+            ("Scale", GizmoMode::Scale),
+        ] {
+            ui.selectable_value(&mut gizmo.mode, mode, label);
+        }
+    });
+    let steps = &mut gizmo.snap;
+    ui.checkbox(&mut steps.enabled, "Snap");
+    ui.add_enabled_ui(steps.enabled, |ui| {
+        egui::Grid::new("snap_steps").show(ui, |ui| {
+            ui.label("Move step");
+            ui.add(
+                egui::DragValue::new(&mut steps.translation)
+                    .speed(0.1)
+                    .range(0.001..=1000.0)
+                    .suffix(format!(" {}", unit)),
+            );
+            ui.end_row();
+            ui.label("Rotate step");
+            ui.add(
+                egui::DragValue::new(&mut steps.rotation_degrees)
+                    .speed(1.0)
+                    .range(0.1..=180.0)
+                    .suffix("°"),
+            );
+            ui.end_row();
+            ui.label("Scale step");
+            ui.add(
+                egui::DragValue::new(&mut steps.scale)
+                    .speed(0.01)
+                    .range(0.001..=10.0),
+            );
+            ui.end_row();
+        });
+    });
+
+    ui.separator();
+    let transform = transform?;
+    let steps = gizmo.snap.clone();
+    let mut components = TransformComponents::from_mat4(&transform);
+    let mut changed = false;
+    let mut dragging = false;
+    let mut finished = false;
+
+    egui::Grid::new("item_transform").show(ui, |ui| {
+        let mut row = |ui: &mut egui::Ui,
+                       label: &str,
+                       values: &mut DVec3,
+                       (speed, step): (f64, f64),
+                       suffix: &str| {
+            ui.label(label);
+            let DVec3 { x, y, z } = values;
+            for value in [x, y, z] {
+                // one step per point keeps dragged values on the grid
+                let speed = if steps.enabled { step } else { speed };
+                let response = ui.add(egui::DragValue::new(value).speed(speed).suffix(suffix));
+                if response.changed() {
+                    changed = true;
+                    if response.dragged() {
+                        dragging = true;
+                        if steps.enabled {
+                            *value = snap(*value, step);
+                        }
+                    }
+                }
+                finished |= response.drag_stopped();
+            }
+            ui.end_row();
+        };
+        row(
+            ui,
+            "Position",
+            &mut components.translation,
+            (0.1, steps.translation),
+            &format!(" {}", unit),
+        );
+        row(
+            ui,
+            "Rotation",
+            &mut components.rotation_degrees,
+            (1.0, steps.rotation_degrees),
+            "°",
+        );
+        row(ui, "Scale", &mut components.scale, (0.01, steps.scale), "");
+    });
+    // a scale of zero collapses the item and cannot be undone by editing it again
+    components.scale = components.scale.max(DVec3::splat(0.001));
+
+    if ui.button("Reset rotation and scale").clicked() {
+        components.rotation_degrees = DVec3::ZERO;
+        components.scale = DVec3::ONE;
+        changed = true;
+    }
+
+    if changed {
+        let edited = components.to_mat4();
+        Some(if dragging {
+            TransformEdit::Dragging(edited)
+        } else {
+            TransformEdit::Finished(edited)
+        })
+    } else if finished {
+        // the last change of a drag is already shown, it only needs to be kept
+        Some(TransformEdit::Finished(transform))
+    } else {
+        None
+    }
+}
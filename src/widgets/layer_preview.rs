@@ -0,0 +1,141 @@
+use egui::{emath::RectTransform, pos2, vec2, Color32, Pos2, Rect, Sense, Shape, Stroke, Vec2};
+
+use crate::slicer::planar_slicer::Layer;
+
+const ZOOM_SPEED: f32 = 0.002;
+const OUTER_COLOR: Color32 = Color32::from_rgb(230, 150, 60);
+const HOLE_COLOR: Color32 = Color32::from_rgb(80, 170, 230);
+
+/// A 2D view of one layer at a time with a slider to move through the layers.
+///
+/// Drag to pan, scroll to zoom around the pointer and double click to fit.
+pub struct LayerPreview {
+    layer_index: usize,
+    /// Bounds of every layer, so the scale stays the same while scrubbing.
+    bounds: Rect,
+    zoom: f32,
+    pan: Vec2,
+}
+
+impl LayerPreview {
+    pub fn new(layers: &[Layer]) -> Self {
+        let mut bounds = Rect::NOTHING;
+        for point in layers
+            .iter()
+            .flat_map(|layer| &layer.contours)
+            .flat_map(|contour| &contour.points)
+        {
+            bounds.extend_with(pos2(point.x as f32, point.y as f32));
+        }
+        if !bounds.is_positive() {
+            bounds = Rect::from_center_size(bounds.center(), vec2(1.0, 1.0));
+        }
+
+        Self {
+            layer_index: 0,
+            bounds,
+            zoom: 1.0,
+            pan: Vec2::ZERO,
+        }
+    }
+
+    pub fn ui(&mut self, ui: &mut egui::Ui, layers: &[Layer]) {
+        let Some(last_index) = layers.len().checked_sub(1) else {
+            ui.label("No layers");
+            return;
+        };
+        self.layer_index = self.layer_index.min(last_index);
+
+        ui.add(egui::Slider::new(&mut self.layer_index, 0..=last_index).text("Layer"));
+        let layer = &layers[self.layer_index];
+        let holes = layer
+            .contours
+            .iter()
+            .filter(|contour| contour.is_hole)
+            .count();
+        ui.horizontal(|ui| {
+            ui.label(format!("Z - {:.3}", layer.z));
+            ui.separator();
+            ui.label(format!("Area - {:.3}", layer.area()));
+        });
+        ui.horizontal(|ui| {
+            ui.label(format!("Islands - {}", layer.contours.len() - holes));
+            ui.separator();
+            ui.label(format!("Holes - {}", holes));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button("Fit").clicked() {
+                    self.fit();
+                }
+            });
+        });
+
+        egui::Frame::canvas(ui.style()).show(ui, |ui| {
+            let (rect, response) = ui.allocate_exact_size(ui.available_size(), Sense::drag());
+            let response = response.interact(Sense::click());
+            self.handle_input(ui, rect, &response);
+
+            let to_screen = self.to_screen(rect);
+            let painter = ui.painter_at(rect);
+            for contour in &layer.contours {
+                let points: Vec<Pos2> = contour
+                    .points
+                    .iter()
+                    .map(|point| to_screen * pos2(point.x as f32, point.y as f32))
+                    .collect();
+                let color = if contour.is_hole {
+                    HOLE_COLOR
+                } else {
+                    OUTER_COLOR
+                };
+                painter.add(Shape::closed_line(points, Stroke::new(1.5, color)));
+            }
+        });
+    }
+
+    fn fit(&mut self) {
+        self.zoom = 1.0;
+        self.pan = Vec2::ZERO;
+    }
+
+    fn handle_input(&mut self, ui: &egui::Ui, rect: Rect, response: &egui::Response) {
+        if response.dragged() {
+            self.pan += response.drag_delta();
+        }
+        if response.double_clicked() {
+            self.fit();
+        }
+
+        if let (true, Some(pointer)) = (response.hovered(), response.hover_pos()) {
+            let scroll = ui.input(|i| i.smooth_scroll_delta.y);
+            if scroll != 0.0 {
+                // keep the point under the pointer in place
+                let factor = (scroll * ZOOM_SPEED).exp();
+                let from_center = pointer - rect.center();
+                self.pan = from_center - (from_center - self.pan) * factor;
+                self.zoom *= factor;
+            }
+        }
+    }
+
+    /// Maps layer coordinates into `rect` with Y up.
+    fn to_screen(&self, rect: Rect) -> RectTransform {
+        let fitted =
+            (rect.width() / self.bounds.width()).min(rect.height() / self.bounds.height()) * 0.9;
+        let scale = fitted * self.zoom;
+        let center = rect.center() + self.pan;
+        let size = rect.size() / scale;
+
+        // flipping the source rectangle turns Y up
+        let from = Rect::from_min_max(
+            pos2(
+                self.bounds.center().x - size.x / 2.0,
+                self.bounds.center().y + size.y / 2.0,
+            ),
+            pos2(
+                self.bounds.center().x + size.x / 2.0,
+                self.bounds.center().y - size.y / 2.0,
+            ),
+        );
+        RectTransform::from_to(from, Rect::from_center_size(center, rect.size()))
+    }
+}
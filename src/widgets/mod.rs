@@ -1 +1,12 @@
+pub mod analysis;
+pub mod item_properties;
+pub mod layer_preview;
+pub mod print_estimate;
+pub mod printer;
+pub mod selection;
+pub mod text_edits;
+pub mod thumbnails;
+pub mod toolpath;
 pub mod tree;
+pub mod tree_view;
+pub mod validation;
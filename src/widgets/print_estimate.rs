@@ -0,0 +1,90 @@
+use crate::gcode::print_estimate::{FilamentSettings, MachineLimits, PrintEstimate};
+
+const BAR_HEIGHT: f32 = 14.0;
+
+/// Draws the fields of the printer limits and the filament. Returns whether any changed.
+pub fn estimate_settings_ui(
+    ui: &mut egui::Ui,
+    limits: &mut MachineLimits,
+    filament: &mut FilamentSettings,
+) -> bool {
+    let mut changed = false;
+    egui::Grid::new("estimate_settings").show(ui, |ui| {
+        let mut field = |ui: &mut egui::Ui, label: &str, value: &mut f64, suffix: &str| {
+            ui.label(label);
+            changed |= ui
+                .add(
+                    egui::DragValue::new(value)
+                        .speed(0.1)
+                        .range(0.001..=f64::MAX)
+                        .suffix(suffix),
+                )
+                .changed();
+            ui.end_row();
+        };
+        field(ui, "Acceleration", &mut limits.max_acceleration, " mm/s²");
+        field(ui, "Jerk", &mut limits.max_jerk, " mm/s");
+        field(ui, "Max speed", &mut limits.max_feedrate, " mm/s");
+        field(ui, "Filament diameter", &mut filament.diameter, " mm");
+        field(ui, "Density", &mut filament.density, " g/cm³");
+        field(ui, "Price", &mut filament.price_per_kg, " /kg");
+    });
+    changed
+}
+
+/// Draws the totals and the time of every layer as a bar.
+pub fn print_estimate_ui(ui: &mut egui::Ui, estimate: &PrintEstimate) {
+    ui.label(format!("Time - {}", format_duration(estimate.total_time())));
+    ui.label(format!(
+        "Filament - {:.2} m",
+        estimate.filament_length / 1000.0
+    ));
+    ui.label(format!("Weight - {:.1} g", estimate.filament_weight));
+    ui.label(format!("Cost - {:.2}", estimate.filament_cost));
+    ui.separator();
+
+    let longest = estimate.layer_times.iter().copied().fold(0.0, f64::max);
+    egui::ScrollArea::vertical().auto_shrink(false).show_rows(
+        ui,
+        BAR_HEIGHT,
+        estimate.layer_times.len(),
+        |ui, rows| {
+            for index in rows {
+                let time = estimate.layer_times[index];
+                ui.horizontal(|ui| {
+                    ui.add_sized([40.0, BAR_HEIGHT], egui::Label::new(index.to_string()));
+                    let width = ui.available_width() - 70.0;
+                    let (rect, response) = ui.allocate_exact_size(
+                        egui::vec2(width.max(0.0), BAR_HEIGHT),
+                        egui::Sense::hover(),
+                    );
+                    let fraction = if longest > 0.0 {
+                        (time / longest) as f32
+                    } else {
+                        0.0
+                    };
+                    let mut bar = rect.shrink2(egui::vec2(0.0, 2.0));
+                    bar.set_width(bar.width() * fraction);
+                    ui.painter()
+                        .rect_filled(bar, 1.0, ui.visuals().selection.bg_fill);
+                    response.on_hover_text(format!("Layer {}", index));
+                    ui.label(format_duration(time));
+                });
+            }
+        },
+    );
+}
+
+/// E.g. `1h 02m 03s`, `2m 03s` or `3.4s`.
+fn format_duration(seconds: f64) -> String {
+    if seconds < 60.0 {
+        return format!("{:.1}s", seconds);
+    }
+    let total = seconds.round() as u64;
+    let (hours, minutes, seconds) = (total / 3600, total / 60 % 60, total % 60);
+    if hours > 0 {
+        format!("{}h {:02}m {:02}s", hours, minutes, seconds)
+    } else {
+        format!("{}m {:02}s", minutes, seconds)
+    }
+}
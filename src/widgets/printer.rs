@@ -0,0 +1,81 @@
+use crate::config::printer_profiles::{BedOrigin, BedShape, PrinterProfile, PrinterProfiles};
+
+/// Draws the choice of printer and the fields of the chosen one. Returns whether the
+/// build volume changed.
+pub fn printer_profiles_ui(ui: &mut egui::Ui, profiles: &mut PrinterProfiles) -> bool {
+    let before = profiles.selected().clone();
+    let selected_before = profiles.selected;
+
+    egui::ComboBox::from_id_source("printer_profile")
+        .selected_text(profiles.selected().name.clone())
+        .show_ui(ui, |ui| {
+            for (index, profile) in profiles.profiles.iter().enumerate() {
+                ui.selectable_value(&mut profiles.selected, index, &profile.name);
+            }
+        });
+    ui.horizontal(|ui| {
+        if ui.button("Add").clicked() {
+            let profile = PrinterProfile {
+                name: format!("{} copy", profiles.selected().name),
+                ..profiles.selected().clone()
+            };
+            profiles.profiles.push(profile);
+            profiles.selected = profiles.profiles.len() - 1;
+        }
+        if ui
+            .add_enabled(profiles.profiles.len() > 1, egui::Button::new("Remove"))
+            .clicked()
+        {
+            profiles.profiles.remove(profiles.selected);
+            profiles.selected = profiles.selected.min(profiles.profiles.len() - 1);
+        }
+    });
+
+    let profile = profiles.selected_mut();
+    egui::Grid::new("printer_profile_fields").show(ui, |ui| {
+        ui.label("Name");
+        ui.text_edit_singleline(&mut profile.name);
+        ui.end_row();
+        ui.label("Shape");
+        ui.horizontal(|ui| {
+            for shape in [BedShape::Rectangular, BedShape::Circular] {
+                ui.selectable_value(&mut profile.shape, shape, shape.to_string());
+            }
+        });
+        ui.end_row();
+        let width_label = match profile.shape {
+            BedShape::Rectangular => "Width",
+            BedShape::Circular => "Diameter",
+        };
+        let field = |ui: &mut egui::Ui, label: &str, value: &mut f64| {
+            ui.label(label);
+            ui.add(
+                egui::DragValue::new(value)
+                    .speed(1.0)
+                    .range(1.0..=10_000.0)
+                    .suffix(" mm"),
+            );
+            ui.end_row();
+        };
+        field(ui, width_label, &mut profile.bed_width);
+        if profile.shape == BedShape::Rectangular {
+            field(ui, "Depth", &mut profile.bed_depth);
+        }
+        field(ui, "Height", &mut profile.max_height);
+        ui.label("Origin");
+        ui.horizontal(|ui| {
+            for origin in [BedOrigin::FrontLeft, BedOrigin::Center] {
+                ui.selectable_value(&mut profile.origin, origin, origin.to_string());
+            }
+        });
+        ui.end_row();
+    });
+
+    let after = profiles.selected();
+    // renaming does not change the volume
+    selected_before != profiles.selected
+        || PrinterProfile {
+            name: after.name.clone(),
+            ..before
+        } != *after
+}
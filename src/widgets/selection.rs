@@ -0,0 +1,208 @@
+use anyhow::{anyhow, Result};
+use std::collections::HashSet;
+
+use super::tree::{NodeId, TreeStore};
+use crate::{
+    geometry::build::{flatten_object, TriangleSet},
+    threemf::threemf_reader::ModelParts,
+};
+
+/// What of a model a node of the trees stands for, shared by the tree, the text and the
+/// viewport when one of them selects it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModelSelection {
+    /// An object of a model part, with the objects of its components.
+    Object { part: String, object_id: usize },
+    /// A triangle of the mesh of an object, by its index in the mesh.
+    Triangle {
+        part: String,
+        object_id: usize,
+        index: usize,
+    },
+    /// A build item of a model part, by its index in the build.
+    Item { part: String, index: usize },
+}
+
+/// What of the model the node is: a triangle, a build item, or the object it is in.
+pub fn model_selection(store: &TreeStore, id: NodeId) -> Option<ModelSelection> {
+    let part = store.document_name(id)?.to_string();
+    let node = store.node(id);
+    let parent_name = node.parent.map(|parent| &*store.node(parent).name);
+
+    if &*node.name == "item" && parent_name == Some("build") {
+        let index = sibling_index(store, id)?;
+        return Some(ModelSelection::Item { part, index });
+    }
+
+    let mut ancestor = Some(id);
+    while let Some(current) = ancestor {
+        if &*store.node(current).name == "object" {
+            let object_id = store.attribute(current, "id")?.trim().parse().ok()?;
+            if &*node.name == "triangle" && parent_name == Some("triangles") {
+                let index = sibling_index(store, id)?;
+                return Some(ModelSelection::Triangle {
+                    part,
+                    object_id,
+                    index,
+                });
+            }
+            return Some(ModelSelection::Object { part, object_id });
+        }
+        ancestor = store.node(current).parent;
+    }
+    None
+}
+
+/// Node of the selected part of the model, the other way round of [`model_selection`].
+pub fn selection_node(store: &TreeStore, selection: &ModelSelection) -> Option<NodeId> {
+    let (part, object_id) = match selection {
+        ModelSelection::Object { part, object_id }
+        | ModelSelection::Triangle {
+            part, object_id, ..
+        } => (part, *object_id),
+        ModelSelection::Item { part, index } => {
+            let build = store.find_child(Some(store.document_root(part)?), "build")?;
+            return nth_child(store, build, "item", *index);
+        }
+    };
+
+    let resources = store.find_child(Some(store.document_root(part)?), "resources")?;
+    let object = store
+        .node(resources)
+        .children
+        .iter()
+        .copied()
+        .find(|&child| {
+            &*store.node(child).name == "object"
+                && store
+                    .attribute(child, "id")
+                    .and_then(|id| id.trim().parse().ok())
+                    == Some(object_id)
+        })?;
+    let ModelSelection::Triangle { index, .. } = selection else {
+        return Some(object);
+    };
+    let mesh = store.find_child(Some(object), "mesh")?;
+    let triangles = store.find_child(Some(mesh), "triangles")?;
+    nth_child(store, triangles, "triangle", *index)
+}
+
+/// Triangles of every mesh object drawn for the selection.
+pub fn selected_triangles(
+    model_parts: &ModelParts,
+    selection: &ModelSelection,
+) -> Result<TriangleSet> {
+    let mesh = match selection {
+        ModelSelection::Triangle {
+            part,
+            object_id,
+            index,
+        } => {
+            return Ok(TriangleSet::from([(
+                (part.clone(), *object_id),
+                HashSet::from([*index]),
+            )]));
+        }
+        ModelSelection::Object { part, object_id } => {
+            flatten_object(model_parts, part, *object_id)?
+        }
+        ModelSelection::Item { part, index } => {
+            let model_part = model_parts
+                .parts
+                .get(part)
+                .ok_or_else(|| anyhow!("Model part {} does not exist", part))?;
+            let item = model_part
+                .model
+                .build
+                .item
+                .get(*index)
+                .ok_or_else(|| anyhow!("Build item {} does not exist", index))?;
+            flatten_object(model_parts, model_part.item_part(*index), item.objectid)?
+        }
+    };
+
+    let mut triangles = TriangleSet::new();
+    for &(object, index) in &mesh.triangle_sources {
+        triangles
+            .entry(mesh.objects[object as usize].clone())
+            .or_default()
+            .insert(index as usize);
+    }
+    Ok(triangles)
+}
+
+/// Index of the node among the children of its parent of the same name.
+fn sibling_index(store: &TreeStore, id: NodeId) -> Option<usize> {
+    let name = &store.node(id).name;
+    store
+        .node(store.node(id).parent?)
+        .children
+        .iter()
+        .filter(|&&child| store.node(child).name == *name)
+        .position(|&child| child == id)
+}
+
+fn nth_child(store: &TreeStore, parent: NodeId, name: &str, index: usize) -> Option<NodeId> {
+    store
+        .node(parent)
+        .children
+        .iter()
+        .copied()
+        .filter(|&child| &*store.node(child).name == name)
+        .nth(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::open_model_parts_from_test_resource;
+
+    #[test]
+    fn test_selection_goes_both_ways() {
+        let model_parts = open_model_parts_from_test_resource("box.3mf");
+        let root = model_parts.root_part();
+        let store = TreeStore::from_xml(&root.name, root.xml.clone()).unwrap();
+        let object_id = root.model.resources.object[0].id;
+
+        let triangle = ModelSelection::Triangle {
+            part: root.name.clone(),
+            object_id,
+            index: 5,
+        };
+        let node = selection_node(&store, &triangle).unwrap();
+        assert!(
+            &*store.node(node).name == "triangle",
+            "Node of a triangle is {}",
+            store.node(node).name
+        );
+        assert!(
+            model_selection(&store, node) == Some(triangle),
+            "Triangle node does not select its triangle"
+        );
+
+        let mesh = store.node(store.node(node).parent.unwrap()).parent.unwrap();
+        let object = ModelSelection::Object {
+            part: root.name.clone(),
+            object_id,
+        };
+        assert!(
+            model_selection(&store, mesh) == Some(object.clone()),
+            "Mesh of an object does not select the object"
+        );
+        let triangles = selected_triangles(&model_parts, &object).unwrap();
+        assert!(
+            triangles[&(root.name.clone(), object_id)].len() == 12,
+            "Not every triangle of the box is selected"
+        );
+
+        let item = ModelSelection::Item {
+            part: root.name.clone(),
+            index: 0,
+        };
+        let node = selection_node(&store, &item).unwrap();
+        assert!(
+            model_selection(&store, node) == Some(item),
+            "Item node does not select its build item"
+        );
+    }
+}
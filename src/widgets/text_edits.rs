@@ -0,0 +1,160 @@
+use quick_xml::{events::Event, Reader};
+
+/// Seconds without typing before the edited text is read again.
+pub const PARSE_DELAY: f64 = 0.5;
+
+/// Why the edited text could not be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextError {
+    pub message: String,
+    /// Byte of the text where the error was found, when it is known.
+    pub offset: Option<usize>,
+}
+
+impl From<anyhow::Error> for TextError {
+    fn from(error: anyhow::Error) -> Self {
+        Self {
+            message: format!("{:#}", error),
+            offset: None,
+        }
+    }
+}
+
+/// Typing in the text view that is not yet read into the document or saved.
+#[derive(Debug, Default)]
+pub struct TextEdits {
+    /// Time of the last edit that is not read yet.
+    changed_at: Option<f64>,
+    /// Whether the text differs from the file it was read from, by typing or otherwise.
+    pub unsaved: bool,
+    /// Why the text could not be read the last time it was.
+    pub error: Option<TextError>,
+}
+
+impl TextEdits {
+    pub fn changed(&mut self, now: f64) {
+        self.changed_at = Some(now);
+        self.unsaved = true;
+    }
+
+    /// Whether edits are waiting to be read.
+    pub fn pending(&self) -> bool {
+        self.changed_at.is_some()
+    }
+
+    /// Whether the waiting edits are read now, once the typing stopped for [`PARSE_DELAY`].
+    pub fn due(&self, now: f64) -> bool {
+        self.changed_at.is_some_and(|at| now - at >= PARSE_DELAY)
+    }
+
+    /// Whether the document holds the text as it is shown, so offsets into the text are
+    /// offsets into the document.
+    pub fn is_read(&self) -> bool {
+        self.changed_at.is_none() && self.error.is_none()
+    }
+
+    pub fn read(&mut self, result: Result<(), TextError>) {
+        self.changed_at = None;
+        self.error = result.err();
+    }
+}
+
+/// First place where `xml` is not well-formed, like a tag that is not closed.
+pub fn xml_syntax_error(xml: &str) -> Option<TextError> {
+    let mut reader = Reader::from_str(xml);
+    // start tags that are not closed yet
+    let mut open = Vec::new();
+    loop {
+        let start = reader.buffer_position() as usize;
+        match reader.read_event() {
+            Ok(Event::Start(element)) => open.push((
+                start,
+                String::from_utf8_lossy(element.name().as_ref()).into_owned(),
+            )),
+            Ok(Event::End(_)) => {
+                open.pop();
+            }
+            Ok(Event::Eof) => break,
+            Ok(_) => {}
+            Err(e) => {
+                return Some(TextError {
+                    message: e.to_string(),
+                    offset: Some(reader.error_position() as usize),
+                })
+            }
+        }
+    }
+    open.pop().map(|(start, name)| TextError {
+        message: format!("Element {} is not closed", name),
+        offset: Some(start),
+    })
+}
+
+/// Line and column of the byte `offset` of the text, counted from 1 with columns in
+/// characters.
+pub fn line_column(text: &str, offset: usize) -> (usize, usize) {
+    let mut end = offset.min(text.len());
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    let before = &text[..end];
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_syntax_errors_are_located() {
+        let unmatched = "<model>\n  <build>\n    <item>\n  </build>\n</model>";
+        let error = xml_syntax_error(unmatched).unwrap();
+        let (line, column) = line_column(unmatched, error.offset.unwrap());
+        assert!(
+            line == 4,
+            "Unmatched end tag is found on line {} column {}: {}",
+            line,
+            column,
+            error.message
+        );
+
+        let unclosed = "<model>\n  <build>\n  </build>\n";
+        let error = xml_syntax_error(unclosed).unwrap();
+        assert!(
+            error.offset.map(|offset| line_column(unclosed, offset)) == Some((1, 1)),
+            "Unclosed element is not found at its start tag: {:?}",
+            error
+        );
+        assert!(
+            xml_syntax_error("<model><build /></model>").is_none(),
+            "Well-formed text has an error"
+        );
+    }
+
+    #[test]
+    fn test_edits_are_read_after_the_typing_stops() {
+        let mut edits = TextEdits::default();
+        edits.changed(10.0);
+        assert!(
+            edits.pending() && !edits.due(10.0 + PARSE_DELAY / 2.0),
+            "Edits are read while typing"
+        );
+        assert!(
+            edits.due(10.0 + PARSE_DELAY),
+            "Edits are not read after the delay"
+        );
+
+        edits.read(Err(TextError {
+            message: "bad".to_string(),
+            offset: None,
+        }));
+        assert!(
+            !edits.pending() && !edits.is_read() && edits.unsaved,
+            "Text with an error counts as read into the document"
+        );
+    }
+}
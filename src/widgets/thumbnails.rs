@@ -0,0 +1,177 @@
+use egui::load::Bytes;
+use std::{
+    collections::HashMap,
+    ffi::OsStr,
+    fs,
+    hash::{DefaultHasher, Hash, Hasher},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    config::recent_files::RecentFiles,
+    threemf::{
+        threemf_package::{read_thumbnail, Package},
+        threemf_reader::{get_object_thumbnails_from_3mf_model_file_string, ModelParts},
+    },
+};
+
+const HEADER_SIZE: f32 = 32.0;
+const GALLERY_SIZE: f32 = 128.0;
+const PREVIEW_SIZE: f32 = 256.0;
+
+/// An image carried by a file, decoded by the image loaders of `egui_extras`.
+#[derive(Clone)]
+pub struct Thumbnail {
+    pub label: String,
+    uri: String,
+    bytes: Bytes,
+}
+
+impl Thumbnail {
+    /// `source` names where the image comes from, e.g. the file and the part name. With a
+    /// hash of the data it makes the uri the decoded image is cached under, so an image
+    /// that changed on disk is decoded again.
+    pub fn new(label: &str, source: &str, data: Vec<u8>) -> Self {
+        let mut hasher = DefaultHasher::new();
+        data.hash(&mut hasher);
+        Self {
+            label: label.to_string(),
+            uri: format!("bytes://{}#{:016x}", source, hasher.finish()),
+            bytes: data.into(),
+        }
+    }
+
+    pub fn image(&self) -> egui::Image<'static> {
+        egui::Image::from_bytes(self.uri.clone(), self.bytes.clone())
+    }
+}
+
+/// The package thumbnail followed by the thumbnails of objects that have one.
+pub fn package_thumbnails(
+    path: &Path,
+    package: &Package,
+    model_parts: &ModelParts,
+) -> Vec<Thumbnail> {
+    let source = path.display().to_string();
+    let mut thumbnails = Vec::new();
+    if let Some(part) = package.thumbnail() {
+        thumbnails.push(Thumbnail::new(
+            "Package",
+            &format!("{}{}", source, part.name),
+            part.data.clone(),
+        ));
+    }
+
+    for model_part in model_parts.parts.values() {
+        let objects = match get_object_thumbnails_from_3mf_model_file_string(
+            &model_part.name,
+            &model_part.xml,
+        ) {
+            Ok(objects) => objects,
+            Err(e) => {
+                log::warn!("Failed to read thumbnails of {}: {}", model_part.name, e);
+                continue;
+            }
+        };
+        for (object_id, name) in objects {
+            match package.part(&name) {
+                Some(part) => thumbnails.push(Thumbnail::new(
+                    &format!("Object {}", object_id),
+                    &format!("{}{}", source, part.name),
+                    part.data.clone(),
+                )),
+                None => log::warn!("Thumbnail {} of object {} is missing", name, object_id),
+            }
+        }
+    }
+
+    thumbnails
+}
+
+/// Draws the thumbnails small, in a row, and larger when hovered.
+pub fn thumbnails_ui(ui: &mut egui::Ui, thumbnails: &[Thumbnail]) {
+    for thumbnail in thumbnails {
+        ui.add(
+            thumbnail
+                .image()
+                .fit_to_exact_size(egui::vec2(HEADER_SIZE, HEADER_SIZE)),
+        )
+        .on_hover_ui(|ui| {
+            ui.label(&thumbnail.label);
+            ui.add(
+                thumbnail
+                    .image()
+                    .fit_to_exact_size(egui::vec2(PREVIEW_SIZE, PREVIEW_SIZE)),
+            );
+        });
+    }
+}
+
+/// Cards of recently opened files with their package thumbnails. Thumbnails are read
+/// once per file, without reading the rest of the package.
+#[derive(Default)]
+pub struct RecentFilesGallery {
+    thumbnails: HashMap<PathBuf, Option<Thumbnail>>,
+}
+
+impl RecentFilesGallery {
+    /// Reads the thumbnail of the file again the next time it is drawn.
+    pub fn forget(&mut self, path: &Path) {
+        self.thumbnails.remove(path);
+    }
+
+    /// Draws a card for every file. Returns the file whose card was clicked.
+    pub fn ui(&mut self, ui: &mut egui::Ui, recent_files: &RecentFiles) -> Option<PathBuf> {
+        let mut clicked = None;
+        ui.heading("Recent files");
+        ui.add_space(8.0);
+        ui.horizontal_wrapped(|ui| {
+            for path in &recent_files.paths {
+                let thumbnail = self
+                    .thumbnails
+                    .entry(path.clone())
+                    .or_insert_with(|| file_thumbnail(path));
+                let image = match thumbnail {
+                    Some(thumbnail) => thumbnail.image(),
+                    None => egui::Image::new(egui::include_image!("../../assets/ferris.png")),
+                };
+
+                ui.vertical(|ui| {
+                    ui.set_width(GALLERY_SIZE);
+                    let response = ui
+                        .add_enabled(
+                            path.exists(),
+                            egui::ImageButton::new(
+                                image.fit_to_exact_size(egui::vec2(GALLERY_SIZE, GALLERY_SIZE)),
+                            ),
+                        )
+                        .on_hover_text(path.display().to_string());
+                    if response.clicked() {
+                        clicked = Some(path.clone());
+                    }
+                    let file_name = path.file_name().and_then(OsStr::to_str).unwrap_or_default();
+                    ui.add(egui::Label::new(file_name).truncate());
+                });
+            }
+        });
+        clicked
+    }
+}
+
+/// Package thumbnail of a 3MF file. Other files and files that cannot be read have none.
+fn file_thumbnail(path: &Path) -> Option<Thumbnail> {
+    if path.extension().and_then(OsStr::to_str) != Some("3mf") {
+        return None;
+    }
+    let file = fs::File::open(path).ok()?;
+    match read_thumbnail(file) {
+        Ok(data) => {
+            let source = path.display().to_string();
+            Some(Thumbnail::new("Package", &source, data?))
+        }
+        Err(e) => {
+            log::debug!("No thumbnail for {}: {}", path.display(), e);
+            None
+        }
+    }
+}
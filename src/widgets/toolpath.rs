@@ -0,0 +1,162 @@
+use crate::{
+    gcode::{
+        feature::Feature,
+        gcode_reader::{GcodeMove, GcodeProgram},
+    },
+    renderer::ColoredLine,
+};
+
+const TRAVEL_COLOR: [f32; 3] = [0.35, 0.35, 0.45];
+/// Colours of the slowest and the fastest extrusions when coloured by speed.
+const SLOW_COLOR: [f32; 3] = [0.1, 0.3, 0.9];
+const FAST_COLOR: [f32; 3] = [0.95, 0.2, 0.1];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolpathColoring {
+    Feature,
+    Speed,
+}
+
+/// Which moves of a G-code program are drawn in the viewport and how they are coloured.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolpathView {
+    pub first_layer: usize,
+    pub last_layer: usize,
+    pub show_travel: bool,
+    pub coloring: ToolpathColoring,
+}
+
+impl ToolpathView {
+    /// Shows every layer of the program.
+    pub fn new(program: &GcodeProgram) -> Self {
+        Self {
+            first_layer: 0,
+            last_layer: program.layers.len().saturating_sub(1),
+            show_travel: false,
+            coloring: ToolpathColoring::Feature,
+        }
+    }
+
+    /// Draws the controls and a legend. Returns whether the lines have to be drawn again.
+    pub fn ui(&mut self, ui: &mut egui::Ui, program: &GcodeProgram) -> bool {
+        let before = self.clone();
+        let last_index = program.layers.len().saturating_sub(1);
+
+        ui.label(format!(
+            "{} layers, {} moves",
+            program.layers.len(),
+            program.moves.len()
+        ));
+        ui.add(egui::Slider::new(&mut self.last_layer, 0..=last_index).text("Top layer"));
+        ui.add(egui::Slider::new(&mut self.first_layer, 0..=last_index).text("Bottom layer"));
+        self.first_layer = self.first_layer.min(self.last_layer);
+        if let Some(layer) = program.layers.get(self.last_layer) {
+            ui.label(format!("Z - {:.3}", layer.z));
+        }
+        ui.checkbox(&mut self.show_travel, "Show travel moves");
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.coloring, ToolpathColoring::Feature, "Feature");
+            ui.selectable_value(&mut self.coloring, ToolpathColoring::Speed, "Speed");
+        });
+        ui.separator();
+
+        match self.coloring {
+            ToolpathColoring::Feature => {
+                for feature in Feature::ALL {
+                    legend_entry(ui, feature_color(feature), &feature.to_string());
+                }
+            }
+            ToolpathColoring::Speed => {
+                if let Some((slowest, fastest)) = feedrate_range(program) {
+                    legend_entry(ui, SLOW_COLOR, &format!("{:.0} mm/s", slowest / 60.0));
+                    legend_entry(ui, FAST_COLOR, &format!("{:.0} mm/s", fastest / 60.0));
+                }
+            }
+        }
+        if self.show_travel {
+            legend_entry(ui, TRAVEL_COLOR, "Travel");
+        }
+
+        *self != before
+    }
+
+    /// Lines of the moves in the shown layers.
+    pub fn lines(&self, program: &GcodeProgram) -> Vec<ColoredLine> {
+        let (Some(first), Some(last)) = (
+            program.layers.get(self.first_layer),
+            program.layers.get(self.last_layer),
+        ) else {
+            return Vec::new();
+        };
+        let speeds = feedrate_range(program);
+
+        program.moves[first.moves.start..last.moves.end]
+            .iter()
+            .filter(|gcode_move| gcode_move.from != gcode_move.to)
+            .filter_map(|gcode_move| {
+                let color = if gcode_move.is_extrusion() {
+                    self.extrusion_color(gcode_move, speeds)
+                } else if self.show_travel {
+                    TRAVEL_COLOR
+                } else {
+                    return None;
+                };
+                Some(ColoredLine {
+                    from: gcode_move.from,
+                    to: gcode_move.to,
+                    color,
+                })
+            })
+            .collect()
+    }
+
+    fn extrusion_color(&self, gcode_move: &GcodeMove, speeds: Option<(f64, f64)>) -> [f32; 3] {
+        match (self.coloring, speeds) {
+            (ToolpathColoring::Speed, Some((slowest, fastest))) => {
+                let t = if fastest > slowest {
+                    ((gcode_move.feedrate - slowest) / (fastest - slowest)) as f32
+                } else {
+                    0.0
+                };
+                std::array::from_fn(|channel| {
+                    SLOW_COLOR[channel] + (FAST_COLOR[channel] - SLOW_COLOR[channel]) * t
+                })
+            }
+            _ => feature_color(gcode_move.feature),
+        }
+    }
+}
+
+pub fn feature_color(feature: Feature) -> [f32; 3] {
+    match feature {
+        Feature::OuterWall => [0.95, 0.5, 0.1],
+        Feature::InnerWall => [0.95, 0.8, 0.2],
+        Feature::Skin => [0.8, 0.2, 0.3],
+        Feature::Fill => [0.65, 0.3, 0.75],
+        Feature::Support => [0.3, 0.8, 0.3],
+        Feature::Skirt => [0.3, 0.7, 0.9],
+        Feature::Other => [0.7, 0.7, 0.7],
+    }
+}
+
+/// Slowest and fastest feedrate of the extrusions.
+fn feedrate_range(program: &GcodeProgram) -> Option<(f64, f64)> {
+    program
+        .moves
+        .iter()
+        .filter(|gcode_move| gcode_move.is_extrusion())
+        .map(|gcode_move| gcode_move.feedrate)
+        .fold(None, |range, feedrate| match range {
+            None => Some((feedrate, feedrate)),
+            Some((min, max)) => Some((feedrate.min(min), feedrate.max(max))),
+        })
+}
+
+fn legend_entry(ui: &mut egui::Ui, [r, g, b]: [f32; 3], text: &str) {
+    ui.horizontal(|ui| {
+        let (rect, _) = ui.allocate_exact_size(egui::vec2(12.0, 12.0), egui::Sense::hover());
+        ui.painter()
+            .rect_filled(rect, 2.0, egui::Rgba::from_rgb(r, g, b));
+        ui.label(text);
+    });
+}
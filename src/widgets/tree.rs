@@ -1,157 +1,367 @@
 use anyhow::{anyhow, Result};
-use xml_dom::level2::{CharacterData, Node, NodeType, RefNode};
-use xml_dom::parser::read_xml;
+use quick_xml::{events::Event, Reader};
+use std::{collections::HashMap, ops::Range, sync::Arc};
 
-/// A tree structure widget with expandable dropdowns
+/// Index of a node in a [`TreeStore`].
+pub type NodeId = usize;
+
+/// A node and its children, used to build summaries of files that are not XML
 #[derive(Debug)]
 pub struct Tree {
     pub name: String,
     pub content: Option<String>,
     pub attributes: Option<Vec<(String, String)>>,
     pub childs: Option<Vec<Tree>>,
+    /// Problem of the node, e.g. a build item outside of the printer. Flagged nodes are
+    /// drawn in the error colour with the problem on hover.
+    pub warning: Option<String>,
+}
+
+/// Where an element was read from, as byte ranges of a document of the store.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeSource {
+    pub document: usize,
+    /// The start tag, which is read again when the attributes are needed.
+    pub tag: Range<usize>,
+    /// The element from its start tag to the end of its end tag.
+    pub element: Range<usize>,
+}
+
+/// A node of a [`TreeStore`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeNode {
+    /// Names are shared by all nodes of the same name.
+    pub name: Arc<str>,
+    pub content: Option<String>,
+    pub parent: Option<NodeId>,
+    pub children: Vec<NodeId>,
+    pub depth: usize,
+    /// Elements of XML documents have a source, nodes of summaries do not.
+    pub source: Option<NodeSource>,
+    /// Attributes of summary nodes.
+    attributes: Vec<(String, String)>,
+}
+
+/// An XML document of a [`TreeStore`] and the nodes read from it.
+#[derive(Debug)]
+struct Document {
+    name: String,
+    /// Shared with the model part or file the document was read from.
+    text: Arc<str>,
+    nodes: Range<NodeId>,
+}
+
+/// Nodes of XML documents and summaries in one flat list, parents before their children.
+///
+/// Every element of a document becomes a node when the document is read, children are not
+/// read later when they are opened. Names are shared and attributes stay in the text until
+/// they are asked for, which keeps a node under 200 bytes, e.g. below 200 MB for a mesh of
+/// a million vertices.
+#[derive(Debug, Default)]
+pub struct TreeStore {
+    documents: Vec<Document>,
+    nodes: Vec<TreeNode>,
+    pub roots: Vec<NodeId>,
+    names: HashMap<String, Arc<str>>,
+    warnings: HashMap<NodeId, String>,
 }
 
-impl Tree {
-    /// Creates a new Tree struct from a XML string structure.
+impl TreeStore {
+    /// Reads every element of the XML string as a document called `name`, e.g. the name of
+    /// the model part or file.
     /// Returns error if the string is an malformed XML string
-    pub fn new_trees_from_xml_string(xml_string: &String) -> Result<Vec<Self>> {
-        let dom = read_xml(xml_string)?;
-        // println!("{:?}", dom);
-        let result = process_dom(dom);
-        if let Some(tree) = result.0 {
-            Ok(tree)
-        } else {
-            Err(anyhow!("No tree was generated"))
-        }
+    pub fn from_xml(name: &str, xml: impl Into<Arc<str>>) -> Result<Self> {
+        let mut store = Self::default();
+        store.append_xml(None, name, xml)?;
+        Ok(store)
     }
 
-    /// Draws the ui
-    pub fn ui(&self, ui: &mut egui::Ui, depth: usize, unique_id: &str) {
-        self.ui_impl(ui, depth, unique_id);
+    pub fn from_trees(trees: Vec<Tree>) -> Self {
+        let mut store = Self::default();
+        for tree in trees {
+            store.append_tree(None, tree);
+        }
+        store
     }
-}
 
-//private implementation
-impl Tree {
-    fn ui_impl(&self, ui: &mut egui::Ui, depth: usize, unique_id: &str) {
-        let name = if let Some(content) = &self.content {
-            format!("{} - {}", self.name, content)
-        } else {
-            self.name.clone()
-        };
-        egui::CollapsingHeader::new(name)
-            .default_open(depth < 1)
-            .id_source(unique_id)
-            .show(ui, |ui| {
-                if let Some(attributes) = &self.attributes {
-                    for attribute in attributes {
-                        ui.label(format!("{} - {}", attribute.0, attribute.1));
+    /// Adds the elements of the XML string below `parent`, or as roots, as a document
+    /// called `name`.
+    pub fn append_xml(
+        &mut self,
+        parent: Option<NodeId>,
+        name: &str,
+        xml: impl Into<Arc<str>>,
+    ) -> Result<()> {
+        let xml = xml.into();
+        let document = self.documents.len();
+        let mut reader = Reader::from_str(&xml);
+        let mut stack: Vec<NodeId> = Vec::new();
+        let first_node = self.nodes.len();
+        // the list of nodes grows once instead of doubling, which would leave up to half of
+        // it unused for documents with millions of elements
+        self.nodes.reserve(count_start_tags(&xml));
+
+        loop {
+            let start = reader.buffer_position() as usize;
+            let event = reader.read_event()?;
+            match &event {
+                Event::Start(element) | Event::Empty(element) => {
+                    let name = std::str::from_utf8(element.local_name().into_inner())?;
+                    let id = self.push_node(stack.last().copied().or(parent), name, Vec::new());
+                    let tag = start..reader.buffer_position() as usize;
+                    self.nodes[id].source = Some(NodeSource {
+                        document,
+                        element: tag.clone(),
+                        tag,
+                    });
+                    if let Event::Start(_) = event {
+                        stack.push(id);
+                    }
+                }
+                Event::End(_) => {
+                    if let Some(source) = stack.pop().and_then(|id| self.nodes[id].source.as_mut())
+                    {
+                        source.element.end = reader.buffer_position() as usize;
+                    }
+                }
+                Event::Text(text) => {
+                    if let Some(&id) = stack.last() {
+                        self.push_content(id, &text.unescape()?);
+                    }
+                }
+                Event::CData(text) => {
+                    if let Some(&id) = stack.last() {
+                        self.push_content(id, std::str::from_utf8(text)?);
                     }
                 }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
 
-                self.children_ui(ui, depth)
-            });
+        if let Some(&id) = stack.last() {
+            return Err(anyhow!("Element {} is not closed", self.nodes[id].name));
+        }
+        if self.nodes.len() == first_node {
+            return Err(anyhow!("No tree was generated"));
+        }
+        self.documents.push(Document {
+            name: name.to_string(),
+            text: xml,
+            nodes: first_node..self.nodes.len(),
+        });
+        Ok(())
     }
 
-    fn children_ui(&self, ui: &mut egui::Ui, depth: usize) {
-        if let Some(trees) = &self.childs {
-            let mut count = 0;
-            for tree in trees {
-                tree.ui_impl(ui, depth + 1, &format!("{} - {}", tree.name, count));
-                count += 1;
-            }
+    /// Adds the tree and its children below `parent`, or as a root.
+    pub fn append_tree(&mut self, parent: Option<NodeId>, tree: Tree) -> NodeId {
+        let id = self.push_node(parent, &tree.name, tree.attributes.unwrap_or_default());
+        self.nodes[id].content = tree.content;
+        if let Some(warning) = tree.warning {
+            self.warnings.insert(id, warning);
+        }
+        for child in tree.childs.into_iter().flatten() {
+            self.append_tree(Some(id), child);
         }
+        id
     }
-}
 
-fn process_dom(ref_node: RefNode) -> (Option<Vec<Tree>>, Option<String>) {
-    let mut sub_trees = Vec::new();
-    let mut sub_content = String::new();
-    for node in ref_node.child_nodes() {
-        let name = node.local_name();
-        let mut attributes: Vec<(String, String)> = Vec::new();
-
-        if node.node_type() == NodeType::Element {
-            let attributes_map = node.attributes();
-
-            for entry in attributes_map {
-                if entry.1.node_type() == NodeType::Attribute {
-                    let attribute_name = entry.0.local_name().to_string();
-                    let mut attribute_value = String::new();
-
-                    if let Some(value) = entry.1.data() {
-                        attribute_value.push_str(&value);
-                    } else {
-                        for attribute_child_node in entry.1.child_nodes() {
-                            if let Some(value) = attribute_child_node.data() {
-                                attribute_value.push_str(&value);
-                                // break;
-                            }
-                        }
-                    };
-                    attributes.push((attribute_name, attribute_value));
-                }
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn node(&self, id: NodeId) -> &TreeNode {
+        &self.nodes[id]
+    }
+
+    /// Attributes of the node with their names as written and unescaped values. Malformed
+    /// attributes are skipped, the document was checked when it was read.
+    pub fn attributes(&self, id: NodeId) -> Vec<(String, String)> {
+        let node = &self.nodes[id];
+        let Some(source) = &node.source else {
+            return node.attributes.clone();
+        };
+        let mut reader =
+            Reader::from_str(&self.documents[source.document].text[source.tag.clone()]);
+        let (Ok(Event::Start(element)) | Ok(Event::Empty(element))) = reader.read_event() else {
+            return Vec::new();
+        };
+        element
+            .attributes()
+            .filter_map(|attribute| {
+                let attribute = attribute.ok()?;
+                let name = std::str::from_utf8(attribute.key.as_ref())
+                    .ok()?
+                    .to_string();
+                Some((name, attribute.unescape_value().ok()?.into_owned()))
+            })
+            .collect()
+    }
+
+    /// Value of the attribute of the node with the name.
+    pub fn attribute(&self, id: NodeId, name: &str) -> Option<String> {
+        self.attributes(id)
+            .into_iter()
+            .find(|(attribute, _)| attribute == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Name of the document the node was read from.
+    pub fn document_name(&self, id: NodeId) -> Option<&str> {
+        let source = self.nodes[id].source.as_ref()?;
+        Some(&self.documents[source.document].name)
+    }
+
+    /// Index of the document called `name`.
+    pub fn document_index(&self, name: &str) -> Option<usize> {
+        self.documents
+            .iter()
+            .position(|document| document.name == name)
+    }
+
+    pub fn document_text(&self, document: usize) -> &str {
+        &self.documents[document].text
+    }
+
+    /// Root element of the document called `name`.
+    pub fn document_root(&self, name: &str) -> Option<NodeId> {
+        let document = &self.documents[self.document_index(name)?];
+        Some(document.nodes.start)
+    }
+
+    /// Innermost element of the document around the byte `offset` of its text.
+    pub fn node_at(&self, document: usize, offset: usize) -> Option<NodeId> {
+        let nodes = self.documents.get(document)?.nodes.clone();
+        // elements are stored in the order they start, so the innermost element around the
+        // offset is the last to start before it or one of its ancestors
+        let after = self.first_starting_at(document, offset + 1);
+        let mut candidate = (after > nodes.start).then(|| after - 1);
+        while let Some(id) = candidate.filter(|id| nodes.contains(id)) {
+            let source = self.nodes[id].source.as_ref()?;
+            if source.element.contains(&offset) {
+                return Some(id);
             }
+            candidate = self.nodes[id].parent;
+        }
+        None
+    }
+
+    /// Elements of the document that start within the `bytes` of its text, e.g. the
+    /// elements replaced by an edit of the text.
+    pub fn nodes_starting_in(&self, document: usize, bytes: Range<usize>) -> Range<NodeId> {
+        self.first_starting_at(document, bytes.start)..self.first_starting_at(document, bytes.end)
+    }
 
-            let (childs, entry) = if node.has_child_nodes() {
-                process_dom(node)
+    /// First element of the document that starts at or after the byte `offset`.
+    fn first_starting_at(&self, document: usize, offset: usize) -> NodeId {
+        let nodes = &self.documents[document].nodes;
+        let (mut low, mut high) = (nodes.start, nodes.end);
+        while low < high {
+            let middle = (low + high) / 2;
+            let starts_before = self.nodes[middle]
+                .source
+                .as_ref()
+                .is_some_and(|source| source.element.start < offset);
+            if starts_before {
+                low = middle + 1;
             } else {
-                (None, None)
-            };
-
-            sub_trees.push(Tree {
-                name,
-                content: entry,
-                attributes: Some(attributes),
-                childs,
-            });
-        } else {
-            // if not an Element then there is highly likely it
-            // contains some data that belongs to the Element item itself
-            if let Some(data) = node.data() {
-                sub_content.push_str(&data);
+                high = middle;
             }
         }
+        low
     }
 
-    let trees = if sub_trees.len() >= 1 {
-        Some(sub_trees)
-    } else {
-        None
-    };
+    /// First child of `parent`, or first root, with the name.
+    pub fn find_child(&self, parent: Option<NodeId>, name: &str) -> Option<NodeId> {
+        let candidates = match parent {
+            Some(parent) => &self.nodes[parent].children,
+            None => &self.roots,
+        };
+        candidates
+            .iter()
+            .copied()
+            .find(|&id| &*self.nodes[id].name == name)
+    }
 
-    let content = if sub_content.len() >= 1 {
-        Some(sub_content)
-    } else {
-        None
-    };
+    pub fn warning(&self, id: NodeId) -> Option<&str> {
+        self.warnings.get(&id).map(String::as_str)
+    }
 
-    (trees, content)
-}
+    pub fn set_warning(&mut self, id: NodeId, warning: Option<String>) {
+        match warning {
+            Some(warning) => self.warnings.insert(id, warning),
+            None => self.warnings.remove(&id),
+        };
+    }
 
-mod tests {
+    fn push_node(
+        &mut self,
+        parent: Option<NodeId>,
+        name: &str,
+        attributes: Vec<(String, String)>,
+    ) -> NodeId {
+        let id = self.nodes.len();
+        let name = match self.names.get(name) {
+            Some(name) => name.clone(),
+            None => {
+                let shared: Arc<str> = name.into();
+                self.names.insert(name.to_string(), shared.clone());
+                shared
+            }
+        };
+        let depth = match parent {
+            Some(parent) => {
+                self.nodes[parent].children.push(id);
+                self.nodes[parent].depth + 1
+            }
+            None => {
+                self.roots.push(id);
+                0
+            }
+        };
+        self.nodes.push(TreeNode {
+            name,
+            content: None,
+            parent,
+            children: Vec::new(),
+            depth,
+            source: None,
+            attributes,
+        });
+        id
+    }
+
+    /// Text between the children of an element, whitespace between tags is left out.
+    fn push_content(&mut self, id: NodeId, text: &str) {
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+        self.nodes[id]
+            .content
+            .get_or_insert_with(String::new)
+            .push_str(text);
+    }
+}
 
-    use crate::widgets::tree;
-    use std::{
-        env::{self},
-        fs::{self},
-        path::PathBuf,
-    };
+/// Number of elements in the XML text, counted by their start tags.
+fn count_start_tags(xml: &str) -> usize {
+    xml.as_bytes()
+        .windows(2)
+        .filter(|pair| pair[0] == b'<' && !matches!(pair[1], b'/' | b'?' | b'!'))
+        .count()
+}
 
-    fn get_file_as_string_from_test_resource(file_name: &str) -> String {
-        let root_dir = &env::var("CARGO_MANIFEST_DIR").expect("$CARGO_MANIFEST_DIR");
-        let mut test_file_path = PathBuf::from(root_dir);
-        test_file_path.push("test_resources\\");
-        test_file_path.push(file_name);
-        // println!("{:?}", test_file_path);
+#[cfg(test)]
+mod tests {
 
-        fs::read_to_string(test_file_path).unwrap()
-    }
+    use crate::{test_support::get_file_as_string_from_test_resource, widgets::tree};
+    use std::sync::Arc;
 
     #[test]
     fn test_a_valid_tree_generated_from_valid_xml() {
         let file = get_file_as_string_from_test_resource("test-xml.xml");
-        let result = tree::Tree::new_trees_from_xml_string(&file);
+        let result = tree::TreeStore::from_xml("test-xml.xml", file.as_str());
 
         assert!(
             result.is_ok(),
@@ -162,11 +372,111 @@ mod tests {
     #[test]
     fn test_error_returned_when_invalid_xml() {
         let file = get_file_as_string_from_test_resource("fake-xml.xml");
-        let result = tree::Tree::new_trees_from_xml_string(&file);
+        let result = tree::TreeStore::from_xml("fake-xml.xml", file.as_str());
 
         assert!(
             result.is_err(),
             "Operation did not return en error when given invalid xml"
         );
     }
+
+    #[test]
+    fn test_nodes_are_stored_flat() {
+        let file = get_file_as_string_from_test_resource("test-xml.xml");
+        let store = tree::TreeStore::from_xml("test-xml.xml", file.as_str()).unwrap();
+
+        let model = store.find_child(None, "model").unwrap();
+        let metadata = store.find_child(Some(model), "metadata").unwrap();
+        assert!(
+            store.node(metadata).content.as_deref()
+                == Some("Copyright (c) 2015 3MF Consortium. All rights reserved."),
+            "Text of an element is not its content"
+        );
+        assert!(
+            store.node(model).content.is_none(),
+            "Whitespace between tags is content"
+        );
+
+        let vertices = store
+            .find_child(store.find_child(Some(model), "resources"), "object")
+            .and_then(|object| store.find_child(Some(object), "mesh"))
+            .and_then(|mesh| store.find_child(Some(mesh), "vertices"))
+            .unwrap();
+        let vertex = store.node(vertices).children[2];
+        assert!(
+            store.node(vertices).children.len() == 8 && store.node(vertex).depth == 5,
+            "Vertices are not children of their element"
+        );
+        assert!(
+            store.attributes(vertex)
+                == [
+                    ("x".to_string(), "10".to_string()),
+                    ("y".to_string(), "20".to_string()),
+                    ("z".to_string(), "0".to_string())
+                ],
+            "Attributes are not read from the tag: {:?}",
+            store.attributes(vertex)
+        );
+    }
+
+    #[test]
+    fn test_nodes_keep_their_source() {
+        let file = get_file_as_string_from_test_resource("test-xml.xml");
+        let store = tree::TreeStore::from_xml("test-xml.xml", file.as_str()).unwrap();
+
+        let model = store.document_root("test-xml.xml").unwrap();
+        let metadata = store.find_child(Some(model), "metadata").unwrap();
+        let source = store.node(metadata).source.clone().unwrap();
+        assert!(
+            file[source.element.clone()]
+                == *r#"<metadata name="Copyright">Copyright (c) 2015 3MF Consortium. All rights reserved.</metadata>"#
+                && file[source.tag.clone()] == *r#"<metadata name="Copyright">"#,
+            "Source of an element is wrong: {:?}",
+            &file[source.element]
+        );
+
+        let triangle = file.find(r#"<triangle v1="4" v2="5" v3="6" />"#).unwrap();
+        let found = store.node_at(0, triangle + 3).unwrap();
+        assert!(
+            &*store.node(found).name == "triangle"
+                && store.attribute(found, "v1").as_deref() == Some("4"),
+            "Element at the offset is not the triangle: {:?}",
+            store.attributes(found)
+        );
+        // whitespace between the triangles belongs to their parent
+        let between = store.node_at(0, triangle - 1).unwrap();
+        assert!(
+            &*store.node(between).name == "triangles",
+            "Element around the offset is not the parent: {}",
+            store.node(between).name
+        );
+        assert!(store.node_at(0, 0).is_none(), "Declaration is an element");
+
+        let triangles = store.node(found).parent.unwrap();
+        let replaced = store.nodes_starting_in(0, triangle..triangle + 10);
+        assert!(
+            replaced == (found..found + 1),
+            "Only the triangle starts in its text: {:?}",
+            replaced
+        );
+        let end = store.node(triangles).source.clone().unwrap().element.end;
+        let after = store.nodes_starting_in(0, end - 1..end - 1);
+        assert!(
+            after.is_empty()
+                && Some(after.start - 1) == store.node(triangles).children.last().copied(),
+            "Nothing starts in an empty range after the last triangle: {:?}",
+            after
+        );
+    }
+
+    #[test]
+    fn test_document_text_is_shared() {
+        let file: Arc<str> = get_file_as_string_from_test_resource("test-xml.xml").into();
+        let store = tree::TreeStore::from_xml("test-xml.xml", file.clone()).unwrap();
+
+        assert!(
+            std::ptr::eq(store.document_text(0), &*file),
+            "Store keeps a copy of the document"
+        );
+    }
 }
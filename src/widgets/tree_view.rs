@@ -0,0 +1,1438 @@
+use anyhow::Result;
+use regex::Regex;
+use std::{collections::HashSet, ops::Range, time::Duration};
+
+use super::tree::{NodeId, TreeStore};
+use crate::threemf::materials::parse_color;
+
+/// Runs of at least this many siblings of the same name are collapsed into a group.
+const GROUP_MIN: usize = 64;
+/// Groups larger than this are split into pages.
+const PAGE_SIZE: usize = 1000;
+const INDENT: f32 = 14.0;
+/// Seconds without typing before the query is searched, a search reads the attributes of
+/// every node.
+const SEARCH_DELAY: f64 = 0.3;
+
+/// What of a node a search looks at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchFields {
+    pub element_names: bool,
+    pub attribute_names: bool,
+    pub attribute_values: bool,
+    pub text: bool,
+}
+
+impl Default for SearchFields {
+    fn default() -> Self {
+        Self {
+            element_names: true,
+            attribute_names: true,
+            attribute_values: true,
+            text: true,
+        }
+    }
+}
+
+/// A search query, text is found anywhere and in any case, regular expressions as written.
+pub enum Matcher {
+    Text(String),
+    Regex(Regex),
+}
+
+impl Matcher {
+    pub fn new(query: &str, use_regex: bool) -> Result<Self> {
+        Ok(if use_regex {
+            Matcher::Regex(Regex::new(query)?)
+        } else {
+            Matcher::Text(query.to_lowercase())
+        })
+    }
+
+    pub fn is_match(&self, text: &str) -> bool {
+        match self {
+            Matcher::Text(query) => text.to_lowercase().contains(query),
+            Matcher::Regex(regex) => regex.is_match(text),
+        }
+    }
+
+    /// Attributes are only read when they are searched.
+    pub fn node_matches(&self, store: &TreeStore, id: NodeId, fields: &SearchFields) -> bool {
+        let node = store.node(id);
+        (fields.element_names && self.is_match(&node.name))
+            || (fields.text
+                && node
+                    .content
+                    .as_deref()
+                    .is_some_and(|text| self.is_match(text)))
+            || ((fields.attribute_names || fields.attribute_values)
+                && store.attributes(id).iter().any(|(name, value)| {
+                    (fields.attribute_names && self.is_match(name))
+                        || (fields.attribute_values && self.is_match(value))
+                }))
+    }
+}
+
+/// Matching nodes in document order.
+pub fn search_store(store: &TreeStore, matcher: &Matcher, fields: &SearchFields) -> Vec<NodeId> {
+    (0..store.node_count())
+        .filter(|&id| matcher.node_matches(store, id, fields))
+        .collect()
+}
+
+/// What can be expanded in the view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum RowKey {
+    Node(NodeId),
+    Group { parent: NodeId, start: usize },
+    Page { parent: NodeId, start: usize },
+}
+
+/// A line of the view, indented by `level`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Row {
+    Node {
+        id: NodeId,
+        level: usize,
+    },
+    /// `len` children of `parent` of the same name from child `start` on.
+    Group {
+        parent: NodeId,
+        start: usize,
+        len: usize,
+        level: usize,
+    },
+    /// Children from `start` to `last` of the group starting at child `group`.
+    Page {
+        parent: NodeId,
+        group: usize,
+        start: usize,
+        last: usize,
+        level: usize,
+    },
+}
+
+impl Row {
+    fn key(&self) -> RowKey {
+        match *self {
+            Row::Node { id, .. } => RowKey::Node(id),
+            Row::Group { parent, start, .. } => RowKey::Group { parent, start },
+            Row::Page { parent, start, .. } => RowKey::Page { parent, start },
+        }
+    }
+}
+
+/// A change of a node made in the view, to be made in the text of its document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TreeEdit {
+    /// Sets, or with `None` removes, an attribute.
+    SetAttribute {
+        node: NodeId,
+        name: String,
+        value: Option<String>,
+    },
+    SetText {
+        node: NodeId,
+        text: String,
+    },
+    /// Adds an empty element as the last child.
+    AddChild {
+        node: NodeId,
+        name: String,
+    },
+    Remove {
+        node: NodeId,
+    },
+}
+
+impl TreeEdit {
+    pub fn node(&self) -> NodeId {
+        match *self {
+            TreeEdit::SetAttribute { node, .. }
+            | TreeEdit::SetText { node, .. }
+            | TreeEdit::AddChild { node, .. }
+            | TreeEdit::Remove { node } => node,
+        }
+    }
+}
+
+/// What was done in the view in a frame.
+#[derive(Debug, Default)]
+pub struct TreeViewOutput {
+    /// Node whose name was clicked.
+    pub clicked: Option<NodeId>,
+    pub edit: Option<TreeEdit>,
+}
+
+/// An edit of a node being entered in its row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Editing {
+    Attribute {
+        node: NodeId,
+        name: String,
+        value: String,
+        original: String,
+    },
+    /// A new attribute entered as `name=value`.
+    NewAttribute { node: NodeId, text: String },
+    Text {
+        node: NodeId,
+        text: String,
+        original: String,
+    },
+    /// Name of a new child element.
+    Child { node: NodeId, name: String },
+}
+
+impl Editing {
+    fn node(&self) -> NodeId {
+        match *self {
+            Editing::Attribute { node, .. }
+            | Editing::NewAttribute { node, .. }
+            | Editing::Text { node, .. }
+            | Editing::Child { node, .. } => node,
+        }
+    }
+
+    /// The edit of the tree entered, if anything was changed.
+    fn finish(self) -> Option<TreeEdit> {
+        match self {
+            Editing::Attribute {
+                node,
+                name,
+                value,
+                original,
+            } => (value != original).then_some(TreeEdit::SetAttribute {
+                node,
+                name,
+                value: Some(value),
+            }),
+            Editing::NewAttribute { node, text } => {
+                let (name, value) = text.split_once('=').unwrap_or((&text, ""));
+                let (name, value) = (name.trim(), value.trim());
+                let value = value
+                    .strip_prefix('"')
+                    .and_then(|value| value.strip_suffix('"'))
+                    .unwrap_or(value);
+                (!name.is_empty()).then(|| TreeEdit::SetAttribute {
+                    node,
+                    name: name.to_string(),
+                    value: Some(value.to_string()),
+                })
+            }
+            Editing::Text {
+                node,
+                text,
+                original,
+            } => (text != original).then_some(TreeEdit::SetText { node, text }),
+            Editing::Child { node, name } => {
+                let name = name.trim();
+                (!name.is_empty()).then(|| TreeEdit::AddChild {
+                    node,
+                    name: name.to_string(),
+                })
+            }
+        }
+    }
+}
+
+/// Ranges of children of the same name, in order.
+fn runs_of_names(store: &TreeStore, children: &[NodeId]) -> Vec<Range<usize>> {
+    let mut runs: Vec<Range<usize>> = Vec::new();
+    for (index, &child) in children.iter().enumerate() {
+        match runs.last_mut() {
+            Some(run) if store.node(children[run.start]).name == store.node(child).name => {
+                run.end = index + 1;
+            }
+            _ => runs.push(index..index + 1),
+        }
+    }
+    runs
+}
+
+/// The trees of the loaded file with a search box.
+///
+/// Only the rows in view are laid out. Long runs of siblings of the same name, like the
+/// vertices of a mesh, are collapsed into groups of pages. While searching only the
+/// matches and their ancestors are listed, grouped the same way. A node is selected by
+/// clicking its name.
+///
+/// Nodes read from a document are edited in their rows: attribute values and text with a
+/// double click, everything else from the context menus of the name and the values.
+#[derive(Default)]
+pub struct TreeView {
+    query: String,
+    use_regex: bool,
+    fields: SearchFields,
+    matches: Vec<NodeId>,
+    current: usize,
+    matched: HashSet<NodeId>,
+    /// Nodes with matches below them.
+    ancestors: HashSet<NodeId>,
+    error: Option<String>,
+    search_stale: bool,
+    /// Time of the last change of the query that is not searched yet.
+    typed_at: Option<f64>,
+    expanded: HashSet<RowKey>,
+    /// What was expanded before the search opened the way to its matches. It is expanded
+    /// again when the query is changed or cleared.
+    expanded_before_search: Option<HashSet<RowKey>>,
+    rows: Vec<Row>,
+    rows_stale: bool,
+    /// Whether the roots were opened for the first frame.
+    opened: bool,
+    selected: Option<NodeId>,
+    /// Node to bring into view in the next frame.
+    scroll_to: Option<NodeId>,
+    editing: Option<Editing>,
+    /// Whether the editor of the edit takes the focus in the next frame.
+    edit_focus: bool,
+    /// Edit entered in this frame.
+    finished: Option<TreeEdit>,
+}
+
+impl TreeView {
+    /// Searches and lists the rows again before the next frame, e.g. after the trees were
+    /// rebuilt. What is expanded stays expanded.
+    pub fn invalidate(&mut self) {
+        self.search_stale = true;
+        self.rows_stale = true;
+    }
+
+    /// Moves what is expanded and selected along with the nodes after the nodes of `range`
+    /// were replaced by `count` nodes, e.g. when an element was added or removed.
+    pub fn nodes_replaced(&mut self, range: Range<NodeId>, count: usize) {
+        let moved = |id: NodeId| {
+            if id < range.start {
+                Some(id)
+            } else if id >= range.end {
+                Some(id - range.len() + count)
+            } else {
+                (id - range.start < count).then_some(id)
+            }
+        };
+        let moved_keys = |keys: &mut HashSet<RowKey>| {
+            *keys = keys
+                .drain()
+                .filter_map(|key| match key {
+                    RowKey::Node(id) => moved(id).map(RowKey::Node),
+                    RowKey::Group { parent, start } => {
+                        moved(parent).map(|parent| RowKey::Group { parent, start })
+                    }
+                    RowKey::Page { parent, start } => {
+                        moved(parent).map(|parent| RowKey::Page { parent, start })
+                    }
+                })
+                .collect();
+        };
+        moved_keys(&mut self.expanded);
+        if let Some(expanded) = &mut self.expanded_before_search {
+            moved_keys(expanded);
+        }
+        self.selected = self.selected.and_then(moved);
+        self.editing = None;
+        self.invalidate();
+    }
+
+    /// Draws the search and the rows.
+    pub fn ui(&mut self, ui: &mut egui::Ui, store: &TreeStore) -> TreeViewOutput {
+        self.search_ui(ui, store);
+        // the trees may have been rebuilt with fewer nodes
+        if self.selected.is_some_and(|id| id >= store.node_count()) {
+            self.selected = None;
+        }
+        if !self.opened {
+            self.opened = true;
+            self.expanded
+                .extend(store.roots.iter().map(|&id| RowKey::Node(id)));
+            self.rows_stale = true;
+        }
+        if self.rows_stale {
+            self.list_rows(store);
+        }
+
+        // rows are laid out at this height whatever they show, so the view can page them
+        let row_height = ui.spacing().interact_size.y;
+        let mut scroll_area = egui::ScrollArea::both().auto_shrink(false);
+        if let Some(target) = self.scroll_to.take() {
+            if let Some(index) = self
+                .rows
+                .iter()
+                .position(|row| matches!(row, Row::Node { id, .. } if *id == target))
+            {
+                let spacing = ui.spacing().item_spacing.y;
+                let offset = index as f32 * (row_height + spacing) - ui.available_height() / 2.0;
+                scroll_area = scroll_area.vertical_scroll_offset(offset.max(0.0));
+            }
+        }
+
+        let mut toggled = None;
+        let mut clicked = None;
+        scroll_area.show_rows(ui, row_height, self.rows.len(), |ui, range| {
+            let visible = self.rows[range].to_vec();
+            for row in &visible {
+                let (toggle, click) = self.row_ui(ui, store, row, row_height);
+                if toggle {
+                    toggled = Some(row.key());
+                }
+                clicked = clicked.or(click);
+            }
+        });
+        if let Some(key) = toggled {
+            if !self.expanded.remove(&key) {
+                self.expanded.insert(key);
+            }
+            self.rows_stale = true;
+        }
+        if clicked.is_some() {
+            self.selected = clicked;
+        }
+
+        let edit = self.finished.take();
+        if let Some(TreeEdit::AddChild { node, .. }) = &edit {
+            self.expanded.insert(RowKey::Node(*node));
+        }
+        TreeViewOutput { clicked, edit }
+    }
+
+    /// Selects the node, e.g. when it was picked elsewhere, and opens and scrolls the view
+    /// to it.
+    pub fn select(&mut self, store: &TreeStore, id: NodeId) {
+        self.selected = Some(id);
+        self.reveal(store, id);
+    }
+
+    /// Opens the ancestors, groups and pages around the node and scrolls the view to it.
+    fn reveal(&mut self, store: &TreeStore, id: NodeId) {
+        self.scroll_to = Some(id);
+        self.rows_stale = true;
+
+        let mut child = id;
+        while let Some(parent) = store.node(child).parent {
+            self.expanded.insert(RowKey::Node(parent));
+            let children = &store.node(parent).children;
+            let index = children.iter().position(|&id| id == child).unwrap_or(0);
+            if let Some((start, run)) = self
+                .child_runs(store, parent)
+                .into_iter()
+                .find(|(_, run)| run.len() >= GROUP_MIN && run.contains(&index))
+            {
+                self.expanded.insert(RowKey::Group { parent, start });
+                if run.len() > PAGE_SIZE {
+                    let position = run.iter().position(|&i| i == index).unwrap_or(0);
+                    self.expanded.insert(RowKey::Page {
+                        parent,
+                        start: run[position / PAGE_SIZE * PAGE_SIZE],
+                    });
+                }
+            }
+            child = parent;
+        }
+    }
+
+    /// Runs of children of the node with the same name, each with the index of its first
+    /// child and the indices of the children listed in it. While searching below the node
+    /// only the children that match or have matches below them are listed.
+    fn child_runs(&self, store: &TreeStore, id: NodeId) -> Vec<(usize, Vec<usize>)> {
+        let children = &store.node(id).children;
+        let filtered = self.is_searching() && self.ancestors.contains(&id);
+        runs_of_names(store, children)
+            .into_iter()
+            .map(|run| {
+                let start = run.start;
+                let listed = run
+                    .filter(|&index| !filtered || self.is_listed(children[index]))
+                    .collect::<Vec<_>>();
+                (start, listed)
+            })
+            .filter(|(_, listed)| !listed.is_empty())
+            .collect()
+    }
+
+    /// Whether the node is listed while searching.
+    fn is_listed(&self, id: NodeId) -> bool {
+        self.matched.contains(&id) || self.ancestors.contains(&id)
+    }
+
+    /// Whether a valid query is entered.
+    pub fn is_searching(&self) -> bool {
+        !self.query.is_empty() && self.error.is_none()
+    }
+
+    fn search_ui(&mut self, ui: &mut egui::Ui, store: &TreeStore) {
+        ui.horizontal(|ui| {
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.query)
+                    .hint_text("Search")
+                    .desired_width(160.0),
+            );
+            if response.changed() {
+                self.typed_at = Some(ui.input(|i| i.time));
+            }
+            if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                // a query that is not searched yet is searched at once, showing its first match
+                if self.typed_at.is_some() {
+                    self.search_stale = true;
+                } else {
+                    self.step(store, true);
+                }
+                response.request_focus();
+            }
+            if ui
+                .toggle_value(&mut self.use_regex, ".*")
+                .on_hover_text("Regular expression")
+                .changed()
+            {
+                self.search_stale = true;
+            }
+        });
+        ui.horizontal(|ui| {
+            let fields = &mut self.fields;
+            for (value, label) in [
+                (&mut fields.element_names, "Element"),
+                (&mut fields.attribute_names, "Attribute"),
+                (&mut fields.attribute_values, "Value"),
+                (&mut fields.text, "Text"),
+            ] {
+                self.search_stale |= ui.toggle_value(value, label).changed();
+            }
+        });
+
+        if let Some(at) = self.typed_at {
+            let waited = ui.input(|i| i.time) - at;
+            if waited >= SEARCH_DELAY {
+                self.search_stale = true;
+            } else {
+                ui.ctx()
+                    .request_repaint_after(Duration::from_secs_f64(SEARCH_DELAY - waited));
+            }
+        }
+        if self.search_stale {
+            self.search(store);
+        }
+
+        if self.is_searching() {
+            ui.horizontal(|ui| {
+                let enabled = !self.matches.is_empty();
+                if ui.add_enabled(enabled, egui::Button::new("⏶")).clicked() {
+                    self.step(store, false);
+                }
+                if ui.add_enabled(enabled, egui::Button::new("⏷")).clicked() {
+                    self.step(store, true);
+                }
+                match self.matches.len() {
+                    0 => ui.label("No matches"),
+                    count => ui.label(format!("{} of {}", self.current + 1, count)),
+                };
+            });
+        }
+        if let Some(error) = &self.error {
+            ui.colored_label(ui.visuals().error_fg_color, error);
+        }
+        ui.separator();
+    }
+
+    /// Draws a row at the given height, however tall what it shows is. Returns whether its
+    /// expander was clicked and the node whose name was clicked.
+    fn row_ui(
+        &mut self,
+        ui: &mut egui::Ui,
+        store: &TreeStore,
+        row: &Row,
+        height: f32,
+    ) -> (bool, Option<NodeId>) {
+        let (level, expandable) = match *row {
+            Row::Node { id, level } => (level, !store.node(id).children.is_empty()),
+            Row::Group { level, .. } | Row::Page { level, .. } => (level, true),
+        };
+        let rect =
+            egui::Rect::from_min_size(ui.cursor().min, egui::vec2(ui.available_width(), height));
+        let layout = egui::Layout::left_to_right(egui::Align::Center);
+        let mut row_ui = ui.child_ui_with_id_source(rect, layout, row.key(), None);
+        let output = {
+            let ui = &mut row_ui;
+            ui.add_space(level as f32 * INDENT);
+            let mut toggled = false;
+            if expandable {
+                let icon = if self.expanded.contains(&row.key()) {
+                    "⏷"
+                } else {
+                    "⏵"
+                };
+                toggled = ui
+                    .add(egui::Label::new(icon).sense(egui::Sense::click()))
+                    .clicked();
+            } else {
+                ui.add_space(INDENT);
+            }
+
+            let mut clicked = None;
+            match *row {
+                Row::Node { id, .. } => {
+                    if self.node_ui(ui, store, id) {
+                        clicked = Some(id);
+                    }
+                }
+                Row::Group {
+                    parent, start, len, ..
+                } => {
+                    let name = &store.node(store.node(parent).children[start]).name;
+                    ui.label(egui::RichText::new(format!("{} × {}", name, len)).italics());
+                }
+                Row::Page {
+                    parent,
+                    group,
+                    start,
+                    last,
+                    ..
+                } => {
+                    let name = &store.node(store.node(parent).children[start]).name;
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{} {} to {}",
+                            name,
+                            start - group,
+                            last - group
+                        ))
+                        .italics(),
+                    );
+                }
+            }
+            (toggled, clicked)
+        };
+        // only as wide as what is shown, for scrolling sideways
+        let width = row_ui.min_rect().width();
+        ui.allocate_exact_size(egui::vec2(width, height), egui::Sense::hover());
+        output
+    }
+
+    /// Draws the name, attributes and text of a node, with the editor of an edit of it.
+    /// Returns whether the name was clicked.
+    fn node_ui(&mut self, ui: &mut egui::Ui, store: &TreeStore, id: NodeId) -> bool {
+        let node = store.node(id);
+        let mut name = egui::RichText::new(&*node.name).strong();
+        if store.warning(id).is_some() {
+            name = name.color(ui.visuals().error_fg_color);
+        }
+        if self.matched.contains(&id) {
+            name = name.background_color(ui.visuals().selection.bg_fill);
+            if self.matches.get(self.current) == Some(&id) {
+                name = name.color(ui.visuals().selection.stroke.color);
+            }
+        }
+        let mut response = ui.selectable_label(self.selected == Some(id), name);
+        if let Some(warning) = store.warning(id) {
+            response = response.on_hover_text(warning);
+        }
+
+        // summaries of binary files have nothing to write the edits to
+        let editable = node.source.is_some();
+        let edit_text = || Editing::Text {
+            node: id,
+            text: node.content.clone().unwrap_or_default(),
+            original: node.content.clone().unwrap_or_default(),
+        };
+        let mut started = None;
+        if editable {
+            response.context_menu(|ui| {
+                if ui.button("Add attribute").clicked() {
+                    started = Some(Editing::NewAttribute {
+                        node: id,
+                        text: String::new(),
+                    });
+                }
+                if ui.button("Add child element").clicked() {
+                    started = Some(Editing::Child {
+                        node: id,
+                        name: String::new(),
+                    });
+                }
+                let has_children = !node.children.is_empty();
+                if ui
+                    .add_enabled(!has_children, egui::Button::new("Edit text"))
+                    .clicked()
+                {
+                    started = Some(edit_text());
+                }
+                // the root element of a document stays
+                let has_parent = node
+                    .parent
+                    .is_some_and(|parent| store.node(parent).source.is_some());
+                if ui
+                    .add_enabled(has_parent, egui::Button::new("Remove element"))
+                    .clicked()
+                {
+                    self.finished = Some(TreeEdit::Remove { node: id });
+                }
+                if started.is_some() || self.finished.is_some() {
+                    ui.close_menu();
+                }
+            });
+        }
+
+        let mut editing = if self.editing.as_ref().is_some_and(|e| e.node() == id) {
+            self.editing.take()
+        } else {
+            None
+        };
+        let mut done = None;
+        for (attribute, value) in store.attributes(id) {
+            ui.label(egui::RichText::new(format!("{}=", attribute)).weak());
+            match &mut editing {
+                Some(Editing::Attribute {
+                    name,
+                    value: edited,
+                    ..
+                }) if *name == attribute => {
+                    done = edit_field(ui, edited, "value", std::mem::take(&mut self.edit_focus));
+                }
+                _ => {
+                    let response = ui.add(egui::Label::new(&value).sense(egui::Sense::click()));
+                    let edit_value = || Editing::Attribute {
+                        node: id,
+                        name: attribute.clone(),
+                        value: value.clone(),
+                        original: value.clone(),
+                    };
+                    if editable && response.double_clicked() {
+                        started = Some(edit_value());
+                    }
+                    if editable {
+                        response.context_menu(|ui| {
+                            if ui.button("Edit value").clicked() {
+                                started = Some(edit_value());
+                                ui.close_menu();
+                            }
+                            if ui.button("Remove attribute").clicked() {
+                                self.finished = Some(TreeEdit::SetAttribute {
+                                    node: id,
+                                    name: attribute.clone(),
+                                    value: None,
+                                });
+                                ui.close_menu();
+                            }
+                        });
+                    }
+                }
+            }
+            if let Some(color) = color_swatch(&attribute, &value) {
+                let (rect, _) =
+                    ui.allocate_exact_size(egui::vec2(12.0, 12.0), egui::Sense::hover());
+                ui.painter().rect_filled(rect, 2.0, color);
+            }
+        }
+
+        let editing_text = matches!(editing, Some(Editing::Text { .. }));
+        if let (Some(content), false) = (&node.content, editing_text) {
+            let response = ui.add(egui::Label::new(content).sense(egui::Sense::click()));
+            if editable && node.children.is_empty() && response.double_clicked() {
+                started = Some(edit_text());
+            }
+        }
+        let focus = &mut self.edit_focus;
+        match &mut editing {
+            Some(Editing::Text { text, .. }) => {
+                done = edit_field(ui, text, "text", std::mem::take(focus));
+            }
+            Some(Editing::NewAttribute { text, .. }) => {
+                done = edit_field(ui, text, "name=value", std::mem::take(focus));
+            }
+            Some(Editing::Child { name, .. }) => {
+                done = edit_field(ui, name, "element", std::mem::take(focus));
+            }
+            _ => {}
+        }
+
+        match done {
+            Some(true) => self.finished = editing.and_then(Editing::finish),
+            Some(false) => {}
+            None => {
+                if editing.is_some() {
+                    self.editing = editing;
+                }
+            }
+        }
+        if started.is_some() {
+            self.editing = started;
+            self.edit_focus = true;
+        }
+        response.clicked()
+    }
+
+    fn list_rows(&mut self, store: &TreeStore) {
+        self.rows_stale = false;
+        let mut rows = Vec::new();
+        for &root in &store.roots {
+            if !self.is_searching() || self.is_listed(root) {
+                self.list_node_rows(store, root, 0, &mut rows);
+            }
+        }
+        self.rows = rows;
+    }
+
+    fn list_node_rows(&self, store: &TreeStore, id: NodeId, level: usize, rows: &mut Vec<Row>) {
+        rows.push(Row::Node { id, level });
+        if !self.expanded.contains(&RowKey::Node(id)) {
+            return;
+        }
+
+        let children = &store.node(id).children;
+        for (start, run) in self.child_runs(store, id) {
+            if run.len() < GROUP_MIN {
+                for index in run {
+                    self.list_node_rows(store, children[index], level + 1, rows);
+                }
+                continue;
+            }
+
+            let parent = id;
+            rows.push(Row::Group {
+                parent,
+                start,
+                len: run.len(),
+                level: level + 1,
+            });
+            if !self.expanded.contains(&RowKey::Group { parent, start }) {
+                continue;
+            }
+            if run.len() <= PAGE_SIZE {
+                for index in run {
+                    self.list_node_rows(store, children[index], level + 2, rows);
+                }
+                continue;
+            }
+            for page in run.chunks(PAGE_SIZE) {
+                let page_start = page[0];
+                rows.push(Row::Page {
+                    parent,
+                    group: start,
+                    start: page_start,
+                    last: page[page.len() - 1],
+                    level: level + 2,
+                });
+                if self.expanded.contains(&RowKey::Page {
+                    parent,
+                    start: page_start,
+                }) {
+                    for &index in page {
+                        self.list_node_rows(store, children[index], level + 3, rows);
+                    }
+                }
+            }
+        }
+    }
+
+    fn search(&mut self, store: &TreeStore) {
+        self.search_stale = false;
+        self.typed_at = None;
+        self.rows_stale = true;
+        self.matches.clear();
+        self.matched.clear();
+        self.ancestors.clear();
+        self.current = 0;
+        self.error = None;
+        // what the last search opened is closed again
+        if let Some(expanded) = self.expanded_before_search.take() {
+            self.expanded = expanded;
+        }
+        if self.query.is_empty() {
+            return;
+        }
+
+        let matcher = match Matcher::new(&self.query, self.use_regex) {
+            Ok(matcher) => matcher,
+            Err(e) => {
+                self.error = Some(e.to_string());
+                return;
+            }
+        };
+        self.matches = search_store(store, &matcher, &self.fields);
+        for &id in &self.matches {
+            let mut parent = store.node(id).parent;
+            while let Some(ancestor) = parent {
+                if !self.ancestors.insert(ancestor) {
+                    // the rest of the way up was added with an earlier match
+                    break;
+                }
+                parent = store.node(ancestor).parent;
+            }
+            self.matched.insert(id);
+        }
+        // matching ancestors open once, they can be closed again
+        self.expanded_before_search = Some(self.expanded.clone());
+        self.expanded
+            .extend(self.ancestors.iter().map(|&id| RowKey::Node(id)));
+        if let Some(&first) = self.matches.first() {
+            self.reveal(store, first);
+        }
+    }
+
+    fn step(&mut self, store: &TreeStore, forward: bool) {
+        if self.matches.is_empty() {
+            return;
+        }
+        let count = self.matches.len();
+        self.current = if forward {
+            (self.current + 1) % count
+        } else {
+            (self.current + count - 1) % count
+        };
+        self.reveal(store, self.matches[self.current]);
+    }
+}
+
+/// Single line editor of an edit. Returns whether the edit is done, `true` when it is
+/// confirmed and `false` when it is cancelled with escape.
+fn edit_field(ui: &mut egui::Ui, text: &mut String, hint: &str, focus: bool) -> Option<bool> {
+    let response = ui.add(
+        egui::TextEdit::singleline(text)
+            .hint_text(hint)
+            .desired_width(120.0),
+    );
+    if focus {
+        response.request_focus();
+    }
+    if !response.lost_focus() {
+        return None;
+    }
+    Some(!ui.input(|i| i.key_pressed(egui::Key::Escape)))
+}
+
+/// Colour of `color` and `displaycolor` attributes of the materials extension.
+fn color_swatch(name: &str, value: &str) -> Option<egui::Rgba> {
+    if name != "color" && name != "displaycolor" {
+        return None;
+    }
+    let [r, g, b, a] = parse_color(value)?;
+    Some(egui::Rgba::from_rgba_unmultiplied(r, g, b, a))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_support::allocated_by, widgets::tree::Tree};
+    use std::{
+        sync::Arc,
+        time::{Duration, Instant},
+    };
+
+    const MODEL_PART: &str = "/3D/3dmodel.model";
+
+    fn node(name: &str, attributes: &[(&str, &str)], childs: Vec<Tree>) -> Tree {
+        Tree {
+            name: name.to_string(),
+            content: None,
+            attributes: Some(
+                attributes
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .collect(),
+            ),
+            childs: (!childs.is_empty()).then_some(childs),
+            warning: None,
+        }
+    }
+
+    fn model() -> TreeStore {
+        TreeStore::from_trees(vec![node(
+            "model",
+            &[("unit", "millimeter")],
+            vec![
+                node(
+                    "resources",
+                    &[],
+                    vec![node("object", &[("id", "1"), ("name", "Box")], vec![])],
+                ),
+                node(
+                    "build",
+                    &[],
+                    vec![
+                        node("item", &[("objectid", "1")], vec![]),
+                        node("item", &[("objectid", "12")], vec![]),
+                    ],
+                ),
+            ],
+        )])
+    }
+
+    /// A mesh with `count` vertices and 9 other nodes.
+    fn mesh_xml(count: usize) -> String {
+        let mut xml =
+            String::from(r#"<model unit="millimeter"><resources><object id="1"><mesh><vertices>"#);
+        for index in 0..count {
+            xml.push_str(&format!(r#"<vertex x="{}" y="0" z="0"/>"#, index));
+        }
+        xml.push_str(r#"</vertices><triangles><triangle v1="0" v2="1" v3="2"/></triangles>"#);
+        xml.push_str(r#"</mesh></object></resources><build><item objectid="1"/></build></model>"#);
+        xml
+    }
+
+    /// Expands the nodes on the way to the node at the path of names.
+    fn expand(view: &mut TreeView, store: &TreeStore, path: &[&str]) -> NodeId {
+        let mut id = None;
+        for name in path {
+            id = store.find_child(id, name);
+            view.expanded.insert(RowKey::Node(id.unwrap()));
+        }
+        id.unwrap()
+    }
+
+    #[test]
+    fn test_search_fields() {
+        let store = model();
+        let fields = SearchFields::default();
+
+        let matches = search_store(&store, &Matcher::new("ITEM", false).unwrap(), &fields);
+        assert!(
+            matches == [4, 5],
+            "Element names are not found in any case: {:?}",
+            matches
+        );
+
+        let names_only = SearchFields {
+            attribute_values: false,
+            ..fields
+        };
+        let matches = search_store(&store, &Matcher::new("box", false).unwrap(), &names_only);
+        assert!(matches.is_empty(), "Attribute values are searched");
+        let matches = search_store(&store, &Matcher::new("box", false).unwrap(), &fields);
+        assert!(
+            matches == [2],
+            "Attribute values are not found: {:?}",
+            matches
+        );
+    }
+
+    #[test]
+    fn test_regex_search() {
+        let store = model();
+        let matches = search_store(
+            &store,
+            &Matcher::new("^1$", true).unwrap(),
+            &SearchFields::default(),
+        );
+        assert!(
+            matches == [2, 4],
+            "Regular expression is not anchored to the whole value: {:?}",
+            matches
+        );
+        assert!(
+            Matcher::new("(", true).is_err(),
+            "Invalid regex is accepted"
+        );
+    }
+
+    #[test]
+    fn test_matches_are_stepped_through() {
+        let store = model();
+        let mut view = TreeView {
+            query: "objectid".to_string(),
+            ..Default::default()
+        };
+        view.search(&store);
+        view.list_rows(&store);
+
+        assert!(
+            view.ancestors == HashSet::from([0, 3]),
+            "Ancestors of the matches are wrong: {:?}",
+            view.ancestors
+        );
+        let listed: Vec<NodeId> = view
+            .rows
+            .iter()
+            .filter_map(|row| match row {
+                Row::Node { id, .. } => Some(*id),
+                _ => None,
+            })
+            .collect();
+        assert!(
+            listed == [0, 3, 4, 5],
+            "Only the matches and their ancestors are listed: {:?}",
+            listed
+        );
+        view.step(&store, false);
+        assert!(
+            view.matches[view.current] == 5,
+            "Previous does not wrap around"
+        );
+        view.step(&store, true);
+        assert!(view.matches[view.current] == 4, "Next does not go forward");
+    }
+
+    #[test]
+    fn test_search_closes_what_it_opened() {
+        let store = model();
+        let mut view = TreeView::default();
+        let before = HashSet::from([RowKey::Node(0)]);
+        view.expanded = before.clone();
+
+        view.query = "Box".to_string();
+        view.search(&store);
+        assert!(
+            view.expanded.contains(&RowKey::Node(1)),
+            "Resources are not opened to the matching object"
+        );
+        view.query = "objectid".to_string();
+        view.search(&store);
+        assert!(
+            !view.expanded.contains(&RowKey::Node(1)) && view.expanded.contains(&RowKey::Node(3)),
+            "Changed query keeps what the last search opened: {:?}",
+            view.expanded
+        );
+        view.query.clear();
+        view.search(&store);
+        assert!(
+            view.expanded == before,
+            "Cleared query does not expand what was expanded before: {:?}",
+            view.expanded
+        );
+    }
+
+    #[test]
+    fn test_long_runs_are_paged() {
+        let store = TreeStore::from_xml(MODEL_PART, mesh_xml(2500)).unwrap();
+        let mut view = TreeView::default();
+        let vertices = expand(
+            &mut view,
+            &store,
+            &["model", "resources", "object", "mesh", "vertices"],
+        );
+        view.list_rows(&store);
+        assert!(
+            view.rows[5]
+                == Row::Group {
+                    parent: vertices,
+                    start: 0,
+                    len: 2500,
+                    level: 5
+                },
+            "Vertices are not grouped: {:?}",
+            view.rows
+        );
+
+        view.expanded.insert(RowKey::Group {
+            parent: vertices,
+            start: 0,
+        });
+        view.expanded.insert(RowKey::Page {
+            parent: vertices,
+            start: 2000,
+        });
+        view.list_rows(&store);
+        let pages: Vec<usize> = view
+            .rows
+            .iter()
+            .filter_map(|row| match row {
+                Row::Page { start, last, .. } => Some(last - start + 1),
+                _ => None,
+            })
+            .collect();
+        assert!(pages == [1000, 1000, 500], "Pages are wrong: {:?}", pages);
+        // model to vertices, the group, its pages, the open page, triangles and build
+        assert!(
+            view.rows.len() == 5 + 1 + 3 + 500 + 2,
+            "Only the vertices of the open page are listed, got {} rows",
+            view.rows.len()
+        );
+    }
+
+    #[test]
+    fn test_matches_are_paged() {
+        let store = TreeStore::from_xml(MODEL_PART, mesh_xml(2500)).unwrap();
+        // x of the vertices 1000 to 2199
+        let mut view = TreeView {
+            query: r"^(1\d\d\d|2[01]\d\d)$".to_string(),
+            use_regex: true,
+            ..Default::default()
+        };
+        view.search(&store);
+        view.list_rows(&store);
+
+        let vertices = store.node(view.matches[0]).parent.unwrap();
+        let groups: Vec<Row> = view
+            .rows
+            .iter()
+            .filter(|row| !matches!(row, Row::Node { .. }))
+            .copied()
+            .collect();
+        assert!(
+            groups
+                == [
+                    Row::Group {
+                        parent: vertices,
+                        start: 0,
+                        len: 1200,
+                        level: 5
+                    },
+                    Row::Page {
+                        parent: vertices,
+                        group: 0,
+                        start: 1000,
+                        last: 1999,
+                        level: 6
+                    },
+                    Row::Page {
+                        parent: vertices,
+                        group: 0,
+                        start: 2000,
+                        last: 2199,
+                        level: 6
+                    },
+                ],
+            "Matches are not grouped into pages: {:?}",
+            groups
+        );
+        // model to vertices, the group, its pages and the page of the first match
+        assert!(
+            view.rows.len() == 5 + 1 + 2 + 1000,
+            "Only the page of the first match is open, got {} rows",
+            view.rows.len()
+        );
+
+        view.step(&store, false);
+        view.list_rows(&store);
+        assert!(
+            view.rows.len() == 5 + 1 + 2 + 1200,
+            "Page of the last match is not opened, got {} rows",
+            view.rows.len()
+        );
+    }
+
+    #[test]
+    fn test_selected_node_is_revealed() {
+        let store = TreeStore::from_xml(MODEL_PART, mesh_xml(2500)).unwrap();
+        let mut view = TreeView::default();
+        let vertices = store
+            .find_child(store.document_root(MODEL_PART), "resources")
+            .and_then(|resources| store.find_child(Some(resources), "object"))
+            .and_then(|object| store.find_child(Some(object), "mesh"))
+            .and_then(|mesh| store.find_child(Some(mesh), "vertices"))
+            .unwrap();
+        let vertex = store.node(vertices).children[1500];
+
+        view.select(&store, vertex);
+        view.list_rows(&store);
+        assert!(
+            view.rows
+                .iter()
+                .any(|row| matches!(row, Row::Node { id, level: 7 } if *id == vertex)),
+            "Selected vertex is not listed in its page"
+        );
+        assert!(
+            view.selected == Some(vertex) && view.scroll_to == Some(vertex),
+            "Selected vertex is not scrolled to"
+        );
+        assert!(
+            view.rows.len() == 5 + 1 + 3 + 1000 + 2,
+            "Other pages are opened, got {} rows",
+            view.rows.len()
+        );
+    }
+
+    #[test]
+    fn test_rows_have_the_row_height() {
+        let xml = r##"<model><resources><basematerials id="1"><base name="Red" displaycolor="#FF0000"/></basematerials></resources></model>"##;
+        let store = TreeStore::from_xml(MODEL_PART, xml).unwrap();
+        let mut view = TreeView::default();
+        expand(&mut view, &store, &["model", "resources", "basematerials"]);
+        view.list_rows(&store);
+        let mut rows = view.rows.clone();
+        rows.push(Row::Group {
+            parent: 0,
+            start: 0,
+            len: 100,
+            level: 1,
+        });
+
+        let ctx = egui::Context::default();
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let row_height = ui.spacing().interact_size.y;
+                let spacing = ui.spacing().item_spacing.y;
+                for row in &rows {
+                    let top = ui.cursor().top();
+                    view.row_ui(ui, &store, row, row_height);
+                    let height = ui.cursor().top() - top;
+                    assert!(
+                        height == row_height + spacing,
+                        "Row {:?} is {} high instead of {}",
+                        row,
+                        height,
+                        row_height + spacing
+                    );
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_edits_are_entered() {
+        let new_attribute = Editing::NewAttribute {
+            node: 2,
+            text: r#" name = "Lid" "#.to_string(),
+        };
+        assert!(
+            new_attribute.finish()
+                == Some(TreeEdit::SetAttribute {
+                    node: 2,
+                    name: "name".to_string(),
+                    value: Some("Lid".to_string())
+                }),
+            "New attribute is not split into name and value"
+        );
+        let unchanged = Editing::Attribute {
+            node: 2,
+            name: "id".to_string(),
+            value: "1".to_string(),
+            original: "1".to_string(),
+        };
+        assert!(unchanged.finish().is_none(), "Unchanged value is an edit");
+        let unnamed = Editing::Child {
+            node: 2,
+            name: " ".to_string(),
+        };
+        assert!(
+            unnamed.finish().is_none(),
+            "Child without a name is an edit"
+        );
+    }
+
+    #[test]
+    fn test_view_follows_replaced_nodes() {
+        let mut view = TreeView {
+            expanded: HashSet::from([
+                RowKey::Node(0),
+                RowKey::Node(2),
+                RowKey::Group {
+                    parent: 3,
+                    start: 0,
+                },
+            ]),
+            selected: Some(4),
+            ..Default::default()
+        };
+
+        // the object was removed
+        view.nodes_replaced(2..3, 0);
+        assert!(
+            view.expanded
+                == HashSet::from([
+                    RowKey::Node(0),
+                    RowKey::Group {
+                        parent: 2,
+                        start: 0
+                    }
+                ]),
+            "Expanded nodes did not move: {:?}",
+            view.expanded
+        );
+        assert!(view.selected == Some(3), "Selected item did not move");
+
+        // a child was added to the first item
+        view.nodes_replaced(4..4, 1);
+        assert!(view.selected == Some(3), "Item before the child moved");
+        view.nodes_replaced(3..3, 1);
+        assert!(
+            view.selected == Some(4),
+            "Item after the child did not move"
+        );
+    }
+
+    /// Run with `cargo test --release -- --ignored`, the time budgets are for optimised
+    /// builds.
+    /// Reads a mesh of `count` vertices, opens the page in the middle of the vertices and
+    /// checks the time it takes to read and draw the tree and the memory of the store.
+    fn assert_budgets_of_mesh(count: usize) {
+        // a frame at 60 Hz, reading and the first frame happen once per file
+        let read_budget = Duration::from_secs(2);
+        let first_frame_budget = Duration::from_millis(100);
+        let frame_budget = Duration::from_secs_f64(1.0 / 60.0);
+        // bytes of a node, every element is one
+        let node_budget = 200;
+        let xml: Arc<str> = mesh_xml(count).into();
+
+        let started = Instant::now();
+        let (store, allocated) = allocated_by(|| TreeStore::from_xml(MODEL_PART, xml.clone()));
+        let store = store.unwrap();
+        let read = started.elapsed();
+        assert!(
+            read < read_budget,
+            "Reading {} nodes from {} MB took {:?}",
+            store.node_count(),
+            xml.len() / 1_000_000,
+            read
+        );
+        assert!(
+            allocated / store.node_count() < node_budget,
+            "{} nodes take {} bytes each",
+            store.node_count(),
+            allocated / store.node_count()
+        );
+
+        let mut view = TreeView::default();
+        let vertices = expand(
+            &mut view,
+            &store,
+            &["model", "resources", "object", "mesh", "vertices"],
+        );
+        view.expanded.insert(RowKey::Group {
+            parent: vertices,
+            start: 0,
+        });
+        view.expanded.insert(RowKey::Page {
+            parent: vertices,
+            start: count / 2 / PAGE_SIZE * PAGE_SIZE,
+        });
+
+        let ctx = egui::Context::default();
+        let frame = |view: &mut TreeView| {
+            let input = egui::RawInput {
+                screen_rect: Some(egui::Rect::from_min_size(
+                    egui::Pos2::ZERO,
+                    egui::vec2(800.0, 600.0),
+                )),
+                ..Default::default()
+            };
+            let started = Instant::now();
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| view.ui(ui, &store));
+            });
+            started.elapsed()
+        };
+
+        let first_frame = frame(&mut view);
+        assert!(
+            first_frame < first_frame_budget,
+            "First frame, listing the rows, took {:?}",
+            first_frame
+        );
+        let frames = 20;
+        let total: Duration = (0..frames).map(|_| frame(&mut view)).sum();
+        assert!(
+            total / frames < frame_budget,
+            "{} rows of {} nodes took {:?} per frame",
+            view.rows.len(),
+            store.node_count(),
+            total / frames
+        );
+        // model to vertices, the group, its pages, the open page, triangles and build
+        assert!(
+            view.rows.len() == 5 + 1 + count / PAGE_SIZE + PAGE_SIZE + 2,
+            "Rows are not paged: {}",
+            view.rows.len()
+        );
+    }
+
+    #[test]
+    fn test_budgets_of_a_hundred_thousand_nodes() {
+        assert_budgets_of_mesh(100_000);
+    }
+
+    // reading takes longer than its budget in debug builds, run with
+    // `cargo test --release -- --ignored`
+    #[test]
+    #[ignore]
+    fn benchmark_frame_time_of_a_million_nodes() {
+        assert_budgets_of_mesh(1_000_000);
+    }
+}
@@ -0,0 +1,53 @@
+use crate::geometry::validation::ObjectValidation;
+
+/// Issues listed per object, more are summarised by their count.
+const MAX_LISTED_ISSUES: usize = 100;
+
+/// Draws the issues found in every mesh object.
+pub fn validation_ui(ui: &mut egui::Ui, validations: &[ObjectValidation]) {
+    for validation in validations {
+        let name = match &validation.name {
+            Some(name) => format!("object {} - {}", validation.object_id, name),
+            None => format!("object {}", validation.object_id),
+        };
+        if validation.issues.is_empty() {
+            ui.label(format!("{} - OK", name));
+            continue;
+        }
+
+        egui::CollapsingHeader::new(
+            egui::RichText::new(format!("{} - {} issues", name, validation.issues.len()))
+                .color(ui.visuals().error_fg_color),
+        )
+        .id_source(("validation", &validation.part, validation.object_id))
+        .show(ui, |ui| {
+            ui.label(format!("part - {}", validation.part));
+            for (kind, count) in validation.issue_counts() {
+                ui.label(format!("{} - {}", kind, count));
+            }
+            ui.separator();
+
+            for issue in validation.issues.iter().take(MAX_LISTED_ISSUES) {
+                let triangles = issue
+                    .triangles
+                    .iter()
+                    .map(usize::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                match issue.edge {
+                    Some([from, to]) => ui.label(format!(
+                        "{} {}-{} - triangles {}",
+                        issue.kind, from, to, triangles
+                    )),
+                    None => ui.label(format!("{} - triangles {}", issue.kind, triangles)),
+                };
+            }
+            if validation.issues.len() > MAX_LISTED_ISSUES {
+                ui.label(format!(
+                    "... {} more",
+                    validation.issues.len() - MAX_LISTED_ISSUES
+                ));
+            }
+        });
+    }
+}
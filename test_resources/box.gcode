@@ -0,0 +1,5334 @@
+; generated by AMRUST
+; layer_height = 0.3
+; line_width = 0.45
+; perimeters = 2
+; infill_density = 0.2
+; layer_count = 100
+M140 S60
+M104 S210
+M190 S60
+M109 S210
+G28
+G92 E0
+G21
+G90
+M83
+M107
+;LAYER:0
+G1 E-2 F2400
+G0 Z0.3 F9000
+;TYPE:WALL-INNER
+G0 X9.325 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y19.325 E0.48549 F1200
+G1 X0.675 Y0.675 E1.04676
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+;TYPE:WALL-OUTER
+G0 X9.775 Y19.775 F9000
+G1 X0.225 Y19.775 E0.53601 F1200
+G1 X0.225 Y0.225 E1.09727
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+;TYPE:SKIN
+G1 E-2 F2400
+G0 X8.988 Y1.669 F9000
+G1 E2 F2400
+G1 X8.331 Y1.012 E0.05211 F1200
+G0 X7.695 Y1.012 F9000
+G1 X8.988 Y2.305 E0.10262 F1200
+G0 X8.988 Y2.942 F9000
+G1 X7.058 Y1.013 E0.15313 F1200
+G0 X6.422 Y1.012 F9000
+G1 X8.988 Y3.578 E0.20365 F1200
+G0 X8.988 Y4.215 F9000
+G1 X5.785 Y1.013 E0.25416 F1200
+G0 X5.149 Y1.012 F9000
+G1 X8.988 Y4.851 E0.30467 F1200
+G0 X8.988 Y5.487 F9000
+G1 X4.513 Y1.012 E0.35519 F1200
+G0 X3.876 Y1.012 F9000
+G1 X8.988 Y6.124 E0.4057 F1200
+G0 X8.988 Y6.76 F9000
+G1 X3.24 Y1.012 E0.45622 F1200
+G0 X2.603 Y1.012 F9000
+G1 X8.987 Y7.397 E0.50673 F1200
+G0 X8.988 Y8.033 F9000
+G1 X1.967 Y1.012 E0.55724 F1200
+G0 X1.331 Y1.012 F9000
+G1 X8.988 Y8.669 E0.60776 F1200
+G0 X8.988 Y9.306 F9000
+G1 X1.012 Y1.331 E0.63301 F1200
+G0 X1.013 Y1.967 F9000
+G1 X8.988 Y9.942 E0.63301 F1200
+G0 X8.988 Y10.578 F9000
+G1 X1.012 Y2.603 E0.63301 F1200
+G0 X1.012 Y3.24 F9000
+G1 X8.988 Y11.215 E0.63301 F1200
+G0 X8.988 Y11.851 F9000
+G1 X1.012 Y3.876 E0.63301 F1200
+G0 X1.012 Y4.513 F9000
+G1 X8.987 Y12.488 E0.63301 F1200
+G0 X8.988 Y13.124 F9000
+G1 X1.013 Y5.149 E0.63301 F1200
+G0 X1.012 Y5.785 F9000
+G1 X8.988 Y13.76 E0.63301 F1200
+G0 X8.988 Y14.397 F9000
+G1 X1.012 Y6.422 E0.63301 F1200
+G0 X1.013 Y7.058 F9000
+G1 X8.987 Y15.033 E0.63301 F1200
+G0 X8.988 Y15.67 F9000
+G1 X1.012 Y7.695 E0.63301 F1200
+G0 X1.012 Y8.331 F9000
+G1 X8.987 Y16.306 E0.63301 F1200
+G0 X8.988 Y16.942 F9000
+G1 X1.013 Y8.967 E0.63301 F1200
+G0 X1.013 Y9.604 F9000
+G1 X8.988 Y17.579 E0.63301 F1200
+G0 X8.988 Y18.215 F9000
+G1 X1.013 Y10.24 E0.63301 F1200
+G0 X1.013 Y10.877 F9000
+G1 X8.987 Y18.852 E0.63301 F1200
+G0 X8.487 Y18.988 F9000
+G1 X1.013 Y11.513 E0.59328 F1200
+G0 X1.012 Y12.149 F9000
+G1 X7.851 Y18.987 E0.54277 F1200
+G0 X7.214 Y18.988 F9000
+G1 X1.013 Y12.786 E0.49226 F1200
+G0 X1.013 Y13.422 F9000
+G1 X6.578 Y18.988 E0.44174 F1200
+G0 X5.941 Y18.988 F9000
+G1 X1.013 Y14.059 E0.39123 F1200
+G0 X1.013 Y14.695 F9000
+G1 X5.305 Y18.988 E0.34072 F1200
+G0 X4.669 Y18.988 F9000
+G1 X1.012 Y15.331 E0.2902 F1200
+G0 X1.012 Y15.968 F9000
+G1 X4.032 Y18.988 E0.23969 F1200
+G0 X3.396 Y18.988 F9000
+G1 X1.012 Y16.604 E0.18917 F1200
+G0 X1.012 Y17.241 F9000
+G1 X2.759 Y18.988 E0.13866 F1200
+G0 X2.123 Y18.988 F9000
+G1 X1.012 Y17.877 E0.08815 F1200
+G0 X1.012 Y18.513 F9000
+G1 X1.487 Y18.988 E0.03763 F1200
+;LAYER:1
+M106 S255
+G1 E-2 F2400
+G0 Z0.6 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:SKIN
+G1 E-2 F2400
+G0 X8.987 Y18.696 F9000
+G1 E2 F2400
+G1 X8.696 Y18.988 E0.02316 F3000
+G0 X8.059 Y18.988 F9000
+G1 X8.987 Y18.059 E0.07367 F3000
+G0 X8.987 Y17.423 F9000
+G1 X7.423 Y18.988 E0.12419 F3000
+G0 X6.787 Y18.988 F9000
+G1 X8.987 Y16.787 E0.1747 F3000
+G0 X8.988 Y16.15 F9000
+G1 X6.15 Y18.988 E0.22521 F3000
+G0 X5.514 Y18.988 F9000
+G1 X8.987 Y15.514 E0.27573 F3000
+G0 X8.988 Y14.877 F9000
+G1 X4.877 Y18.988 E0.32624 F3000
+G0 X4.241 Y18.988 F9000
+G1 X8.987 Y14.241 E0.37676 F3000
+G0 X8.988 Y13.605 F9000
+G1 X3.605 Y18.988 E0.42727 F3000
+G0 X2.968 Y18.988 F9000
+G1 X8.988 Y12.968 E0.47778 F3000
+G0 X8.988 Y12.332 F9000
+G1 X2.332 Y18.988 E0.5283 F3000
+G0 X1.695 Y18.988 F9000
+G1 X8.988 Y11.695 E0.57881 F3000
+G0 X8.988 Y11.059 F9000
+G1 X1.059 Y18.988 E0.62933 F3000
+G0 X1.013 Y18.398 F9000
+G1 X8.988 Y10.423 E0.63301 F3000
+G0 X8.987 Y9.786 F9000
+G1 X1.012 Y17.761 E0.63301 F3000
+G0 X1.012 Y17.125 F9000
+G1 X8.988 Y9.15 E0.63301 F3000
+G0 X8.988 Y8.513 F9000
+G1 X1.013 Y16.488 E0.63301 F3000
+G0 X1.012 Y15.852 F9000
+G1 X8.987 Y7.877 E0.63301 F3000
+G0 X8.988 Y7.241 F9000
+G1 X1.013 Y15.216 E0.63301 F3000
+G0 X1.012 Y14.579 F9000
+G1 X8.988 Y6.604 E0.63301 F3000
+G0 X8.988 Y5.968 F9000
+G1 X1.013 Y13.943 E0.63301 F3000
+G0 X1.012 Y13.306 F9000
+G1 X8.988 Y5.331 E0.63301 F3000
+G0 X8.988 Y4.695 F9000
+G1 X1.012 Y12.67 E0.63301 F3000
+G0 X1.013 Y12.034 F9000
+G1 X8.988 Y4.059 E0.63301 F3000
+G0 X8.988 Y3.422 F9000
+G1 X1.013 Y11.397 E0.63301 F3000
+G0 X1.013 Y10.761 F9000
+G1 X8.988 Y2.786 E0.63301 F3000
+G0 X8.988 Y2.149 F9000
+G1 X1.013 Y10.124 E0.63301 F3000
+G0 X1.013 Y9.488 F9000
+G1 X8.988 Y1.513 E0.63301 F3000
+G0 X8.852 Y1.012 F9000
+G1 X1.012 Y8.852 E0.62223 F3000
+G0 X1.013 Y8.215 F9000
+G1 X8.215 Y1.012 E0.57172 F3000
+G0 X7.579 Y1.012 F9000
+G1 X1.013 Y7.579 E0.5212 F3000
+G0 X1.013 Y6.942 F9000
+G1 X6.942 Y1.013 E0.47069 F3000
+G0 X6.306 Y1.012 F9000
+G1 X1.012 Y6.306 E0.42018 F3000
+G0 X1.012 Y5.67 F9000
+G1 X5.67 Y1.012 E0.36966 F3000
+G0 X5.033 Y1.012 F9000
+G1 X1.013 Y5.033 E0.31915 F3000
+G0 X1.013 Y4.397 F9000
+G1 X4.397 Y1.012 E0.26863 F3000
+G0 X3.76 Y1.012 F9000
+G1 X1.013 Y3.76 E0.21812 F3000
+G0 X1.012 Y3.124 F9000
+G1 X3.124 Y1.012 E0.16761 F3000
+G0 X2.488 Y1.012 F9000
+G1 X1.013 Y2.488 E0.11709 F3000
+G0 X1.012 Y1.851 F9000
+G1 X1.851 Y1.012 E0.06658 F3000
+G0 X1.215 Y1.012 F9000
+G1 X1.013 Y1.215 E0.01606 F3000
+;LAYER:2
+G1 E-2 F2400
+G0 Z0.9 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:SKIN
+G1 E-2 F2400
+G0 X8.988 Y1.669 F9000
+G1 E2 F2400
+G1 X8.331 Y1.012 E0.05211 F3000
+G0 X7.695 Y1.012 F9000
+G1 X8.988 Y2.305 E0.10262 F3000
+G0 X8.988 Y2.942 F9000
+G1 X7.058 Y1.013 E0.15313 F3000
+G0 X6.422 Y1.012 F9000
+G1 X8.988 Y3.578 E0.20365 F3000
+G0 X8.988 Y4.215 F9000
+G1 X5.785 Y1.013 E0.25416 F3000
+G0 X5.149 Y1.012 F9000
+G1 X8.988 Y4.851 E0.30467 F3000
+G0 X8.988 Y5.487 F9000
+G1 X4.513 Y1.012 E0.35519 F3000
+G0 X3.876 Y1.012 F9000
+G1 X8.988 Y6.124 E0.4057 F3000
+G0 X8.988 Y6.76 F9000
+G1 X3.24 Y1.012 E0.45622 F3000
+G0 X2.603 Y1.012 F9000
+G1 X8.987 Y7.397 E0.50673 F3000
+G0 X8.988 Y8.033 F9000
+G1 X1.967 Y1.012 E0.55724 F3000
+G0 X1.331 Y1.012 F9000
+G1 X8.988 Y8.669 E0.60776 F3000
+G0 X8.988 Y9.306 F9000
+G1 X1.012 Y1.331 E0.63301 F3000
+G0 X1.013 Y1.967 F9000
+G1 X8.988 Y9.942 E0.63301 F3000
+G0 X8.988 Y10.578 F9000
+G1 X1.012 Y2.603 E0.63301 F3000
+G0 X1.012 Y3.24 F9000
+G1 X8.988 Y11.215 E0.63301 F3000
+G0 X8.988 Y11.851 F9000
+G1 X1.012 Y3.876 E0.63301 F3000
+G0 X1.012 Y4.513 F9000
+G1 X8.987 Y12.488 E0.63301 F3000
+G0 X8.988 Y13.124 F9000
+G1 X1.013 Y5.149 E0.63301 F3000
+G0 X1.012 Y5.785 F9000
+G1 X8.988 Y13.76 E0.63301 F3000
+G0 X8.988 Y14.397 F9000
+G1 X1.012 Y6.422 E0.63301 F3000
+G0 X1.013 Y7.058 F9000
+G1 X8.987 Y15.033 E0.63301 F3000
+G0 X8.988 Y15.67 F9000
+G1 X1.012 Y7.695 E0.63301 F3000
+G0 X1.012 Y8.331 F9000
+G1 X8.987 Y16.306 E0.63301 F3000
+G0 X8.988 Y16.942 F9000
+G1 X1.013 Y8.967 E0.63301 F3000
+G0 X1.013 Y9.604 F9000
+G1 X8.988 Y17.579 E0.63301 F3000
+G0 X8.988 Y18.215 F9000
+G1 X1.013 Y10.24 E0.63301 F3000
+G0 X1.013 Y10.877 F9000
+G1 X8.987 Y18.852 E0.63301 F3000
+G0 X8.487 Y18.988 F9000
+G1 X1.013 Y11.513 E0.59328 F3000
+G0 X1.012 Y12.149 F9000
+G1 X7.851 Y18.987 E0.54277 F3000
+G0 X7.214 Y18.988 F9000
+G1 X1.013 Y12.786 E0.49226 F3000
+G0 X1.013 Y13.422 F9000
+G1 X6.578 Y18.988 E0.44174 F3000
+G0 X5.941 Y18.988 F9000
+G1 X1.013 Y14.059 E0.39123 F3000
+G0 X1.013 Y14.695 F9000
+G1 X5.305 Y18.988 E0.34072 F3000
+G0 X4.669 Y18.988 F9000
+G1 X1.012 Y15.331 E0.2902 F3000
+G0 X1.012 Y15.968 F9000
+G1 X4.032 Y18.988 E0.23969 F3000
+G0 X3.396 Y18.988 F9000
+G1 X1.012 Y16.604 E0.18917 F3000
+G0 X1.012 Y17.241 F9000
+G1 X2.759 Y18.988 E0.13866 F3000
+G0 X2.123 Y18.988 F9000
+G1 X1.012 Y17.877 E0.08815 F3000
+G0 X1.012 Y18.513 F9000
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:3
+G1 E-2 F2400
+G0 Z1.2 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:SKIN
+G1 E-2 F2400
+G0 X8.987 Y18.696 F9000
+G1 E2 F2400
+G1 X8.696 Y18.988 E0.02316 F3000
+G0 X8.059 Y18.988 F9000
+G1 X8.987 Y18.059 E0.07367 F3000
+G0 X8.987 Y17.423 F9000
+G1 X7.423 Y18.988 E0.12419 F3000
+G0 X6.787 Y18.988 F9000
+G1 X8.987 Y16.787 E0.1747 F3000
+G0 X8.988 Y16.15 F9000
+G1 X6.15 Y18.988 E0.22521 F3000
+G0 X5.514 Y18.988 F9000
+G1 X8.987 Y15.514 E0.27573 F3000
+G0 X8.988 Y14.877 F9000
+G1 X4.877 Y18.988 E0.32624 F3000
+G0 X4.241 Y18.988 F9000
+G1 X8.987 Y14.241 E0.37676 F3000
+G0 X8.988 Y13.605 F9000
+G1 X3.605 Y18.988 E0.42727 F3000
+G0 X2.968 Y18.988 F9000
+G1 X8.988 Y12.968 E0.47778 F3000
+G0 X8.988 Y12.332 F9000
+G1 X2.332 Y18.988 E0.5283 F3000
+G0 X1.695 Y18.988 F9000
+G1 X8.988 Y11.695 E0.57881 F3000
+G0 X8.988 Y11.059 F9000
+G1 X1.059 Y18.988 E0.62933 F3000
+G0 X1.013 Y18.398 F9000
+G1 X8.988 Y10.423 E0.63301 F3000
+G0 X8.987 Y9.786 F9000
+G1 X1.012 Y17.761 E0.63301 F3000
+G0 X1.012 Y17.125 F9000
+G1 X8.988 Y9.15 E0.63301 F3000
+G0 X8.988 Y8.513 F9000
+G1 X1.013 Y16.488 E0.63301 F3000
+G0 X1.012 Y15.852 F9000
+G1 X8.987 Y7.877 E0.63301 F3000
+G0 X8.988 Y7.241 F9000
+G1 X1.013 Y15.216 E0.63301 F3000
+G0 X1.012 Y14.579 F9000
+G1 X8.988 Y6.604 E0.63301 F3000
+G0 X8.988 Y5.968 F9000
+G1 X1.013 Y13.943 E0.63301 F3000
+G0 X1.012 Y13.306 F9000
+G1 X8.988 Y5.331 E0.63301 F3000
+G0 X8.988 Y4.695 F9000
+G1 X1.012 Y12.67 E0.63301 F3000
+G0 X1.013 Y12.034 F9000
+G1 X8.988 Y4.059 E0.63301 F3000
+G0 X8.988 Y3.422 F9000
+G1 X1.013 Y11.397 E0.63301 F3000
+G0 X1.013 Y10.761 F9000
+G1 X8.988 Y2.786 E0.63301 F3000
+G0 X8.988 Y2.149 F9000
+G1 X1.013 Y10.124 E0.63301 F3000
+G0 X1.013 Y9.488 F9000
+G1 X8.988 Y1.513 E0.63301 F3000
+G0 X8.852 Y1.012 F9000
+G1 X1.012 Y8.852 E0.62223 F3000
+G0 X1.013 Y8.215 F9000
+G1 X8.215 Y1.012 E0.57172 F3000
+G0 X7.579 Y1.012 F9000
+G1 X1.013 Y7.579 E0.5212 F3000
+G0 X1.013 Y6.942 F9000
+G1 X6.942 Y1.013 E0.47069 F3000
+G0 X6.306 Y1.012 F9000
+G1 X1.012 Y6.306 E0.42018 F3000
+G0 X1.012 Y5.67 F9000
+G1 X5.67 Y1.012 E0.36966 F3000
+G0 X5.033 Y1.012 F9000
+G1 X1.013 Y5.033 E0.31915 F3000
+G0 X1.013 Y4.397 F9000
+G1 X4.397 Y1.012 E0.26863 F3000
+G0 X3.76 Y1.012 F9000
+G1 X1.013 Y3.76 E0.21812 F3000
+G0 X1.012 Y3.124 F9000
+G1 X3.124 Y1.012 E0.16761 F3000
+G0 X2.488 Y1.012 F9000
+G1 X1.013 Y2.488 E0.11709 F3000
+G0 X1.012 Y1.851 F9000
+G1 X1.851 Y1.012 E0.06658 F3000
+G0 X1.215 Y1.012 F9000
+G1 X1.013 Y1.215 E0.01606 F3000
+;LAYER:4
+G1 E-2 F2400
+G0 Z1.5 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:5
+G1 E-2 F2400
+G0 Z1.8 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:6
+G1 E-2 F2400
+G0 Z2.1 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:7
+G1 E-2 F2400
+G0 Z2.4 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:8
+G1 E-2 F2400
+G0 Z2.7 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:9
+G1 E-2 F2400
+G0 Z3 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:10
+G1 E-2 F2400
+G0 Z3.3 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:11
+G1 E-2 F2400
+G0 Z3.6 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:12
+G1 E-2 F2400
+G0 Z3.9 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:13
+G1 E-2 F2400
+G0 Z4.2 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:14
+G1 E-2 F2400
+G0 Z4.5 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:15
+G1 E-2 F2400
+G0 Z4.8 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:16
+G1 E-2 F2400
+G0 Z5.1 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:17
+G1 E-2 F2400
+G0 Z5.4 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:18
+G1 E-2 F2400
+G0 Z5.7 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:19
+G1 E-2 F2400
+G0 Z6 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:20
+G1 E-2 F2400
+G0 Z6.3 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:21
+G1 E-2 F2400
+G0 Z6.6 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:22
+G1 E-2 F2400
+G0 Z6.9 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:23
+G1 E-2 F2400
+G0 Z7.2 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:24
+G1 E-2 F2400
+G0 Z7.5 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:25
+G1 E-2 F2400
+G0 Z7.8 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:26
+G1 E-2 F2400
+G0 Z8.1 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:27
+G1 E-2 F2400
+G0 Z8.4 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:28
+G1 E-2 F2400
+G0 Z8.7 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:29
+G1 E-2 F2400
+G0 Z9 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:30
+G1 E-2 F2400
+G0 Z9.3 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:31
+G1 E-2 F2400
+G0 Z9.6 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:32
+G1 E-2 F2400
+G0 Z9.9 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:33
+G1 E-2 F2400
+G0 Z10.2 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:34
+G1 E-2 F2400
+G0 Z10.5 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:35
+G1 E-2 F2400
+G0 Z10.8 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:36
+G1 E-2 F2400
+G0 Z11.1 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:37
+G1 E-2 F2400
+G0 Z11.4 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:38
+G1 E-2 F2400
+G0 Z11.7 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:39
+G1 E-2 F2400
+G0 Z12 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:40
+G1 E-2 F2400
+G0 Z12.3 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:41
+G1 E-2 F2400
+G0 Z12.6 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:42
+G1 E-2 F2400
+G0 Z12.9 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:43
+G1 E-2 F2400
+G0 Z13.2 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:44
+G1 E-2 F2400
+G0 Z13.5 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:45
+G1 E-2 F2400
+G0 Z13.8 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:46
+G1 E-2 F2400
+G0 Z14.1 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:47
+G1 E-2 F2400
+G0 Z14.4 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:48
+G1 E-2 F2400
+G0 Z14.7 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:49
+G1 E-2 F2400
+G0 Z15 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:50
+G1 E-2 F2400
+G0 Z15.3 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:51
+G1 E-2 F2400
+G0 Z15.6 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:52
+G1 E-2 F2400
+G0 Z15.9 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:53
+G1 E-2 F2400
+G0 Z16.2 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:54
+G1 E-2 F2400
+G0 Z16.5 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:55
+G1 E-2 F2400
+G0 Z16.8 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:56
+G1 E-2 F2400
+G0 Z17.1 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:57
+G1 E-2 F2400
+G0 Z17.4 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:58
+G1 E-2 F2400
+G0 Z17.7 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:59
+G1 E-2 F2400
+G0 Z18 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:60
+G1 E-2 F2400
+G0 Z18.3 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:61
+G1 E-2 F2400
+G0 Z18.6 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:62
+G1 E-2 F2400
+G0 Z18.9 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:63
+G1 E-2 F2400
+G0 Z19.2 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:64
+G1 E-2 F2400
+G0 Z19.5 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:65
+G1 E-2 F2400
+G0 Z19.8 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:66
+G1 E-2 F2400
+G0 Z20.1 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:67
+G1 E-2 F2400
+G0 Z20.4 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:68
+G1 E-2 F2400
+G0 Z20.7 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:69
+G1 E-2 F2400
+G0 Z21 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:70
+G1 E-2 F2400
+G0 Z21.3 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:71
+G1 E-2 F2400
+G0 Z21.6 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:72
+G1 E-2 F2400
+G0 Z21.9 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:73
+G1 E-2 F2400
+G0 Z22.2 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:74
+G1 E-2 F2400
+G0 Z22.5 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:75
+G1 E-2 F2400
+G0 Z22.8 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:76
+G1 E-2 F2400
+G0 Z23.1 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:77
+G1 E-2 F2400
+G0 Z23.4 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:78
+G1 E-2 F2400
+G0 Z23.7 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:79
+G1 E-2 F2400
+G0 Z24 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:80
+G1 E-2 F2400
+G0 Z24.3 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:81
+G1 E-2 F2400
+G0 Z24.6 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:82
+G1 E-2 F2400
+G0 Z24.9 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:83
+G1 E-2 F2400
+G0 Z25.2 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:84
+G1 E-2 F2400
+G0 Z25.5 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:85
+G1 E-2 F2400
+G0 Z25.8 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:86
+G1 E-2 F2400
+G0 Z26.1 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:87
+G1 E-2 F2400
+G0 Z26.4 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:88
+G1 E-2 F2400
+G0 Z26.7 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:89
+G1 E-2 F2400
+G0 Z27 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:90
+G1 E-2 F2400
+G0 Z27.3 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:91
+G1 E-2 F2400
+G0 Z27.6 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:92
+G1 E-2 F2400
+G0 Z27.9 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:93
+G1 E-2 F2400
+G0 Z28.2 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:94
+G1 E-2 F2400
+G0 Z28.5 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.988 Y4.215 F9000
+G1 E2 F2400
+G1 X5.785 Y1.013 E0.25416 F3000
+G1 E-2 F2400
+G0 X2.603 Y1.012 F9000
+G1 E2 F2400
+G1 X8.987 Y7.397 E0.50673 F3000
+G1 E-2 F2400
+G0 X8.988 Y10.578 F9000
+G1 E2 F2400
+G1 X1.012 Y2.603 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y5.785 F9000
+G1 E2 F2400
+G1 X8.988 Y13.76 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y16.942 F9000
+G1 E2 F2400
+G1 X1.013 Y8.967 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.012 Y12.149 F9000
+G1 E2 F2400
+G1 X7.851 Y18.987 E0.54277 F3000
+G1 E-2 F2400
+G0 X4.669 Y18.988 F9000
+G1 E2 F2400
+G1 X1.012 Y15.331 E0.2902 F3000
+G1 E-2 F2400
+G0 X1.012 Y18.513 F9000
+G1 E2 F2400
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:95
+G1 E-2 F2400
+G0 Z28.8 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:FILL
+G1 E-2 F2400
+G0 X8.987 Y18.059 F9000
+G1 E2 F2400
+G1 X8.059 Y18.988 E0.07367 F3000
+G1 E-2 F2400
+G0 X4.877 Y18.988 F9000
+G1 E2 F2400
+G1 X8.988 Y14.877 E0.32624 F3000
+G1 E-2 F2400
+G0 X8.988 Y11.695 F9000
+G1 E2 F2400
+G1 X1.695 Y18.988 E0.57881 F3000
+G1 E-2 F2400
+G0 X1.013 Y16.488 F9000
+G1 E2 F2400
+G1 X8.988 Y8.513 E0.63301 F3000
+G1 E-2 F2400
+G0 X8.988 Y5.331 F9000
+G1 E2 F2400
+G1 X1.012 Y13.306 E0.63301 F3000
+G1 E-2 F2400
+G0 X1.013 Y10.124 F9000
+G1 E2 F2400
+G1 X8.988 Y2.149 E0.63301 F3000
+G1 E-2 F2400
+G0 X6.942 Y1.013 F9000
+G1 E2 F2400
+G1 X1.013 Y6.942 E0.47069 F3000
+G1 E-2 F2400
+G0 X1.013 Y3.76 F9000
+G1 E2 F2400
+G1 X3.76 Y1.012 E0.21812 F3000
+;LAYER:96
+G1 E-2 F2400
+G0 Z29.1 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:SKIN
+G1 E-2 F2400
+G0 X8.988 Y1.669 F9000
+G1 E2 F2400
+G1 X8.331 Y1.012 E0.05211 F3000
+G0 X7.695 Y1.012 F9000
+G1 X8.988 Y2.305 E0.10262 F3000
+G0 X8.988 Y2.942 F9000
+G1 X7.058 Y1.013 E0.15313 F3000
+G0 X6.422 Y1.012 F9000
+G1 X8.988 Y3.578 E0.20365 F3000
+G0 X8.988 Y4.215 F9000
+G1 X5.785 Y1.013 E0.25416 F3000
+G0 X5.149 Y1.012 F9000
+G1 X8.988 Y4.851 E0.30467 F3000
+G0 X8.988 Y5.487 F9000
+G1 X4.513 Y1.012 E0.35519 F3000
+G0 X3.876 Y1.012 F9000
+G1 X8.988 Y6.124 E0.4057 F3000
+G0 X8.988 Y6.76 F9000
+G1 X3.24 Y1.012 E0.45622 F3000
+G0 X2.603 Y1.012 F9000
+G1 X8.987 Y7.397 E0.50673 F3000
+G0 X8.988 Y8.033 F9000
+G1 X1.967 Y1.012 E0.55724 F3000
+G0 X1.331 Y1.012 F9000
+G1 X8.988 Y8.669 E0.60776 F3000
+G0 X8.988 Y9.306 F9000
+G1 X1.012 Y1.331 E0.63301 F3000
+G0 X1.013 Y1.967 F9000
+G1 X8.988 Y9.942 E0.63301 F3000
+G0 X8.988 Y10.578 F9000
+G1 X1.012 Y2.603 E0.63301 F3000
+G0 X1.012 Y3.24 F9000
+G1 X8.988 Y11.215 E0.63301 F3000
+G0 X8.988 Y11.851 F9000
+G1 X1.012 Y3.876 E0.63301 F3000
+G0 X1.012 Y4.513 F9000
+G1 X8.987 Y12.488 E0.63301 F3000
+G0 X8.988 Y13.124 F9000
+G1 X1.013 Y5.149 E0.63301 F3000
+G0 X1.012 Y5.785 F9000
+G1 X8.988 Y13.76 E0.63301 F3000
+G0 X8.988 Y14.397 F9000
+G1 X1.012 Y6.422 E0.63301 F3000
+G0 X1.013 Y7.058 F9000
+G1 X8.987 Y15.033 E0.63301 F3000
+G0 X8.988 Y15.67 F9000
+G1 X1.012 Y7.695 E0.63301 F3000
+G0 X1.012 Y8.331 F9000
+G1 X8.987 Y16.306 E0.63301 F3000
+G0 X8.988 Y16.942 F9000
+G1 X1.013 Y8.967 E0.63301 F3000
+G0 X1.013 Y9.604 F9000
+G1 X8.988 Y17.579 E0.63301 F3000
+G0 X8.988 Y18.215 F9000
+G1 X1.013 Y10.24 E0.63301 F3000
+G0 X1.013 Y10.877 F9000
+G1 X8.987 Y18.852 E0.63301 F3000
+G0 X8.487 Y18.988 F9000
+G1 X1.013 Y11.513 E0.59328 F3000
+G0 X1.012 Y12.149 F9000
+G1 X7.851 Y18.987 E0.54277 F3000
+G0 X7.214 Y18.988 F9000
+G1 X1.013 Y12.786 E0.49226 F3000
+G0 X1.013 Y13.422 F9000
+G1 X6.578 Y18.988 E0.44174 F3000
+G0 X5.941 Y18.988 F9000
+G1 X1.013 Y14.059 E0.39123 F3000
+G0 X1.013 Y14.695 F9000
+G1 X5.305 Y18.988 E0.34072 F3000
+G0 X4.669 Y18.988 F9000
+G1 X1.012 Y15.331 E0.2902 F3000
+G0 X1.012 Y15.968 F9000
+G1 X4.032 Y18.988 E0.23969 F3000
+G0 X3.396 Y18.988 F9000
+G1 X1.012 Y16.604 E0.18917 F3000
+G0 X1.012 Y17.241 F9000
+G1 X2.759 Y18.988 E0.13866 F3000
+G0 X2.123 Y18.988 F9000
+G1 X1.012 Y17.877 E0.08815 F3000
+G0 X1.012 Y18.513 F9000
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:97
+G1 E-2 F2400
+G0 Z29.4 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:SKIN
+G1 E-2 F2400
+G0 X8.987 Y18.696 F9000
+G1 E2 F2400
+G1 X8.696 Y18.988 E0.02316 F3000
+G0 X8.059 Y18.988 F9000
+G1 X8.987 Y18.059 E0.07367 F3000
+G0 X8.987 Y17.423 F9000
+G1 X7.423 Y18.988 E0.12419 F3000
+G0 X6.787 Y18.988 F9000
+G1 X8.987 Y16.787 E0.1747 F3000
+G0 X8.988 Y16.15 F9000
+G1 X6.15 Y18.988 E0.22521 F3000
+G0 X5.514 Y18.988 F9000
+G1 X8.987 Y15.514 E0.27573 F3000
+G0 X8.988 Y14.877 F9000
+G1 X4.877 Y18.988 E0.32624 F3000
+G0 X4.241 Y18.988 F9000
+G1 X8.987 Y14.241 E0.37676 F3000
+G0 X8.988 Y13.605 F9000
+G1 X3.605 Y18.988 E0.42727 F3000
+G0 X2.968 Y18.988 F9000
+G1 X8.988 Y12.968 E0.47778 F3000
+G0 X8.988 Y12.332 F9000
+G1 X2.332 Y18.988 E0.5283 F3000
+G0 X1.695 Y18.988 F9000
+G1 X8.988 Y11.695 E0.57881 F3000
+G0 X8.988 Y11.059 F9000
+G1 X1.059 Y18.988 E0.62933 F3000
+G0 X1.013 Y18.398 F9000
+G1 X8.988 Y10.423 E0.63301 F3000
+G0 X8.987 Y9.786 F9000
+G1 X1.012 Y17.761 E0.63301 F3000
+G0 X1.012 Y17.125 F9000
+G1 X8.988 Y9.15 E0.63301 F3000
+G0 X8.988 Y8.513 F9000
+G1 X1.013 Y16.488 E0.63301 F3000
+G0 X1.012 Y15.852 F9000
+G1 X8.987 Y7.877 E0.63301 F3000
+G0 X8.988 Y7.241 F9000
+G1 X1.013 Y15.216 E0.63301 F3000
+G0 X1.012 Y14.579 F9000
+G1 X8.988 Y6.604 E0.63301 F3000
+G0 X8.988 Y5.968 F9000
+G1 X1.013 Y13.943 E0.63301 F3000
+G0 X1.012 Y13.306 F9000
+G1 X8.988 Y5.331 E0.63301 F3000
+G0 X8.988 Y4.695 F9000
+G1 X1.012 Y12.67 E0.63301 F3000
+G0 X1.013 Y12.034 F9000
+G1 X8.988 Y4.059 E0.63301 F3000
+G0 X8.988 Y3.422 F9000
+G1 X1.013 Y11.397 E0.63301 F3000
+G0 X1.013 Y10.761 F9000
+G1 X8.988 Y2.786 E0.63301 F3000
+G0 X8.988 Y2.149 F9000
+G1 X1.013 Y10.124 E0.63301 F3000
+G0 X1.013 Y9.488 F9000
+G1 X8.988 Y1.513 E0.63301 F3000
+G0 X8.852 Y1.012 F9000
+G1 X1.012 Y8.852 E0.62223 F3000
+G0 X1.013 Y8.215 F9000
+G1 X8.215 Y1.012 E0.57172 F3000
+G0 X7.579 Y1.012 F9000
+G1 X1.013 Y7.579 E0.5212 F3000
+G0 X1.013 Y6.942 F9000
+G1 X6.942 Y1.013 E0.47069 F3000
+G0 X6.306 Y1.012 F9000
+G1 X1.012 Y6.306 E0.42018 F3000
+G0 X1.012 Y5.67 F9000
+G1 X5.67 Y1.012 E0.36966 F3000
+G0 X5.033 Y1.012 F9000
+G1 X1.013 Y5.033 E0.31915 F3000
+G0 X1.013 Y4.397 F9000
+G1 X4.397 Y1.012 E0.26863 F3000
+G0 X3.76 Y1.012 F9000
+G1 X1.013 Y3.76 E0.21812 F3000
+G0 X1.012 Y3.124 F9000
+G1 X3.124 Y1.012 E0.16761 F3000
+G0 X2.488 Y1.012 F9000
+G1 X1.013 Y2.488 E0.11709 F3000
+G0 X1.012 Y1.851 F9000
+G1 X1.851 Y1.012 E0.06658 F3000
+G0 X1.215 Y1.012 F9000
+G1 X1.013 Y1.215 E0.01606 F3000
+;LAYER:98
+G1 E-2 F2400
+G0 Z29.7 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y0.675 F9000
+G1 E2 F2400
+G1 X9.325 Y0.675 E0.48549 F3000
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+G1 X0.675 Y0.675 E1.04676
+;TYPE:WALL-OUTER
+G0 X0.225 Y0.225 F9000
+G1 X9.775 Y0.225 E0.53601 F3000
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+G1 X0.225 Y0.225 E1.09727
+;TYPE:SKIN
+G1 E-2 F2400
+G0 X8.988 Y1.669 F9000
+G1 E2 F2400
+G1 X8.331 Y1.012 E0.05211 F3000
+G0 X7.695 Y1.012 F9000
+G1 X8.988 Y2.305 E0.10262 F3000
+G0 X8.988 Y2.942 F9000
+G1 X7.058 Y1.013 E0.15313 F3000
+G0 X6.422 Y1.012 F9000
+G1 X8.988 Y3.578 E0.20365 F3000
+G0 X8.988 Y4.215 F9000
+G1 X5.785 Y1.013 E0.25416 F3000
+G0 X5.149 Y1.012 F9000
+G1 X8.988 Y4.851 E0.30467 F3000
+G0 X8.988 Y5.487 F9000
+G1 X4.513 Y1.012 E0.35519 F3000
+G0 X3.876 Y1.012 F9000
+G1 X8.988 Y6.124 E0.4057 F3000
+G0 X8.988 Y6.76 F9000
+G1 X3.24 Y1.012 E0.45622 F3000
+G0 X2.603 Y1.012 F9000
+G1 X8.987 Y7.397 E0.50673 F3000
+G0 X8.988 Y8.033 F9000
+G1 X1.967 Y1.012 E0.55724 F3000
+G0 X1.331 Y1.012 F9000
+G1 X8.988 Y8.669 E0.60776 F3000
+G0 X8.988 Y9.306 F9000
+G1 X1.012 Y1.331 E0.63301 F3000
+G0 X1.013 Y1.967 F9000
+G1 X8.988 Y9.942 E0.63301 F3000
+G0 X8.988 Y10.578 F9000
+G1 X1.012 Y2.603 E0.63301 F3000
+G0 X1.012 Y3.24 F9000
+G1 X8.988 Y11.215 E0.63301 F3000
+G0 X8.988 Y11.851 F9000
+G1 X1.012 Y3.876 E0.63301 F3000
+G0 X1.012 Y4.513 F9000
+G1 X8.987 Y12.488 E0.63301 F3000
+G0 X8.988 Y13.124 F9000
+G1 X1.013 Y5.149 E0.63301 F3000
+G0 X1.012 Y5.785 F9000
+G1 X8.988 Y13.76 E0.63301 F3000
+G0 X8.988 Y14.397 F9000
+G1 X1.012 Y6.422 E0.63301 F3000
+G0 X1.013 Y7.058 F9000
+G1 X8.987 Y15.033 E0.63301 F3000
+G0 X8.988 Y15.67 F9000
+G1 X1.012 Y7.695 E0.63301 F3000
+G0 X1.012 Y8.331 F9000
+G1 X8.987 Y16.306 E0.63301 F3000
+G0 X8.988 Y16.942 F9000
+G1 X1.013 Y8.967 E0.63301 F3000
+G0 X1.013 Y9.604 F9000
+G1 X8.988 Y17.579 E0.63301 F3000
+G0 X8.988 Y18.215 F9000
+G1 X1.013 Y10.24 E0.63301 F3000
+G0 X1.013 Y10.877 F9000
+G1 X8.987 Y18.852 E0.63301 F3000
+G0 X8.487 Y18.988 F9000
+G1 X1.013 Y11.513 E0.59328 F3000
+G0 X1.012 Y12.149 F9000
+G1 X7.851 Y18.987 E0.54277 F3000
+G0 X7.214 Y18.988 F9000
+G1 X1.013 Y12.786 E0.49226 F3000
+G0 X1.013 Y13.422 F9000
+G1 X6.578 Y18.988 E0.44174 F3000
+G0 X5.941 Y18.988 F9000
+G1 X1.013 Y14.059 E0.39123 F3000
+G0 X1.013 Y14.695 F9000
+G1 X5.305 Y18.988 E0.34072 F3000
+G0 X4.669 Y18.988 F9000
+G1 X1.012 Y15.331 E0.2902 F3000
+G0 X1.012 Y15.968 F9000
+G1 X4.032 Y18.988 E0.23969 F3000
+G0 X3.396 Y18.988 F9000
+G1 X1.012 Y16.604 E0.18917 F3000
+G0 X1.012 Y17.241 F9000
+G1 X2.759 Y18.988 E0.13866 F3000
+G0 X2.123 Y18.988 F9000
+G1 X1.012 Y17.877 E0.08815 F3000
+G0 X1.012 Y18.513 F9000
+G1 X1.487 Y18.988 E0.03763 F3000
+;LAYER:99
+G1 E-2 F2400
+G0 Z30 F9000
+;TYPE:WALL-INNER
+G0 X0.675 Y19.325 F9000
+G1 E2 F2400
+G1 X0.675 Y0.675 E1.04676 F3000
+G1 X9.325 Y0.675 E0.48549
+G1 X9.325 Y19.325 E1.04676
+G1 X0.675 Y19.325 E0.48549
+;TYPE:WALL-OUTER
+G0 X0.225 Y19.775 F9000
+G1 X0.225 Y0.225 E1.09727 F3000
+G1 X9.775 Y0.225 E0.53601
+G1 X9.775 Y19.775 E1.09727
+G1 X0.225 Y19.775 E0.53601
+;TYPE:SKIN
+G1 E-2 F2400
+G0 X8.987 Y18.696 F9000
+G1 E2 F2400
+G1 X8.696 Y18.988 E0.02316 F3000
+G0 X8.059 Y18.988 F9000
+G1 X8.987 Y18.059 E0.07367 F3000
+G0 X8.987 Y17.423 F9000
+G1 X7.423 Y18.988 E0.12419 F3000
+G0 X6.787 Y18.988 F9000
+G1 X8.987 Y16.787 E0.1747 F3000
+G0 X8.988 Y16.15 F9000
+G1 X6.15 Y18.988 E0.22521 F3000
+G0 X5.514 Y18.988 F9000
+G1 X8.987 Y15.514 E0.27573 F3000
+G0 X8.988 Y14.877 F9000
+G1 X4.877 Y18.988 E0.32624 F3000
+G0 X4.241 Y18.988 F9000
+G1 X8.987 Y14.241 E0.37676 F3000
+G0 X8.988 Y13.605 F9000
+G1 X3.605 Y18.988 E0.42727 F3000
+G0 X2.968 Y18.988 F9000
+G1 X8.988 Y12.968 E0.47778 F3000
+G0 X8.988 Y12.332 F9000
+G1 X2.332 Y18.988 E0.5283 F3000
+G0 X1.695 Y18.988 F9000
+G1 X8.988 Y11.695 E0.57881 F3000
+G0 X8.988 Y11.059 F9000
+G1 X1.059 Y18.988 E0.62933 F3000
+G0 X1.013 Y18.398 F9000
+G1 X8.988 Y10.423 E0.63301 F3000
+G0 X8.987 Y9.786 F9000
+G1 X1.012 Y17.761 E0.63301 F3000
+G0 X1.012 Y17.125 F9000
+G1 X8.988 Y9.15 E0.63301 F3000
+G0 X8.988 Y8.513 F9000
+G1 X1.013 Y16.488 E0.63301 F3000
+G0 X1.012 Y15.852 F9000
+G1 X8.987 Y7.877 E0.63301 F3000
+G0 X8.988 Y7.241 F9000
+G1 X1.013 Y15.216 E0.63301 F3000
+G0 X1.012 Y14.579 F9000
+G1 X8.988 Y6.604 E0.63301 F3000
+G0 X8.988 Y5.968 F9000
+G1 X1.013 Y13.943 E0.63301 F3000
+G0 X1.012 Y13.306 F9000
+G1 X8.988 Y5.331 E0.63301 F3000
+G0 X8.988 Y4.695 F9000
+G1 X1.012 Y12.67 E0.63301 F3000
+G0 X1.013 Y12.034 F9000
+G1 X8.988 Y4.059 E0.63301 F3000
+G0 X8.988 Y3.422 F9000
+G1 X1.013 Y11.397 E0.63301 F3000
+G0 X1.013 Y10.761 F9000
+G1 X8.988 Y2.786 E0.63301 F3000
+G0 X8.988 Y2.149 F9000
+G1 X1.013 Y10.124 E0.63301 F3000
+G0 X1.013 Y9.488 F9000
+G1 X8.988 Y1.513 E0.63301 F3000
+G0 X8.852 Y1.012 F9000
+G1 X1.012 Y8.852 E0.62223 F3000
+G0 X1.013 Y8.215 F9000
+G1 X8.215 Y1.012 E0.57172 F3000
+G0 X7.579 Y1.012 F9000
+G1 X1.013 Y7.579 E0.5212 F3000
+G0 X1.013 Y6.942 F9000
+G1 X6.942 Y1.013 E0.47069 F3000
+G0 X6.306 Y1.012 F9000
+G1 X1.012 Y6.306 E0.42018 F3000
+G0 X1.012 Y5.67 F9000
+G1 X5.67 Y1.012 E0.36966 F3000
+G0 X5.033 Y1.012 F9000
+G1 X1.013 Y5.033 E0.31915 F3000
+G0 X1.013 Y4.397 F9000
+G1 X4.397 Y1.012 E0.26863 F3000
+G0 X3.76 Y1.012 F9000
+G1 X1.013 Y3.76 E0.21812 F3000
+G0 X1.012 Y3.124 F9000
+G1 X3.124 Y1.012 E0.16761 F3000
+G0 X2.488 Y1.012 F9000
+G1 X1.013 Y2.488 E0.11709 F3000
+G0 X1.012 Y1.851 F9000
+G1 X1.851 Y1.012 E0.06658 F3000
+G0 X1.215 Y1.012 F9000
+G1 X1.013 Y1.215 E0.01606 F3000
+G1 E-2 F2400
+M107
+M104 S0
+M140 S0
+G91
+G1 Z5 F600
+G90
+G28 X0 Y0
+M84